@@ -2,14 +2,521 @@
 //! implemented by [KXCVZNMX](https://github.com/KXCVZNMX)
 //!
 //! Data structures currently implemented:
-//! * Linked List
-//! * Stack (with linked list)
+//! * Linked List (with an owning `LinkedList` handle)
+//! * Doubly Linked List
+//! * Stack (backed by a vector or a linked list)
+//! * Bounded Stack (fixed capacity, without allocating)
+//! * Concurrent Stack (lock-free)
+//! * Queue
+//! * Vector
+//! * Deque (a ring buffer over a vector)
+//! * Small Vector (with inline storage)
+//!
+//! Built on top of them:
+//! * An arithmetic expression evaluator in [expr]
+//! * A balanced delimiter validator in [validator]
+//! * An undo/redo history in [history]
 
 /// Module Data Structure
 pub mod ds {
+    use std::fmt;
+
+    /// Errors returned by the fallible operations of the data structures in this module
+    ///
+    /// # Example
+    /// ```
+    /// # use crate::data_structure::ds::{Error, linked_list::ListNode};
+    /// let mut list = ListNode::from_vec(vec![1, 2, 3]);
+    /// assert_eq!(list.delete(4), Err(Error::NotFound));
+    /// assert_eq!(list.insert(5, 0), Err(Error::IndexOutOfRange { index: 5, len: 3 }));
+    /// assert_eq!(Error::NotFound.to_string(), "Element not found");
+    /// ```
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub enum Error {
+        /// The requested element is not in the structure
+        NotFound,
+        /// `index` is past the end of a structure holding `len` elements
+        IndexOutOfRange { index: usize, len: usize },
+        /// The structure has no element to hand out, or the operation
+        /// would leave a structure that can't be empty without elements
+        Empty,
+        /// The structure is full and can't take another element
+        CapacityExceeded,
+        /// The requested capacity doesn't fit in memory
+        CapacityOverflow,
+        /// The allocator couldn't provide the requested memory
+        AllocFailed,
+    }
+
+    impl fmt::Display for Error {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            match self {
+                Error::NotFound => write!(f, "Element not found"),
+                Error::IndexOutOfRange { index, len } => {
+                    write!(f, "Index {} out of range for length {}", index, len)
+                }
+                Error::Empty => write!(f, "Structure is empty"),
+                Error::CapacityExceeded => write!(f, "Capacity exceeded"),
+                Error::CapacityOverflow => write!(f, "Capacity overflow"),
+                Error::AllocFailed => write!(f, "Memory allocation failed"),
+            }
+        }
+    }
+
+    impl std::error::Error for Error {}
+
+    /// This module provides the `Allocator` trait the containers allocate through,
+    /// and `Global`, the default allocator backed by `std::alloc`
+    ///
+    /// `Vector<T, A>`, `ListNode<T, A>`, `LinkedList<T, A>` and `DoublyLinkedList<T, A>`
+    /// take an allocator as their last type parameter, and a `Stack<T, Vector<T, A>>`
+    /// or `ListStack<T, A>` allocates through its storage. The links of a `ListNode<T, A>`
+    /// are this module's [Box], which places a single value in memory from an allocator.
+    ///
+    /// # Example
+    /// ```
+    /// # use crate::data_structure::ds::allocator::{AllocError, Allocator, Global};
+    /// # use crate::data_structure::ds::vector::Vector;
+    /// use std::alloc::Layout;
+    /// use std::cell::Cell;
+    /// use std::ptr::NonNull;
+    ///
+    /// #[derive(Default)]
+    /// struct Counting {
+    ///     live: Cell<usize>,
+    /// }
+    ///
+    /// unsafe impl Allocator for Counting {
+    ///     fn allocate(&self, layout: Layout) -> Result<NonNull<u8>, AllocError> {
+    ///         self.live.set(self.live.get() + 1);
+    ///         Global.allocate(layout)
+    ///     }
+    ///
+    ///     unsafe fn deallocate(&self, ptr: NonNull<u8>, layout: Layout) {
+    ///         self.live.set(self.live.get() - 1);
+    ///         Global.deallocate(ptr, layout)
+    ///     }
+    /// }
+    ///
+    /// let counting = Counting::default();
+    /// let mut vector = Vector::new_in(&counting);
+    /// vector.extend([1, 2, 3]);
+    /// assert_eq!(counting.live.get(), 1);
+    /// drop(vector);
+    /// assert_eq!(counting.live.get(), 0);
+    /// ```
+    pub mod allocator {
+        use std::alloc::{self, Layout};
+        use std::fmt;
+        use std::marker::PhantomData;
+        use std::mem::{self, ManuallyDrop};
+        use std::ops::{Deref, DerefMut};
+        use std::ptr::{self, NonNull};
+
+        /// Error returned by an `Allocator` that couldn't satisfy a request
+        #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+        pub struct AllocError;
+
+        impl fmt::Display for AllocError {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                write!(f, "Memory allocation failed")
+            }
+        }
+
+        impl std::error::Error for AllocError {}
+
+        /// A source of memory for the containers in `ds`, mirroring the unstable
+        /// `std::alloc::Allocator` on stable Rust
+        ///
+        /// Only `allocate` and `deallocate` are required; `grow` and `shrink`
+        /// fall back to allocating a new block and copying.
+        ///
+        /// # Safety
+        /// Blocks returned by `allocate`, `grow` and `shrink` must stay valid and
+        /// untouched until they are passed to `deallocate`, `grow` or `shrink` of
+        /// this allocator or of a clone of it, even if the allocator itself is moved.
+        ///
+        /// If the allocator implements `PartialEq`, allocators that compare equal
+        /// must be able to free each other's blocks as well, since containers move
+        /// blocks between them: an arena should compare by identity, not contents.
+        pub unsafe trait Allocator {
+            /// Allocates a block fitting `layout`
+            fn allocate(&self, layout: Layout) -> Result<NonNull<u8>, AllocError>;
+
+            /// Frees a block
+            ///
+            /// # Safety
+            /// `ptr` must have been allocated by this allocator with `layout`.
+            unsafe fn deallocate(&self, ptr: NonNull<u8>, layout: Layout);
+
+            /// Moves a block to one fitting the larger `new_layout`, keeping its contents
+            ///
+            /// # Safety
+            /// `ptr` must have been allocated by this allocator with `old_layout`, and
+            /// `new_layout` must not be smaller than it. `ptr` is invalidated on success.
+            unsafe fn grow(
+                &self,
+                ptr: NonNull<u8>,
+                old_layout: Layout,
+                new_layout: Layout,
+            ) -> Result<NonNull<u8>, AllocError> {
+                debug_assert!(new_layout.size() >= old_layout.size());
+                let new_ptr = self.allocate(new_layout)?;
+                ptr::copy_nonoverlapping(ptr.as_ptr(), new_ptr.as_ptr(), old_layout.size());
+                self.deallocate(ptr, old_layout);
+                Ok(new_ptr)
+            }
+
+            /// Moves a block to one fitting the smaller `new_layout`, keeping the
+            /// contents that still fit
+            ///
+            /// # Safety
+            /// `ptr` must have been allocated by this allocator with `old_layout`, and
+            /// `new_layout` must not be larger than it. `ptr` is invalidated on success.
+            unsafe fn shrink(
+                &self,
+                ptr: NonNull<u8>,
+                old_layout: Layout,
+                new_layout: Layout,
+            ) -> Result<NonNull<u8>, AllocError> {
+                debug_assert!(new_layout.size() <= old_layout.size());
+                let new_ptr = self.allocate(new_layout)?;
+                ptr::copy_nonoverlapping(ptr.as_ptr(), new_ptr.as_ptr(), new_layout.size());
+                self.deallocate(ptr, old_layout);
+                Ok(new_ptr)
+            }
+        }
+
+        unsafe impl<A: Allocator + ?Sized> Allocator for &A {
+            fn allocate(&self, layout: Layout) -> Result<NonNull<u8>, AllocError> {
+                (**self).allocate(layout)
+            }
+
+            unsafe fn deallocate(&self, ptr: NonNull<u8>, layout: Layout) {
+                (**self).deallocate(ptr, layout)
+            }
+
+            unsafe fn grow(
+                &self,
+                ptr: NonNull<u8>,
+                old_layout: Layout,
+                new_layout: Layout,
+            ) -> Result<NonNull<u8>, AllocError> {
+                (**self).grow(ptr, old_layout, new_layout)
+            }
+
+            unsafe fn shrink(
+                &self,
+                ptr: NonNull<u8>,
+                old_layout: Layout,
+                new_layout: Layout,
+            ) -> Result<NonNull<u8>, AllocError> {
+                (**self).shrink(ptr, old_layout, new_layout)
+            }
+        }
+
+        /// The global allocator, as used by `Box` and `Vec`
+        ///
+        /// Zero-sized requests never reach the global allocator.
+        #[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+        pub struct Global;
+
+        /// Returns a well-aligned, non-null pointer for a zero-sized block
+        fn dangling(layout: Layout) -> NonNull<u8> {
+            // SAFETY: alignments are never zero
+            unsafe { NonNull::new_unchecked(ptr::without_provenance_mut(layout.align())) }
+        }
+
+        unsafe impl Allocator for Global {
+            fn allocate(&self, layout: Layout) -> Result<NonNull<u8>, AllocError> {
+                if layout.size() == 0 {
+                    return Ok(dangling(layout));
+                }
+                // SAFETY: `layout` has a non-zero size
+                NonNull::new(unsafe { alloc::alloc(layout) }).ok_or(AllocError)
+            }
+
+            unsafe fn deallocate(&self, ptr: NonNull<u8>, layout: Layout) {
+                if layout.size() != 0 {
+                    alloc::dealloc(ptr.as_ptr(), layout);
+                }
+            }
+
+            unsafe fn grow(
+                &self,
+                ptr: NonNull<u8>,
+                old_layout: Layout,
+                new_layout: Layout,
+            ) -> Result<NonNull<u8>, AllocError> {
+                if old_layout.size() == 0 {
+                    return self.allocate(new_layout);
+                }
+                if old_layout.align() != new_layout.align() {
+                    let new_ptr = self.allocate(new_layout)?;
+                    ptr::copy_nonoverlapping(ptr.as_ptr(), new_ptr.as_ptr(), old_layout.size());
+                    self.deallocate(ptr, old_layout);
+                    return Ok(new_ptr);
+                }
+                NonNull::new(alloc::realloc(ptr.as_ptr(), old_layout, new_layout.size())).ok_or(AllocError)
+            }
+
+            unsafe fn shrink(
+                &self,
+                ptr: NonNull<u8>,
+                old_layout: Layout,
+                new_layout: Layout,
+            ) -> Result<NonNull<u8>, AllocError> {
+                if new_layout.size() == 0 {
+                    self.deallocate(ptr, old_layout);
+                    return Ok(dangling(new_layout));
+                }
+                if old_layout.align() != new_layout.align() {
+                    let new_ptr = self.allocate(new_layout)?;
+                    ptr::copy_nonoverlapping(ptr.as_ptr(), new_ptr.as_ptr(), new_layout.size());
+                    self.deallocate(ptr, old_layout);
+                    return Ok(new_ptr);
+                }
+                NonNull::new(alloc::realloc(ptr.as_ptr(), old_layout, new_layout.size())).ok_or(AllocError)
+            }
+        }
+
+        /// An owning pointer to a `T` placed in memory from the allocator `A`,
+        /// mirroring the unstable `std::boxed::Box<T, A>` on stable Rust
+        ///
+        /// Like `std::boxed::Box`, its own functions are associated functions
+        /// (`Box::into_inner(b)` rather than `b.into_inner()`), so they never
+        /// shadow the methods of `T` reached through `Deref`.
+        ///
+        /// # Example
+        /// ```
+        /// # use crate::data_structure::ds::allocator::{Box, Global};
+        /// let mut boxed = Box::new_in(String::from("a"), Global);
+        /// boxed.push('b');
+        /// assert_eq!(Box::into_inner(boxed), "ab");
+        /// ```
+        pub struct Box<T, A: Allocator = Global> {
+            ptr: NonNull<T>,
+            alloc: A,
+            _owns: PhantomData<T>,
+        }
+
+        // SAFETY: `Box<T, A>` owns its `T` and `A` like a `(T, A)` would
+        unsafe impl<T: Send, A: Allocator + Send> Send for Box<T, A> {}
+        unsafe impl<T: Sync, A: Allocator + Sync> Sync for Box<T, A> {}
+
+        impl<T, A: Allocator> Unpin for Box<T, A> {}
+
+        impl<T> Box<T> {
+            /// Places `val` on the heap of the global allocator
+            pub fn new(val: T) -> Self {
+                Box::new_in(val, Global)
+            }
+
+            /// Takes ownership of a `T` allocated by `Box::new`
+            ///
+            /// # Safety
+            /// `raw` must come from `Box::into_raw` or `Box::leak` of a `Box<T>`,
+            /// and must not be used after this call.
+            pub unsafe fn from_raw(raw: *mut T) -> Self {
+                Box::from_raw_in(raw, Global)
+            }
+        }
+
+        impl<T, A: Allocator> Box<T, A> {
+            /// Places `val` in memory from `alloc`
+            ///
+            /// # Panics
+            /// Panics if the allocator fails
+            pub fn new_in(val: T, alloc: A) -> Self {
+                match Box::try_new_in(val, alloc) {
+                    Ok(boxed) => boxed,
+                    Err(_) => alloc::handle_alloc_error(Layout::new::<T>()),
+                }
+            }
+
+            /// Places `val` in memory from `alloc`, returning `Err(AllocError)`
+            /// instead of aborting if the allocator fails
+            pub fn try_new_in(val: T, alloc: A) -> Result<Self, AllocError> {
+                let ptr = alloc.allocate(Layout::new::<T>())?.cast::<T>();
+                // SAFETY: the block fits a `T` and nothing else refers to it
+                unsafe { ptr.as_ptr().write(val) };
+                Ok(Box {
+                    ptr,
+                    alloc,
+                    _owns: PhantomData,
+                })
+            }
+
+            /// Takes ownership of a `T` allocated by `alloc`
+            ///
+            /// # Safety
+            /// `raw` must come from `Box::into_raw` or `Box::leak` of a `Box<T, A>`
+            /// whose allocator can free it through `alloc`, and must not be used
+            /// after this call.
+            pub unsafe fn from_raw_in(raw: *mut T, alloc: A) -> Self {
+                Box {
+                    ptr: NonNull::new_unchecked(raw),
+                    alloc,
+                    _owns: PhantomData,
+                }
+            }
+
+            /// Returns the allocator the value lives in
+            pub fn allocator(b: &Self) -> &A {
+                &b.alloc
+            }
+
+            /// Returns a raw pointer to the value without giving up ownership
+            ///
+            /// The pointer stays valid, and may be written through, until the
+            /// box is dropped, even while other references to the value come and go.
+            pub fn as_mut_ptr(b: &mut Self) -> *mut T {
+                b.ptr.as_ptr()
+            }
+
+            /// Gives up ownership of the value, returning a raw pointer to it
+            /// and the allocator that has to free it
+            pub fn into_raw_with_allocator(b: Self) -> (*mut T, A) {
+                let b = ManuallyDrop::new(b);
+                // SAFETY: `b` is never used or dropped again
+                (b.ptr.as_ptr(), unsafe { ptr::read(&b.alloc) })
+            }
+
+            /// Gives up ownership of the value, returning a raw pointer to it
+            ///
+            /// The allocator is dropped, so `Box::from_raw_in` needs one that
+            /// can still free the block.
+            pub fn into_raw(b: Self) -> *mut T {
+                Box::into_raw_with_allocator(b).0
+            }
+
+            /// Gives up ownership of the value, returning a reference that
+            /// lives as long as the allocator
+            pub fn leak<'a>(b: Self) -> &'a mut T
+            where
+                A: 'a,
+            {
+                let (ptr, alloc) = Box::into_raw_with_allocator(b);
+                mem::forget(alloc);
+                // SAFETY: the block is never freed
+                unsafe { &mut *ptr }
+            }
+
+            /// Moves the value out of the box, freeing its memory
+            pub fn into_inner(b: Self) -> T {
+                let (ptr, alloc) = Box::into_raw_with_allocator(b);
+                // SAFETY: the value is initialized and the block came from `alloc`
+                unsafe {
+                    let val = ptr.read();
+                    alloc.deallocate(NonNull::new_unchecked(ptr).cast(), Layout::new::<T>());
+                    val
+                }
+            }
+        }
+
+        impl<T, A: Allocator> Drop for Box<T, A> {
+            fn drop(&mut self) {
+                /// Frees the block even if dropping the value panics
+                struct Dealloc<'a, T, A: Allocator>(&'a mut Box<T, A>);
+
+                impl<T, A: Allocator> Drop for Dealloc<'_, T, A> {
+                    fn drop(&mut self) {
+                        // SAFETY: the block came from this allocator with this layout
+                        unsafe { self.0.alloc.deallocate(self.0.ptr.cast(), Layout::new::<T>()) }
+                    }
+                }
+
+                let guard = Dealloc(self);
+                // SAFETY: the value is initialized and dropped exactly once
+                unsafe { ptr::drop_in_place(guard.0.ptr.as_ptr()) }
+            }
+        }
+
+        impl<T, A: Allocator> Deref for Box<T, A> {
+            type Target = T;
+
+            fn deref(&self) -> &T {
+                // SAFETY: the box owns an initialized `T`
+                unsafe { self.ptr.as_ref() }
+            }
+        }
+
+        impl<T, A: Allocator> DerefMut for Box<T, A> {
+            fn deref_mut(&mut self) -> &mut T {
+                // SAFETY: the box owns an initialized `T` and is borrowed mutably
+                unsafe { self.ptr.as_mut() }
+            }
+        }
+
+        impl<T, A: Allocator> AsRef<T> for Box<T, A> {
+            fn as_ref(&self) -> &T {
+                self
+            }
+        }
+
+        impl<T, A: Allocator> AsMut<T> for Box<T, A> {
+            fn as_mut(&mut self) -> &mut T {
+                self
+            }
+        }
+
+        impl<T: Clone, A: Allocator + Clone> Clone for Box<T, A> {
+            fn clone(&self) -> Self {
+                Box::new_in((**self).clone(), self.alloc.clone())
+            }
+        }
+
+        impl<T: Default> Default for Box<T> {
+            fn default() -> Self {
+                Box::new(T::default())
+            }
+        }
+
+        impl<T: PartialEq, A: Allocator> PartialEq for Box<T, A> {
+            fn eq(&self, other: &Self) -> bool {
+                **self == **other
+            }
+        }
+
+        impl<T: Eq, A: Allocator> Eq for Box<T, A> {}
+
+        impl<T: fmt::Debug, A: Allocator> fmt::Debug for Box<T, A> {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                fmt::Debug::fmt(&**self, f)
+            }
+        }
+
+        impl<T: fmt::Display, A: Allocator> fmt::Display for Box<T, A> {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                fmt::Display::fmt(&**self, f)
+            }
+        }
+
+        impl<T, A: Allocator> fmt::Pointer for Box<T, A> {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                fmt::Pointer::fmt(&self.ptr, f)
+            }
+        }
+
+        impl<T> From<std::boxed::Box<T>> for Box<T> {
+            fn from(b: std::boxed::Box<T>) -> Self {
+                // SAFETY: `Global` frees through the same global allocator as `std::boxed::Box`,
+                // except for zero-sized values, which neither of them allocates
+                unsafe { Box::from_raw(std::boxed::Box::into_raw(b)) }
+            }
+        }
+    }
+
     pub mod linked_list {
+        use crate::ds::Error;
+        use crate::ds::allocator::{Allocator, Box, Global};
+        use std::cmp::Ordering;
         use std::fmt::{Debug, Display};
+        use std::iter::FusedIterator;
+        use std::mem::ManuallyDrop;
         use std::ops::{Index, IndexMut};
+        use std::ptr;
+        use std::sync::Mutex;
         use std::thread;
 
         /// This module provides a Slngly Linked List struct
@@ -17,24 +524,47 @@ pub mod ds {
         ///
         /// Functions implemented:
         /// * [new](struct.ListNode.html#method.new) -> `Box<Self>`
+        /// * [new_in](struct.ListNode.html#method.new_in) -> `Box<Self, A>`
         /// * [from_vec](struct.ListNode.html#method.from_vec) -> `Box<Self>`
+        /// * [try_from_vec](struct.ListNode.html#method.try_from_vec) -> `Result<Box<Self>, Error>`
+        /// * [try_from_iter](struct.ListNode.html#method.try_from_iter) -> `Result<Box<Self>, Error>`
         /// * [print](struct.ListNode.html#method.print) -> `()`
         /// * [push](struct.ListNode.html#method.push) -> `()`
         /// * [push_back](struct.ListNode.html#method.push_back) -> `()`
-        /// * [delete](struct.ListNode.html#method.delete) -> `Result<(), &'static str>`
-        /// * [find](struct.ListNode.html#method.find) -> `Result<&Box<Self>, &'static str>`
+        /// * [delete](struct.ListNode.html#method.delete) -> `Result<(), Error>`
+        /// * [find](struct.ListNode.html#method.find) -> `Result<&Self, Error>`
         /// * [len](struct.ListNode.html#method.len) -> `i32`
         /// * [reverse](struct.ListNode.html#method.reverse) -> `()`
         /// * [copy](struct.ListNode.html#method.copy) -> `Box<ListNode<T>>`
-        /// * [insert](struct.ListNode.html#method.insert) -> `()`
+        /// * [insert](struct.ListNode.html#method.insert) -> `Result<(), Error>`
+        /// * [try_get](struct.ListNode.html#method.try_get) -> `Result<&T, Error>`
+        /// * [try_get_mut](struct.ListNode.html#method.try_get_mut) -> `Result<&mut T, Error>`
         /// * [pop](struct.ListNode.html#method.pop) -> `Option<T>`
         /// * [contains](struct.ListNode.html#method.contains) -> `bool`
-        /// * [merge](struct.ListNode.html#method.merge) -> `Option<Box<ListNode<i32>>>`
-        /// * [sort](struct.ListNode.html#method.sort) -> `Option<Box<ListNode<i32>>>`
-        #[derive(Clone, Debug)]
-        pub struct ListNode<T> {
+        /// * [iter](struct.ListNode.html#method.iter) -> `Iter<'_, T>`
+        /// * [iter_mut](struct.ListNode.html#method.iter_mut) -> `IterMut<'_, T>`
+        /// * [merge](struct.ListNode.html#method.merge) -> `Option<Box<Self>>`
+        /// * [merge_by](struct.ListNode.html#method.merge_by) -> `Option<Box<Self>>`
+        /// * [sort](struct.ListNode.html#method.sort) -> `Option<Box<Self>>`
+        /// * [sort_by](struct.ListNode.html#method.sort_by) -> `Option<Box<Self>>`
+        /// * [sort_by_key](struct.ListNode.html#method.sort_by_key) -> `Option<Box<Self>>`
+        /// * [sort_unstable](struct.ListNode.html#method.sort_unstable) -> `Option<Box<Self>>`
+        /// * [par_sort](struct.ListNode.html#method.par_sort) -> `Option<Box<Self>>`
+        /// * [par_sort_by](struct.ListNode.html#method.par_sort_by) -> `Option<Box<Self>>`
+        /// * [cursor_mut](struct.ListNode.html#method.cursor_mut) -> `CursorMut<'_, T>`
+        ///
+        /// `Drop`, `Clone` and `Debug` walk the list iteratively, so lists of
+        /// millions of nodes don't overflow the stack.
+        ///
+        /// The links are `ds::allocator::Box`es, so the nodes of a `ListNode<T, A>`
+        /// live in the allocator `A`, which defaults to `Global`. Each box carries its
+        /// own allocator, so nodes can be moved between chains freely. The functions
+        /// allocating new nodes other than [new_in](struct.ListNode.html#method.new_in)
+        /// only exist for `Global`, as a node has no allocator to hand out;
+        /// `LinkedList<T, A>` keeps one and allocates through it.
+        pub struct ListNode<T, A: Allocator = Global> {
             pub val: T,
-            pub next: Option<Box<ListNode<T>>>,
+            pub next: Option<Box<ListNode<T, A>, A>>,
         }
 
         impl<T> ListNode<T> {
@@ -43,7 +573,7 @@ pub mod ds {
             ///
             /// # Example
             /// ```
-            /// # use crate::data_structure::ds::linked_list::ListNode;
+            /// # use crate::data_structure::ds::{allocator::Box, linked_list::ListNode};
             /// let linked_list = ListNode::new(0);
             /// assert_eq!(linked_list, Box::new( ListNode{ val: 0, next: None } ));
             /// ```
@@ -59,7 +589,7 @@ pub mod ds {
             ///
             /// # Example
             /// ```
-            /// # use crate::data_structure::ds::linked_list::ListNode;
+            /// # use crate::data_structure::ds::{allocator::Box, linked_list::ListNode};
             /// assert_eq!(
             ///     ListNode::from_vec(vec![1, 2, 3]),
             ///     Box::new(ListNode {
@@ -74,42 +604,37 @@ pub mod ds {
             ///     })
             /// );
             /// ```
-            pub fn from_vec(l: Vec<T>) -> Box<Self>
-            where
-                T: Clone,
-            {
-                if l.is_empty() {
-                    panic!("Vector can't be empty");
-                }
+            pub fn from_vec(l: Vec<T>) -> Box<Self> {
+                ListNode::try_from_vec(l).expect("Vector can't be empty")
+            }
 
-                let mut list = ListNode::new(l[0].clone());
-                let mut head = &mut list;
-                for i in 1..l.len() {
-                    let newnode = ListNode::new(l[i].clone());
-                    head.next = Some(newnode);
-                    head = head.next.as_mut().unwrap();
-                }
-                list
+            /// Non-panicking version of [from_vec](struct.ListNode.html#method.from_vec),
+            /// returning `Err(Error::Empty)` if the `Vec<T>` is empty
+            ///
+            /// # Example
+            /// ```
+            /// # use crate::data_structure::ds::{Error, linked_list::ListNode};
+            /// assert_eq!(ListNode::<i32>::try_from_vec(vec![]), Err(Error::Empty));
+            /// assert_eq!(ListNode::try_from_vec(vec![1]), Ok(ListNode::new(1)));
+            /// ```
+            pub fn try_from_vec(l: Vec<T>) -> Result<Box<Self>, Error> {
+                ListNode::try_from_iter(l)
             }
 
-            /// Prints the provided `ListNode<T>`
+            /// Constructs a new instance of `ListNode<T>` from an iterator,
+            /// returning `Err(Error::Empty)` if the iterator yields nothing
             ///
             /// # Example
             /// ```
-            /// # use crate::data_structure::ds::linked_list::ListNode;
-            /// let list = ListNode::from_vec(vec![1, 2, 3]);
-            /// list.print(); //Output = 1 -> 2 -> 3 -> None
-            pub fn print(&self) -> ()
-            where
-                T: Display,
-            {
-                let mut head = Some(self);
-                while let Some(node) = head {
-                    print!("{} ({:p}) -> ", node.val, node);
-                    head = node.next.as_deref();
-                }
-                print!("None");
-                println!();
+            /// # use crate::data_structure::ds::{Error, linked_list::ListNode};
+            /// assert_eq!(ListNode::try_from_iter(1..4), Ok(ListNode::from_vec(vec![1, 2, 3])));
+            /// assert_eq!(ListNode::try_from_iter(1..1), Err(Error::Empty));
+            /// ```
+            pub fn try_from_iter<I: IntoIterator<Item = T>>(iter: I) -> Result<Box<Self>, Error> {
+                let mut iter = iter.into_iter();
+                let mut list = ListNode::new(iter.next().ok_or(Error::Empty)?);
+                list.extend(iter);
+                Ok(list)
             }
 
             /// Pushes an instance of `ListNode<T>` to the front of the list
@@ -121,17 +646,9 @@ pub mod ds {
             /// list.push(1);
             /// assert_eq!(list, ListNode::from_vec(vec![1, 2, 3]));
             /// ```
-            pub fn push(&mut self, val: T) -> ()
-            where
-                T: Clone,
-            {
-                let newnode = ListNode::new(val);
-                let mut newhead = newnode;
-                newhead.next = Some(Box::new(ListNode {
-                    val: self.val.clone(),
-                    next: self.next.take(),
-                }));
-                *self = *newhead;
+            pub fn push(&mut self, val: T) {
+                let oldhead = std::mem::replace(self, ListNode { val, next: None });
+                self.next = Some(Box::new(oldhead));
             }
 
             /// Pushes an instance of `ListNode<T>` to the back of the list
@@ -143,7 +660,7 @@ pub mod ds {
             /// list.push_back(3);
             /// assert_eq!(list, ListNode::from_vec(vec![1, 2, 3]));
             /// ```
-            pub fn push_back(&mut self, val: T) -> ()
+            pub fn push_back(&mut self, val: T)
             where
                 T: Copy,
             {
@@ -157,103 +674,235 @@ pub mod ds {
                 }
             }
 
-            /// Deletes the node that equals to the given `val: T`
-            ///
-            /// The function will delete the first node in the sequence
-            /// iterating from `head`, not by index.
-            ///
-            /// If the element is not found, the program would panic,
-            /// yielding `Node not found`.
+            /// Deep copies the given `ListNode<T>`
             ///
             /// # Example
             /// ```
             /// # use crate::data_structure::ds::linked_list::ListNode;
-            /// # fn foo() -> Result<(), &'static str> {
-            /// let mut list = ListNode::from_vec(vec![1, 2, 2, 3]);
-            /// list.delete(2)?;
-            /// assert_eq!(list, ListNode::from_vec(vec![1, 2, 3]));
-            /// # Ok(())
-            /// # }
-            pub fn delete(&mut self, val: T) -> Result<(), &'static str>
+            /// let list = ListNode::from_vec(vec![1, 2, 3]);
+            /// let copied = list.copy();
+            /// assert_eq!(list, copied);
+            /// // compares the pointers
+            /// assert_ne!(
+            ///     (format!(
+            ///         "{:p}{:p}{:p}",
+            ///         list,
+            ///         list.next.as_ref().unwrap(),
+            ///         list.next.as_ref().unwrap().next.as_ref().unwrap())),
+            ///     (format!(
+            ///         "{:p}{:p}{:p}",
+            ///         copied,
+            ///         copied.next.as_ref().unwrap(),
+            ///         copied.next.as_ref().unwrap().next.as_ref().unwrap())),
+            /// );
+            /// ```
+            pub fn copy(&self) -> Box<ListNode<T>>
             where
-                T: PartialEq,
+                T: Clone,
             {
-                let mut head = self;
-                loop {
-                    if head
-                        .next
-                        .as_ref()
-                        .unwrap_or_else(|| {
-                            panic!("Node not found");
-                        })
-                        .val
-                        == val
-                    {
-                        let nextnode = head.next.as_mut().unwrap();
-                        if nextnode.val == val {
-                            head.next = nextnode.next.take();
-                            return Ok(());
-                        }
-                        return Err("Node not found");
-                    }
-                    head = head.next.as_deref_mut().unwrap();
-                }
+                Box::new(self.clone())
             }
 
-            /// Finds the node with the give `val: T` and
-            /// returns a reference of element.
+            /// Inserts a new node of `ListNode<T>` with the `val: T` and in position `index: usize`
             ///
-            /// This function does not return a new copy of the linked-list, it
-            /// returns a reference to a node already on the list.
+            /// if the index is out of range, the function would return
+            /// `Err(Error::IndexOutOfRange)`
             ///
             /// # Example
             /// ```
-            /// # use crate::data_structure::ds::linked_list::ListNode;
-            /// # fn foo<T: Default>() -> Result<Box<ListNode<T>>, &'static str> {
-            ///  let mut list = ListNode::from_vec(vec![1, 2, 3]);
-            ///  let found_node = list.find(2)?;
-            ///  assert_eq!(found_node.val, 2);
-            ///  // 1 (list's head) -> 2 (found_node's head) -> 3;
-            ///  // find() does not copy the original list
-            ///  // so the two pointers could potentially
-            ///  // be the same element
-            ///  let mut list2 = ListNode::from_vec(vec![1, 2, 3]);
-            ///  {
-            ///     let found_node2 = list2.find(1)?;
-            ///     assert_eq!(found_node2.val, 1);
-            ///  }
-            ///  // compares the pointers
-            ///  assert_eq!(
-            ///     format!("{:p}", list2.as_ref()),
-            ///     format!("{:p}", list2.find(1)?.as_ref())
-            /// );
-            ///  // 1 (list2's head and found_node2's head) -> 2 -> 3
-            ///  // you could print the list as well, as the
-            ///  // print function prints the pointer address too
-            /// # Ok(Box::new(*ListNode::new(T::default())))
+            /// # use crate::data_structure::ds::{Error, linked_list::ListNode};
+            /// # fn foo() -> Result<(), Error> {
+            ///  let mut list = ListNode::from_vec(vec![1, 3]);
+            ///  list.insert(1, 2)?;
+            ///  assert_eq!(list, ListNode::from_vec(vec![1, 2, 3]));
+            /// # Ok(())
             /// # }
+            /// # foo().unwrap();
             /// ```
-            pub fn find(&mut self, val: T) -> Result<&Box<Self>, &'static str>
-            where
-                T: PartialEq,
-            {
+            pub fn insert(&mut self, index: usize, val: T) -> Result<(), Error> {
+                if index == 0 {
+                    let tempnode = Box::new(ListNode {
+                        val,
+                        next: self.next.take(),
+                    });
+                    self.next = Some(tempnode);
+                    std::mem::swap(&mut self.val, &mut self.next.as_mut().unwrap().val);
+                    return Ok(());
+                }
+
+                let mut head = self;
+                for step in 0..index - 1 {
+                    head = match head.next.as_mut() {
+                        Some(node) => node,
+                        None => return Err(Error::IndexOutOfRange { index, len: step + 1 }),
+                    };
+                }
+
+                let tempnode = Box::new(ListNode {
+                    val,
+                    next: head.next.take(),
+                });
+                head.next = Some(tempnode);
+                Ok(())
+            }
+
+            /// Returns a cursor over the chain starting at `head`,
+            /// pointing at its first element
+            ///
+            /// Like [merge](struct.ListNode.html#method.merge), this works on an
+            /// `Option<Box<ListNode<T>>>` so the chain may be emptied through the cursor.
+            ///
+            /// # Example
+            /// ```
+            /// # use crate::data_structure::ds::linked_list::ListNode;
+            /// let mut head = Some(ListNode::new(1));
+            /// let mut cursor = ListNode::cursor_mut(&mut head);
+            /// cursor.insert_after(2);
+            /// assert_eq!(cursor.remove_current(), Some(1));
+            /// assert_eq!(cursor.remove_current(), Some(2));
+            /// assert_eq!(head, None);
+            /// ```
+            pub fn cursor_mut(head: &mut Option<Box<Self>>) -> CursorMut<'_, T> {
+                CursorMut {
+                    link: Some(head),
+                    index: 0,
+                    len: None,
+                    alloc: &Global,
+                }
+            }
+        }
+
+        impl<T, A: Allocator> ListNode<T, A> {
+            /// Constructs a new instance of `ListNode<T, A>` with the provided `val: T`,
+            /// placed in memory from `alloc`
+            ///
+            /// # Example
+            /// ```
+            /// # use crate::data_structure::ds::allocator::Global;
+            /// # use crate::data_structure::ds::linked_list::ListNode;
+            /// let linked_list = ListNode::new_in(0, Global);
+            /// assert_eq!(linked_list, ListNode::new(0));
+            /// ```
+            pub fn new_in(val: T, alloc: A) -> Box<Self, A> {
+                Box::new_in(ListNode { val, next: None }, alloc)
+            }
+
+            /// Prints the provided `ListNode<T>`
+            ///
+            /// # Example
+            /// ```
+            /// # use crate::data_structure::ds::linked_list::ListNode;
+            /// let list = ListNode::from_vec(vec![1, 2, 3]);
+            /// list.print(); //Output = 1 -> 2 -> 3 -> None
+            pub fn print(&self)
+            where
+                T: Display,
+            {
+                let mut head = Some(self);
+                while let Some(node) = head {
+                    print!("{} ({:p}) -> ", node.val, node);
+                    head = node.next.as_deref();
+                }
+                print!("None");
+                println!();
+            }
+
+            /// Deletes the node that equals to the given `val: T`
+            ///
+            /// The function will delete the first node in the sequence
+            /// iterating from `head`, not by index.
+            ///
+            /// If the element is not found, `Err(Error::NotFound)` is returned.
+            /// A `ListNode<T>` can't be empty, so deleting its only node returns
+            /// `Err(Error::Empty)`; use `LinkedList<T>` if the list may run empty.
+            ///
+            /// # Example
+            /// ```
+            /// # use crate::data_structure::ds::{Error, linked_list::ListNode};
+            /// # fn foo() -> Result<(), Error> {
+            /// let mut list = ListNode::from_vec(vec![1, 2, 2, 3]);
+            /// list.delete(2)?;
+            /// assert_eq!(list, ListNode::from_vec(vec![1, 2, 3]));
+            /// assert_eq!(list.delete(4), Err(Error::NotFound));
+            /// # Ok(())
+            /// # }
+            /// # foo().unwrap();
+            /// ```
+            pub fn delete(&mut self, val: T) -> Result<(), Error>
+            where
+                T: PartialEq,
+            {
+                if self.val == val {
+                    if self.next.is_none() {
+                        return Err(Error::Empty);
+                    }
+                    self.shift();
+                    return Ok(());
+                }
+
                 let mut head = self;
                 loop {
-                    if head
-                        .next
-                        .as_ref()
-                        .unwrap_or_else(|| {
-                            panic!("Node not found");
-                        })
-                        .val
-                        == val
-                    {
-                        return Ok(head.next.as_ref().unwrap());
+                    match head.next.as_ref() {
+                        None => return Err(Error::NotFound),
+                        Some(nextnode) if nextnode.val == val => {
+                            let mut nextnode = head.next.take().unwrap();
+                            head.next = nextnode.next.take();
+                            return Ok(());
+                        }
+                        Some(_) => head = head.next.as_deref_mut().unwrap(),
                     }
-                    head = head.next.as_deref_mut().unwrap();
                 }
             }
 
+            /// Finds the node with the give `val: T` and
+            /// returns a reference of element.
+            ///
+            /// This function does not return a new copy of the linked-list, it
+            /// returns a reference to a node already on the list.
+            ///
+            /// If the element is not found, `Err(Error::NotFound)` is returned.
+            ///
+            /// # Example
+            /// ```
+            /// # use crate::data_structure::ds::{Error, linked_list::ListNode};
+            /// # fn foo() -> Result<(), Error> {
+            ///  let list = ListNode::from_vec(vec![1, 2, 3]);
+            ///  let found_node = list.find(2)?;
+            ///  assert_eq!(found_node.val, 2);
+            ///  // 1 (list's head) -> 2 (found_node's head) -> 3;
+            ///  // find() does not copy the original list
+            ///  // so the two pointers could potentially
+            ///  // be the same element
+            ///  let list2 = ListNode::from_vec(vec![1, 2, 3]);
+            ///  let found_node2 = list2.find(1)?;
+            ///  assert_eq!(found_node2.val, 1);
+            ///  // compares the pointers
+            ///  assert_eq!(
+            ///     format!("{:p}", list2.as_ref()),
+            ///     format!("{:p}", found_node2)
+            /// );
+            ///  // 1 (list2's head and found_node2's head) -> 2 -> 3
+            ///  // you could print the list as well, as the
+            ///  // print function prints the pointer address too
+            ///  assert_eq!(list2.find(4).unwrap_err(), Error::NotFound);
+            /// # Ok(())
+            /// # }
+            /// # foo().unwrap();
+            /// ```
+            pub fn find(&self, val: T) -> Result<&Self, Error>
+            where
+                T: PartialEq,
+            {
+                let mut head = Some(self);
+                while let Some(node) = head {
+                    if node.val == val {
+                        return Ok(node);
+                    }
+                    head = node.next.as_deref();
+                }
+                Err(Error::NotFound)
+            }
+
             /// Return the length of the given `ListNode<T>` as an i32
             ///
             /// # Example
@@ -262,6 +911,7 @@ pub mod ds {
             /// let mut list = ListNode::from_vec(vec![1, 2, 3]);
             /// assert_eq!(list.len(), 3);
             /// ```
+            #[allow(clippy::len_without_is_empty)] // a `ListNode` always holds at least one element
             pub fn len(&mut self) -> i32 {
                 let mut head = self;
                 let mut count: i32 = 0;
@@ -286,126 +936,123 @@ pub mod ds {
             /// rev_list.reverse();
             /// assert_eq!(list, rev_list);
             /// ```
-            pub fn reverse(&mut self)
-            where
-                T: Copy,
-            {
-                let mut prev = None;
-                let mut current = Some(Box::new(ListNode {
-                    val: std::mem::replace(&mut self.val, unsafe { std::mem::zeroed() }),
-                    next: self.next.take(),
-                }));
+            ///
+            /// The head node stays in place: its value is swapped with the last one,
+            /// and the old last node is relinked at the back, so no node is reallocated.
+            pub fn reverse(&mut self) {
+                let Some(mut new_head) = ListNode::reverse_chain(self.next.take()) else {
+                    return;
+                };
+
+                std::mem::swap(&mut self.val, &mut new_head.val);
+                self.next = new_head.next.take();
 
-                while let Some(mut boxed_node) = current {
-                    let next = boxed_node.next.take();
-                    boxed_node.next = prev;
-                    prev = Some(boxed_node);
-                    current = next;
+                let mut link = &mut self.next;
+                while let Some(node) = link {
+                    link = &mut node.next;
                 }
+                *link = Some(new_head);
+            }
 
-                if let Some(mut new_head) = prev {
-                    self.val = new_head.val;
-                    self.next = new_head.next.take();
+            /// Reverses the chain starting at `head`, returning its new head
+            fn reverse_chain(mut head: Option<Box<Self, A>>) -> Option<Box<Self, A>> {
+                let mut prev = None;
+                while let Some(mut node) = head {
+                    head = node.next.take();
+                    node.next = prev;
+                    prev = Some(node);
                 }
+                prev
             }
 
-            /// Deep copies the given `ListNode<T>`
+            /// Returns a reference to the element at `index: usize`,
+            /// or `Err(Error::IndexOutOfRange)` instead of panicking like `list[index]`
             ///
             /// # Example
             /// ```
-            /// # use crate::data_structure::ds::linked_list::ListNode;
+            /// # use crate::data_structure::ds::{Error, linked_list::ListNode};
             /// let list = ListNode::from_vec(vec![1, 2, 3]);
-            /// let copied = list.copy();
-            /// assert_eq!(list, copied);
-            /// // compares the pointers
-            /// assert_ne!(
-            ///     (format!(
-            ///         "{:p}{:p}{:p}",
-            ///         list,
-            ///         list.next.as_ref().unwrap(),
-            ///         list.next.as_ref().unwrap().next.as_ref().unwrap())),
-            ///     (format!(
-            ///         "{:p}{:p}{:p}",
-            ///         copied,
-            ///         copied.next.as_ref().unwrap(),
-            ///         copied.next.as_ref().unwrap().next.as_ref().unwrap())),
-            /// );
+            /// assert_eq!(list.try_get(1), Ok(&2));
+            /// assert_eq!(list.try_get(3), Err(Error::IndexOutOfRange { index: 3, len: 3 }));
             /// ```
-            pub fn copy(&self) -> Box<ListNode<T>>
-            where
-                T: Clone,
-            {
-                Box::new(ListNode {
-                    val: self.val.clone(),
-                    next: self.next.as_ref().map(|node| node.clone()),
-                })
+            pub fn try_get(&self, index: usize) -> Result<&T, Error> {
+                let mut head = self;
+                for step in 0..index {
+                    head = match head.next.as_deref() {
+                        Some(node) => node,
+                        None => return Err(Error::IndexOutOfRange { index, len: step + 1 }),
+                    };
+                }
+                Ok(&head.val)
             }
 
-            /// Inserts a new node of `ListNode<T>` with the `val: T` and in position `index: usize`
-            ///
-            /// if the index is out of range, the function would return Err
+            /// Returns a mutable reference to the element at `index: usize`,
+            /// or `Err(Error::IndexOutOfRange)` instead of panicking like `list[index]`
             ///
             /// # Example
             /// ```
-            /// # use crate::data_structure::ds::linked_list::ListNode;
-            /// # fn foo() -> Result<(), &'static str> {
-            ///  let mut list = ListNode::from_vec(vec![1, 3]);
-            ///  list.insert(1, 2)?;
-            ///  assert_eq!(list, ListNode::from_vec(vec![1, 2, 3]));
-            /// # Ok(())
-            /// # }
+            /// # use crate::data_structure::ds::{Error, linked_list::ListNode};
+            /// let mut list = ListNode::from_vec(vec![1, 2, 3]);
+            /// *list.try_get_mut(1).unwrap() = 5;
+            /// assert_eq!(list, ListNode::from_vec(vec![1, 5, 3]));
+            /// assert!(list.try_get_mut(4).is_err());
             /// ```
-            pub fn insert(&mut self, index: usize, val: T) -> Result<(), &'static str> {
-                if index == 0 {
-                    let tempnode = Box::new(ListNode {
-                        val,
-                        next: self.next.take(),
-                    });
-                    self.next = Some(tempnode);
-                    std::mem::swap(&mut self.val, &mut self.next.as_mut().unwrap().val);
-                    return Ok(());
-                }
-
+            pub fn try_get_mut(&mut self, index: usize) -> Result<&mut T, Error> {
                 let mut head = self;
-                for _ in 0..index - 1 {
-                    head = match head.next.as_mut() {
+                for step in 0..index {
+                    head = match head.next.as_deref_mut() {
                         Some(node) => node,
-                        None => return Err("Index out of range"),
+                        None => return Err(Error::IndexOutOfRange { index, len: step + 1 }),
                     };
                 }
-
-                let tempnode = Box::new(ListNode {
-                    val,
-                    next: head.next.take(),
-                });
-                head.next = Some(tempnode);
-                Ok(())
+                Ok(&mut head.val)
             }
 
-            /// Pops the element on the front of the list
+            /// Pops the element on the front of the list starting at `head`
+            ///
+            /// A `ListNode<T>` can't be empty, so this takes the link owning the
+            /// first node: popping the last element returns it and leaves `head`
+            /// as `None`, the empty list.
             ///
             /// # Example
             /// ```
             /// # use crate::data_structure::ds::linked_list::ListNode;
-            /// # fn foo<T>() -> Option<T> {
-            /// let mut list = ListNode::from_vec(vec![1, 1, 2, 3]);
-            /// list.pop()?;
-            /// assert_eq!(list, ListNode::from_vec(vec![1, 2, 3]));
-            /// # None
-            /// # }
+            /// let mut list = Some(ListNode::from_vec(vec![1, 1, 2, 3]));
+            /// assert_eq!(ListNode::pop(&mut list), Some(1));
+            /// assert_eq!(list, Some(ListNode::from_vec(vec![1, 2, 3])));
+            ///
+            /// let mut single = Some(ListNode::new(String::from("only")));
+            /// assert_eq!(ListNode::pop(&mut single).as_deref(), Some("only"));
+            /// assert_eq!(single, None);
+            /// assert_eq!(ListNode::pop(&mut single), None);
             /// ```
-            pub fn pop(&mut self) -> Option<T> {
-                if self.next.is_none() {
-                    return Some(std::mem::replace(&mut self.val, unsafe {
-                        std::mem::zeroed()
-                    }));
-                }
+            pub fn pop(head: &mut Option<Box<Self, A>>) -> Option<T> {
+                let (val, next) = ListNode::into_parts(head.take()?);
+                *head = next;
+                Some(val)
+            }
 
-                let mut oldhead = self.next.take().unwrap();
-                std::mem::swap(&mut self.val, &mut oldhead.val);
-                self.next = oldhead.next.take();
+            /// Moves the second element into this node, returning the first one,
+            /// or `None` if this is the only node
+            fn shift(&mut self) -> Option<T> {
+                let (mut val, next) = ListNode::into_parts(self.next.take()?);
+                std::mem::swap(&mut self.val, &mut val);
+                self.next = next;
+                Some(val)
+            }
 
-                Some(oldhead.val)
+            /// Takes the boxed node apart, freeing it and returning its value
+            /// and the rest of the list
+            ///
+            /// Fields can't be moved out of a `ListNode<T>` as it implements `Drop`.
+            pub(crate) fn into_parts(node: Box<Self, A>) -> (T, Option<Box<Self, A>>) {
+                let mut node = Box::into_inner(node);
+                let next = node.next.take();
+                let node = ManuallyDrop::new(node);
+                // SAFETY: `node` is never dropped, so `val` is read out of it exactly once,
+                // and its `next` has already been taken so nothing is leaked.
+                let val = unsafe { ptr::read(&node.val) };
+                (val, next)
             }
 
             /// Checks if whether the `ListNode<T>` contains the given `val: T` element
@@ -420,22 +1067,50 @@ pub mod ds {
             where
                 T: PartialEq,
             {
-                let mut head = self;
-                while !head.next.is_none() {
-                    if head.val == val {
-                        return true;
-                    } else {
-                        head = head.next.as_deref().unwrap();
-                    }
+                self.iter().any(|node_val| *node_val == val)
+            }
+
+            /// Returns an iterator over references to the elements of the list
+            ///
+            /// # Example
+            /// ```
+            /// # use crate::data_structure::ds::linked_list::ListNode;
+            /// let list = ListNode::from_vec(vec![1, 2, 3]);
+            /// let doubled: Vec<i32> = list.iter().map(|val| val * 2).collect();
+            /// assert_eq!(doubled, vec![2, 4, 6]);
+            /// ```
+            pub fn iter(&self) -> Iter<'_, T, A> {
+                Iter {
+                    next: Some(self),
+                    len: None,
                 }
-                false
             }
 
-            /// Merges two sorted `ListNode<T>` while keeping the order
+            /// Returns an iterator over mutable references to the elements of the list
             ///
             /// # Example
             /// ```
             /// # use crate::data_structure::ds::linked_list::ListNode;
+            /// let mut list = ListNode::from_vec(vec![1, 2, 3]);
+            /// for val in list.iter_mut() {
+            ///     *val *= 10;
+            /// }
+            /// assert_eq!(list, ListNode::from_vec(vec![10, 20, 30]));
+            /// ```
+            pub fn iter_mut(&mut self) -> IterMut<'_, T, A> {
+                IterMut {
+                    next: Some(self),
+                    len: None,
+                }
+            }
+
+            /// Merges two sorted `ListNode<T>` while keeping the order
+            ///
+            /// The merge is stable: of two equal elements, the one from `l1` comes first.
+            ///
+            /// # Example
+            /// ```
+            /// # use crate::data_structure::ds::{allocator::Box, linked_list::ListNode};
             /// # fn foo() -> Option<Box<ListNode<i32>>> {
             /// let temp1 = ListNode::from_vec(vec![1, 3]);
             /// let temp2 = ListNode::from_vec(vec![2, 4, 5]);
@@ -443,489 +1118,9370 @@ pub mod ds {
             /// assert_eq!(list, ListNode::from_vec(vec![1, 2, 3, 4, 5]));
             /// # Some(ListNode::new(0))
             /// # }
+            /// # foo().unwrap();
             /// ```
-            pub fn merge(
-                mut l1: Option<Box<ListNode<i32>>>,
-                mut l2: Option<Box<ListNode<i32>>>,
-            ) -> Option<Box<ListNode<i32>>>
+            pub fn merge(l1: Option<Box<Self, A>>, l2: Option<Box<Self, A>>) -> Option<Box<Self, A>>
             where
-                T: PartialOrd,
+                T: Ord,
             {
-                let mut r = &mut l1;
-                while l2.is_some() {
-                    if r.is_none() || l2.as_ref()?.val < r.as_ref()?.val {
-                        std::mem::swap(r, &mut l2);
-                    }
-                    r = &mut r.as_mut()?.next;
-                }
-                l1
+                ListNode::merge_by(l1, l2, T::cmp)
             }
 
-            fn split_list(head: Option<Box<ListNode<i32>>>, k: i32) -> Vec<Option<Box<ListNode<i32>>>> {
-                let mut length = 0;
-                let mut current = head.as_ref();
-                let mut parts = Vec::new();
-
-                while let Some(node) = current {
-                    length += 1;
-                    current = node.next.as_ref();
+            /// Merges two `ListNode<T>` sorted with respect to `cmp` while keeping the order
+            ///
+            /// The nodes are relinked rather than copied, and the merge is stable:
+            /// of two equal elements, the one from `l1` comes first.
+            ///
+            /// # Example
+            /// ```
+            /// # use crate::data_structure::ds::linked_list::ListNode;
+            /// let temp1 = ListNode::from_vec(vec![5, 3, 1]);
+            /// let temp2 = ListNode::from_vec(vec![4, 2]);
+            /// let list = ListNode::merge_by(Some(temp1), Some(temp2), |a, b| b.cmp(a));
+            /// assert_eq!(list, Some(ListNode::from_vec(vec![5, 4, 3, 2, 1])));
+            /// ```
+            pub fn merge_by<F>(
+                mut l1: Option<Box<Self, A>>,
+                mut l2: Option<Box<Self, A>>,
+                mut cmp: F,
+            ) -> Option<Box<Self, A>>
+            where
+                F: FnMut(&T, &T) -> Ordering,
+            {
+                let mut merged = None;
+                let mut tail = &mut merged;
+                while let (Some(left), Some(right)) = (l1.as_deref(), l2.as_deref()) {
+                    let source = if cmp(&right.val, &left.val) == Ordering::Less {
+                        &mut l2
+                    } else {
+                        &mut l1
+                    };
+                    let mut node = source.take().unwrap();
+                    *source = node.next.take();
+                    tail = &mut tail.insert(node).next;
                 }
+                *tail = l1.or(l2);
+                merged
+            }
 
-                let (base_size, mut extra) = (length / k, length % k);
-                let mut current = head;
-
-                for _ in 0..k {
-                    let mut part_size = base_size + if extra > 0 { 1 } else { 0 };
-                    let mut dummy = Box::new(ListNode { val: 0, next: None });
-                    let mut tail = &mut dummy;
-
-                    while part_size > 0 {
-                        tail.next = current.take();
-                        tail = tail.next.as_mut().unwrap();
-                        current = tail.next.take();
-                        part_size -= 1;
+            /// Detaches and returns every node after the first `at` nodes of `head`
+            fn split_off(head: &mut Option<Box<Self, A>>, at: usize) -> Option<Box<Self, A>> {
+                let mut link = head;
+                for _ in 0..at {
+                    match link {
+                        Some(node) => link = &mut node.next,
+                        None => return None,
                     }
+                }
+                link.take()
+            }
 
-                    parts.push(dummy.next.take());
-                    if extra > 0 {
-                        extra -= 1;
-                    }
+            fn merge_sort<F>(mut head: Option<Box<Self, A>>, len: usize, cmp: &mut F) -> Option<Box<Self, A>>
+            where
+                F: FnMut(&T, &T) -> Ordering,
+            {
+                if len <= 1 {
+                    return head;
                 }
 
-                parts
+                let mid = len / 2;
+                let right = ListNode::split_off(&mut head, mid);
+                let left_sorted = ListNode::merge_sort(head, mid, cmp);
+                let right_sorted = ListNode::merge_sort(right, len - mid, cmp);
+
+                ListNode::merge_by(left_sorted, right_sorted, cmp)
             }
 
             /// Sorts the `ListNode<T>` through merge sort
             ///
+            /// The sort is stable and relinks the existing nodes instead of cloning them.
+            ///
             /// # Example
             /// ```
-            /// # use crate::data_structure::ds::linked_list::ListNode;
+            /// # use crate::data_structure::ds::{allocator::Box, linked_list::ListNode};
             /// # fn foo() -> Option<Box<ListNode<i32>>> {
             /// let list = ListNode::<i32>::sort(Some(ListNode::from_vec(vec![5, 2, 3, 1, 4])))?;
             /// assert_eq!(list, ListNode::from_vec(vec![1, 2, 3, 4, 5]));
             /// # Some(ListNode::new(0))
             /// # }
+            /// # foo().unwrap();
             /// ```
-            pub fn sort(head: Option<Box<ListNode<i32>>>) -> Option<Box<ListNode<i32>>> {
-                if head.is_none() || head.as_ref().unwrap().next.is_none() {
-                    return head;
-                }
-
-                let (left, right) = {
-                    let v = ListNode::<i32>::split_list(head, 2);
-                    (
-                        v[0].as_ref().unwrap().as_ref().clone(),
-                        v[1].as_ref().unwrap().as_ref().clone(),
-                    )
-                };
-
-                let left_handle = thread::spawn(move || ListNode::<i32>::sort(Some(Box::new(left))));
-                let right_handle = thread::spawn(move || ListNode::<i32>::sort(Some(Box::new(right))));
-
-                let left_sorted = left_handle.join().unwrap();
-                let right_sorted = right_handle.join().unwrap();
-
-                ListNode::<i32>::merge(left_sorted, right_sorted)
+            pub fn sort(head: Option<Box<Self, A>>) -> Option<Box<Self, A>>
+            where
+                T: Ord,
+            {
+                ListNode::sort_by(head, T::cmp)
             }
-        }
 
-        impl<T> Index<usize> for ListNode<T> {
-            type Output = T;
-
-            fn index(&self, index: usize) -> &Self::Output {
-                let mut head = self;
-
-                for _ in 0..index {
-                    head = head.next.as_deref().expect("Index out of range");
-                }
-                &head.val
-            }
-        }
-
-        impl<T> IndexMut<usize> for ListNode<T> {
-            fn index_mut(&mut self, index: usize) -> &mut Self::Output {
-                let mut head = self;
-
-                for _ in 0..index {
-                    head = head.next.as_deref_mut().expect("Index out of range");
-                }
-                &mut head.val
-            }
-        }
-
-        impl<T: PartialEq> PartialEq for ListNode<T> {
-            fn eq(&self, other: &Self) -> bool {
-                let mut head = Some(self);
-                let mut other_head = Some(other);
-                while let (Some(current), Some(other_current)) = (head, other_head) {
-                    if current.val != other_current.val {
-                        return false;
-                    }
-                    head = current.next.as_deref();
-                    other_head = other_current.next.as_deref();
-                }
-                head.is_none() && other_head.is_none()
-            }
-        }
-    }
-
-    /// This module provides a Stack struct named `Stack`
-    ///
-    /// Functions Implemented:
-    /// * [new](struct.Stack.html#method.new) -> `Self`
-    /// * [from_vec](struct.Stack.html#method.from_vec) -> `Self`
-    /// * [print](struct.Stack.html#method.print) -> `()`
-    /// * [pop](struct.Stack.html#method.pop) -> `T`
-    /// * [push](struct.Stack.html#method.push) -> `()`
-    /// * [peak](struct.Stack.html#method.peak) -> `T`
-    /// * [clear](struct.Stack.html#method.clear) -> `()`
-    pub mod stack {
-        use std::fmt::Display;
-        use crate::ds::linked_list::ListNode;
-
-        /// Stack implementation
-        #[derive(Debug, Clone)]
-        pub struct Stack<T> {
-            pub list: Box<ListNode<T>>,
-            pub len: usize
-        }
-
-        impl<T> Stack<T> {
-            /// Constructs a new instance of `Stack<T>` with the provided
-            /// `val: T`, returning `Self`
+            /// Sorts the `ListNode<T>` through merge sort with the comparator `cmp`
             ///
             /// # Example
             /// ```
-            /// # use crate::data_structure::ds::stack::Stack;
             /// # use crate::data_structure::ds::linked_list::ListNode;
-            /// let stack = Stack::new(1);
-            /// assert_eq!(stack, Stack{list: ListNode::new(1), len: 1});
+            /// let list = ListNode::from_vec(vec!["bb", "a", "ccc"]);
+            /// let list = ListNode::sort_by(Some(list), |a, b| b.len().cmp(&a.len()));
+            /// assert_eq!(list, Some(ListNode::from_vec(vec!["ccc", "bb", "a"])));
             /// ```
-            ///
-            /// There cannot be an empty stack if you are initialising it.
-            pub fn new(val: T) -> Self {
-                Stack {
-                    list: ListNode::new(val),
-                    len: 1
-                }
+            pub fn sort_by<F>(head: Option<Box<Self, A>>, mut cmp: F) -> Option<Box<Self, A>>
+            where
+                F: FnMut(&T, &T) -> Ordering,
+            {
+                let len = head.as_ref().map_or(0, |node| node.iter().count());
+                ListNode::merge_sort(head, len, &mut cmp)
             }
 
-            /// Constructs a new instance of `Stack<T>` with the provided
-            /// `Vec<T>`, returning `Self`
+            /// Sorts the `ListNode<T>` through merge sort, comparing the keys extracted by `f`
             ///
             /// # Example
             /// ```
-            /// # use crate::data_structure::ds::{stack::Stack, linked_list::ListNode};
-            /// let stack = Stack::from_vec(vec![1, 2, 3]);
-            /// assert_eq!(
-            ///     stack,
-            ///     Stack{
-            ///         len: 3,
-            ///         list: ListNode::from_vec(vec![1, 2, 3]),
-            ///     }
-            /// )
-            /// ```
-            pub fn from_vec(vec: Vec<T>) -> Self
-            where T:
-                Clone
-            {
-                let temp = vec.clone();
-                Stack {
-                    list: ListNode::from_vec(temp),
-                    len: vec.len()
-                }
-            }
-
-            /// Prints the given Stack.
-            ///
-            /// # Example Output
-            ///
-            /// ```text
-            /// +---+---+---+---+---+
-            /// | 1 | 2 | 3 | 4 | 5 |
-            /// +---+---+---+---+---+
-            ///   ↑
-            ///  HEAD
+            /// # use crate::data_structure::ds::linked_list::ListNode;
+            /// let list = ListNode::from_vec(vec![(2, 'a'), (1, 'b'), (2, 'c'), (1, 'd')]);
+            /// let list = ListNode::sort_by_key(Some(list), |pair| pair.0);
+            /// assert_eq!(list, Some(ListNode::from_vec(vec![(1, 'b'), (1, 'd'), (2, 'a'), (2, 'c')])));
             /// ```
-            ///
-            /// The stack is displayed with the top (HEAD) indicated.
-            pub fn print(&self)
-            where T:
-                Display
+            pub fn sort_by_key<K, F>(head: Option<Box<Self, A>>, mut f: F) -> Option<Box<Self, A>>
+            where
+                K: Ord,
+                F: FnMut(&T) -> K,
             {
-                let separator = "+---".repeat(self.len) + "+";
-
-                println!("{}", separator);
-
-                for i in 0..self.len {
-                    print!("| {:^2}", self.list[i]);
-                }
-
-                println!("|");
-                println!("{}", separator);
-                println!("  ↑");
-                println!(" HEAD");
+                ListNode::sort_by(head, |a, b| f(a).cmp(&f(b)))
             }
 
-            /// Pops the first element of the stack off, returning it
+            /// Sorts the `ListNode<T>` without guaranteeing the order of equal elements
             ///
-            /// # Example
-            /// ```
-            /// # use crate::data_structure::ds::stack::Stack;
-            /// let mut stack = Stack::from_vec(vec![1, 1, 2, 3, 4, 5]);
-            /// let poped_val = stack.pop();
-            /// assert_eq!(poped_val, 1);
-            /// ```
-            pub fn pop(&mut self) -> T {
-                let val = self.list.pop().unwrap();
-                self.len -= 1;
-                val
-            }
-
-            /// Pushes an element on to the top of the stack
+            /// Merge sort is already the fastest way to sort a linked list,
+            /// so this currently does the same as [sort](struct.ListNode.html#method.sort).
             ///
             /// # Example
             /// ```
-            /// # use crate::data_structure::ds::stack::Stack;
-            /// let mut stack = Stack::from_vec(vec![2, 3, 4, 5]);
-            /// stack.push(1);
-            /// assert_eq!(stack, Stack::from_vec(vec![1, 2, 3, 4, 5]));
+            /// # use crate::data_structure::ds::linked_list::ListNode;
+            /// let list = ListNode::sort_unstable(Some(ListNode::from_vec(vec![3, 1, 2])));
+            /// assert_eq!(list, Some(ListNode::from_vec(vec![1, 2, 3])));
             /// ```
-            pub fn push(&mut self, val: T)
-            where T:
-                Clone
+            pub fn sort_unstable(head: Option<Box<Self, A>>) -> Option<Box<Self, A>>
+            where
+                T: Ord,
             {
-                self.list.push(val);
-                self.len += 1;
+                ListNode::sort(head)
             }
 
-            /// Clears the entire stack, making it with one element with value of `T::default`
+            /// Sorts the `ListNode<T>` through a parallel merge sort tuned by `config`
+            ///
+            /// The list is cut into at most `config.threads` runs of at least
+            /// `config.sequential_cutoff` nodes, which are sorted and then merged
+            /// pairwise by a fixed-size pool of worker threads. Nodes are moved
+            /// between threads, never cloned, and the sort is stable.
             ///
             /// # Example
             /// ```
-            /// # use crate::data_structure::ds::{linked_list::ListNode, stack::Stack};
-            ///
-            /// let mut stack = Stack::from_vec(vec![1, 2, 3, 4, 5]);
-            /// stack.clear();
-            /// assert_eq!(stack, Stack{len: 1, list: ListNode::new(i32::default())});
+            /// # use crate::data_structure::ds::{allocator::Box, linked_list::{ListNode, SortConfig}};
+            /// let list: Box<ListNode<i32>> = (0..10_000).rev().collect();
+            /// let config = SortConfig { threads: 4, sequential_cutoff: 1_000 };
+            /// let list = ListNode::par_sort(Some(list), &config).unwrap();
+            /// assert!(list.iter().eq((0..10_000).collect::<Vec<_>>().iter()));
             /// ```
-            pub fn clear(&mut self)
-            where T:
-                Default
+            pub fn par_sort(head: Option<Box<Self, A>>, config: &SortConfig) -> Option<Box<Self, A>>
+            where
+                T: Ord + Send,
+                A: Send,
             {
-                self.list = ListNode::new(T::default());
-                self.len = 1;
+                ListNode::par_sort_by(head, config, T::cmp)
             }
 
-            /// Peaks at the top of the stack, returning the value `T`, but not removing it
+            /// Sorts the `ListNode<T>` through a parallel merge sort with the comparator `cmp`,
+            /// see [par_sort](struct.ListNode.html#method.par_sort)
             ///
             /// # Example
             /// ```
-            /// # use crate::data_structure::ds::stack::Stack;
-            /// let stack = Stack::from_vec(vec![1, 2, 3, 4, 5]);
-            /// let peak_val = stack.peak();
-            /// assert_eq!(peak_val, 1);
-            /// assert_eq!(stack, Stack::from_vec(vec![1, 2, 3, 4, 5]));
+            /// # use crate::data_structure::ds::linked_list::{ListNode, SortConfig};
+            /// let list = ListNode::from_vec(vec![1, 3, 2]);
+            /// let list = ListNode::par_sort_by(Some(list), &SortConfig::default(), |a, b| b.cmp(a));
+            /// assert_eq!(list, Some(ListNode::from_vec(vec![3, 2, 1])));
             /// ```
-            pub fn peak(&self) -> T
-            where T:
-                Clone
+            pub fn par_sort_by<F>(head: Option<Box<Self, A>>, config: &SortConfig, cmp: F) -> Option<Box<Self, A>>
+            where
+                T: Send,
+                A: Send,
+                F: Fn(&T, &T) -> Ordering + Sync,
             {
-                self.list.val.clone()
+                let len = head.as_ref().map_or(0, |node| node.iter().count());
+                ListNode::par_merge_sort(head, len, config, &cmp)
             }
-        }
 
-        impl<T: PartialEq + Clone + Copy> PartialEq for Stack<T> {
-            fn eq(&self, other: &Self) -> bool {
-                if self.len != other.len {
-                    return false;
+            fn par_merge_sort<F>(
+                mut head: Option<Box<Self, A>>,
+                len: usize,
+                config: &SortConfig,
+                cmp: &F,
+            ) -> Option<Box<Self, A>>
+            where
+                T: Send,
+                A: Send,
+                F: Fn(&T, &T) -> Ordering + Sync,
+            {
+                let threads = config.threads.max(1);
+                let run_count = (len / config.sequential_cutoff.max(1)).clamp(1, threads);
+                if run_count == 1 {
+                    return ListNode::merge_sort(head, len, &mut |a, b| cmp(a, b));
+                }
+
+                let mut runs = Vec::with_capacity(run_count);
+                for i in 0..run_count {
+                    let run_len = len / run_count + usize::from(i < len % run_count);
+                    let rest = ListNode::split_off(&mut head, run_len);
+                    runs.push((head, run_len));
+                    head = rest;
                 }
-                let head = *self.list.copy();
-                let other_head = *self.list.copy();
-                for i in 0..self.len {
-                    if head[i] != other_head[i] {
-                        return false;
+
+                let mut runs = run_on_pool(runs, threads, |(run, run_len)| {
+                    ListNode::merge_sort(run, run_len, &mut |a, b| cmp(a, b))
+                });
+
+                while runs.len() > 1 {
+                    let mut pairs = Vec::with_capacity(runs.len() / 2);
+                    let mut runs_iter = runs.into_iter();
+                    let mut leftover = None;
+                    while let Some(left) = runs_iter.next() {
+                        match runs_iter.next() {
+                            Some(right) => pairs.push((left, right)),
+                            None => leftover = Some(left),
+                        }
                     }
+
+                    runs = run_on_pool(pairs, threads, |(left, right)| {
+                        ListNode::merge_by(left, right, cmp)
+                    });
+                    runs.extend(leftover);
                 }
-                true
+                runs.pop().flatten()
             }
         }
-    }
-
-    pub mod vector {
-        use std::alloc;
-        use std::alloc::Layout;
-        use std::ptr::NonNull;
 
-        pub struct Vector<T> {
-            ptr: NonNull<T>,
-            len: usize,
-            cap: usize
+        /// Tuning for [ListNode::par_sort](struct.ListNode.html#method.par_sort)
+        /// and [LinkedList::par_sort](struct.LinkedList.html#method.par_sort)
+        ///
+        /// # Example
+        /// ```
+        /// # use crate::data_structure::ds::linked_list::SortConfig;
+        /// let config = SortConfig { threads: 2, ..SortConfig::default() };
+        /// assert_eq!(config.threads, 2);
+        /// ```
+        #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+        pub struct SortConfig {
+            /// Maximum number of worker threads sorting at the same time
+            pub threads: usize,
+            /// Lists shorter than this are sorted on the calling thread,
+            /// and no run handed to a worker is shorter than this
+            pub sequential_cutoff: usize,
         }
 
-        impl<T> Vector<T> {
+        impl SortConfig {
+            /// Constructs a `SortConfig` using every available core
+            /// and a cutoff of 4096 nodes
             pub fn new() -> Self {
-                assert_ne!(size_of::<T>(), 0, "Cannot accept a vector with element size 0");
-                Vector {
-                    ptr: NonNull::dangling(),
-                    len: 0,
-                    cap: 0,
+                SortConfig {
+                    threads: thread::available_parallelism().map_or(1, |n| n.get()),
+                    sequential_cutoff: 4096,
                 }
             }
+        }
 
-            pub fn grow(&mut self) {
-                let (new_cap, new_layout) = if self.cap == 0 {
-                    (1, Layout::array::<T>(1).unwrap())
-                } else {
-                    let new_cap = self.cap * 2;
-                    let new_layout = Layout::array::<T>(new_cap).unwrap();
-                    (new_cap, new_layout)
-                };
-
-                assert!(new_layout.size() <= isize::MAX as usize, "Too large of allocation");
-
-                let new_ptr = if self.cap == 0 {
-                    unsafe {alloc::alloc(new_layout)}
-                } else {
-                    let old_layout = Layout::array::<T>(self.cap).unwrap();
-                    let old_ptr = self.ptr.as_ptr() as *mut u8;
-                    unsafe {alloc::realloc(old_ptr, old_layout, new_layout.size())}
-                };
-
-                self.ptr = match NonNull::new(new_ptr as *mut T) {
-                    Some(p) => p,
-                    None => alloc::handle_alloc_error(new_layout),
-                };
-
-                self.cap = new_cap;
+        impl Default for SortConfig {
+            fn default() -> Self {
+                Self::new()
             }
+        }
 
-            pub fn push(&mut self, val: T) {
-                if self.len == self.cap { self.grow() }
+        /// Runs `work` over every job on at most `threads` scoped worker threads,
+        /// returning the results in the order of the jobs
+        fn run_on_pool<J, R, W>(jobs: Vec<J>, threads: usize, work: W) -> Vec<R>
+        where
+            J: Send,
+            R: Send,
+            W: Fn(J) -> R + Sync,
+        {
+            let count = jobs.len();
+            let queue = Mutex::new(jobs.into_iter().enumerate());
+            let results = Mutex::new((0..count).map(|_| None).collect::<Vec<Option<R>>>());
 
-                unsafe {
-                    ptr::write(self.ptr.as_ptr().add(self.len), val);
+            thread::scope(|scope| {
+                for _ in 0..threads.min(count) {
+                    scope.spawn(|| loop {
+                        let job = queue.lock().unwrap().next();
+                        let Some((index, job)) = job else { break };
+                        let result = work(job);
+                        results.lock().unwrap()[index] = Some(result);
+                    });
                 }
+            });
 
-                self.len += 1;
+            results
+                .into_inner()
+                .unwrap()
+                .into_iter()
+                .map(|result| result.expect("every job is run by a worker"))
+                .collect()
+        }
+
+        impl<T, A: Allocator> Index<usize> for ListNode<T, A> {
+            type Output = T;
+
+            fn index(&self, index: usize) -> &Self::Output {
+                self.try_get(index).unwrap_or_else(|e| panic!("{}", e))
             }
+        }
 
-            pub fn pop(&mut self) -> Option<T> {
-                if self.len == 0 {
-                    None
-                } else {
-                    self.len -= 1;
-                    unsafe {
-                        Some(ptr::read(self.ptr.as_ptr().add(self.len)))
-                    }
-                }
+        impl<T, A: Allocator> IndexMut<usize> for ListNode<T, A> {
+            fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+                self.try_get_mut(index).unwrap_or_else(|e| panic!("{}", e))
             }
         }
 
-        impl<T> Drop for Vector<T> {
+        impl<T, A: Allocator> Drop for ListNode<T, A> {
             fn drop(&mut self) {
-                while let Some(_) = self.pop() {}
-                unsafe {
-                    alloc::dealloc(
-                        self.ptr.as_ptr() as *mut u8,
-                        Layout::array::<T>(self.cap).unwrap()
-                    );
+                let mut next = self.next.take();
+                while let Some(mut node) = next {
+                    next = node.next.take();
                 }
             }
         }
-    }
-}
-
-#[cfg(test)]
-mod test {
-    use crate::ds::linked_list::ListNode;
-    use crate::ds::stack::Stack;
-
-    #[test]
-    fn test_linked_list() {
-        let mut l1 = ListNode::new(1);
-        let temp = ListNode::from_vec(vec![2, 3, 4, 5]);
-        l1.next = Some(temp);
-        assert_eq!(l1, ListNode::from_vec(vec![1, 2, 3, 4, 5]));
 
-        let mut l2 = ListNode::from_vec(vec![2, 3, 4, 5]);
-        l2.push(1);
-        assert_eq!(l2, ListNode::from_vec(vec![1, 2, 3, 4, 5]));
+        /// Every copied node is allocated by the allocator of the node it copies
+        impl<T: Clone, A: Allocator + Clone> Clone for ListNode<T, A> {
+            fn clone(&self) -> Self {
+                let mut head = ListNode {
+                    val: self.val.clone(),
+                    next: None,
+                };
+                let mut tail = &mut head;
+                let mut next = self.next.as_ref();
+                while let Some(node) = next {
+                    let copy = ListNode::new_in(node.val.clone(), Box::allocator(node).clone());
+                    tail = tail.next.insert(copy);
+                    next = node.next.as_ref();
+                }
+                head
+            }
+        }
 
-        let mut l4 = ListNode::from_vec(vec![1, 2, 3, 4]);
-        l4.push_back(5);
-        assert_eq!(l4, ListNode::from_vec(vec![1, 2, 3, 4, 5]));
+        /// Formats the list as the sequence of its elements
+        ///
+        /// # Example
+        /// ```
+        /// # use crate::data_structure::ds::linked_list::ListNode;
+        /// let list = ListNode::from_vec(vec![1, 2, 3]);
+        /// assert_eq!(format!("{:?}", list), "[1, 2, 3]");
+        /// ```
+        impl<T: Debug, A: Allocator> Debug for ListNode<T, A> {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.debug_list().entries(self.iter()).finish()
+            }
+        }
 
-        let mut l5 = ListNode::from_vec(vec![1, 2, 2, 3, 4, 5]);
-        match l5.delete(2) {
-            Ok(_) => (),
-            Err(e) => panic!("{}", e),
+        impl<T: PartialEq, A: Allocator> PartialEq for ListNode<T, A> {
+            fn eq(&self, other: &Self) -> bool {
+                self.iter().eq(other.iter())
+            }
         }
-        assert_eq!(l5, ListNode::from_vec(vec![1, 2, 3, 4, 5]));
 
-        let mut l6 = ListNode::from_vec(vec![3, 3, 3, 1, 2, 3, 4, 5]);
-        let l6 = match l6.find(1) {
-            Ok(res) => res,
-            Err(e) => panic!("{e}"),
-        };
-        assert_eq!(l6, &ListNode::from_vec(vec![1, 2, 3, 4, 5]));
+        /// An owning handle over a chain of `ListNode<T>` which,
+        /// unlike a bare `ListNode<T>`, can be empty
+        ///
+        /// The length is cached, so [len](struct.LinkedList.html#method.len)
+        /// is constant-time.
+        ///
+        /// Like `DoublyLinkedList<T, A>`, the list allocates its nodes from the
+        /// allocator `A`, which defaults to `Global`.
+        ///
+        /// Functions implemented:
+        /// * [new](struct.LinkedList.html#method.new) -> `Self`
+        /// * [new_in](struct.LinkedList.html#method.new_in) -> `Self`
+        /// * [allocator](struct.LinkedList.html#method.allocator) -> `&A`
+        /// * [len](struct.LinkedList.html#method.len) -> `usize`
+        /// * [is_empty](struct.LinkedList.html#method.is_empty) -> `bool`
+        /// * [head](struct.LinkedList.html#method.head) -> `Option<&ListNode<T>>`
+        /// * [into_head](struct.LinkedList.html#method.into_head) -> `Option<Box<ListNode<T>>>`
+        /// * [front](struct.LinkedList.html#method.front) -> `Option<&T>`
+        /// * [front_mut](struct.LinkedList.html#method.front_mut) -> `Option<&mut T>`
+        /// * [push_front](struct.LinkedList.html#method.push_front) -> `()`
+        /// * [pop_front](struct.LinkedList.html#method.pop_front) -> `Option<T>`
+        /// * [push_back](struct.LinkedList.html#method.push_back) -> `()`
+        /// * [pop_back](struct.LinkedList.html#method.pop_back) -> `Option<T>`
+        /// * [clear](struct.LinkedList.html#method.clear) -> `()`
+        /// * [print](struct.LinkedList.html#method.print) -> `()`
+        /// * [delete](struct.LinkedList.html#method.delete) -> `Result<(), Error>`
+        /// * [find](struct.LinkedList.html#method.find) -> `Result<&ListNode<T>, Error>`
+        /// * [insert](struct.LinkedList.html#method.insert) -> `Result<(), Error>`
+        /// * [reverse](struct.LinkedList.html#method.reverse) -> `()`
+        /// * [contains](struct.LinkedList.html#method.contains) -> `bool`
+        /// * [sort](struct.LinkedList.html#method.sort) -> `()`
+        /// * [sort_by](struct.LinkedList.html#method.sort_by) -> `()`
+        /// * [sort_by_key](struct.LinkedList.html#method.sort_by_key) -> `()`
+        /// * [sort_unstable](struct.LinkedList.html#method.sort_unstable) -> `()`
+        /// * [par_sort](struct.LinkedList.html#method.par_sort) -> `()`
+        /// * [par_sort_by](struct.LinkedList.html#method.par_sort_by) -> `()`
+        /// * [cursor_front_mut](struct.LinkedList.html#method.cursor_front_mut) -> `CursorMut<'_, T>`
+        /// * [iter](struct.LinkedList.html#method.iter) -> `Iter<'_, T>`
+        /// * [iter_mut](struct.LinkedList.html#method.iter_mut) -> `IterMut<'_, T>`
+        #[derive(Clone)]
+        pub struct LinkedList<T, A: Allocator = Global> {
+            head: Option<Box<ListNode<T, A>, A>>,
+            len: usize,
+            alloc: A,
+        }
 
-        let mut l7 = ListNode::from_vec(vec![1, 2, 3, 4, 5]);
-        assert_eq!(l7.len(), 5);
+        impl<T> LinkedList<T> {
+            /// Constructs a new, empty `LinkedList<T>`
+            ///
+            /// # Example
+            /// ```
+            /// # use crate::data_structure::ds::linked_list::LinkedList;
+            /// let list: LinkedList<i32> = LinkedList::new();
+            /// assert!(list.is_empty());
+            /// assert_eq!(list.head(), None);
+            /// ```
+            pub fn new() -> Self {
+                LinkedList::new_in(Global)
+            }
+        }
 
-        let mut l8 = ListNode::from_vec(vec![5, 4, 3, 2, 1]);
-        l8.reverse();
-        assert_eq!(l8, ListNode::from_vec(vec![1, 2, 3, 4, 5]));
+        impl<T, A: Allocator> LinkedList<T, A> {
+            /// Constructs a new, empty `LinkedList<T, A>` allocating its nodes from `alloc`
+            pub fn new_in(alloc: A) -> Self {
+                LinkedList { head: None, len: 0, alloc }
+            }
 
-        let temp = ListNode::from_vec(vec![1, 2, 3, 4, 5]);
-        let l9 = temp.copy();
-        assert_eq!(temp, l9);
+            /// Returns the allocator the list allocates its nodes from
+            pub fn allocator(&self) -> &A {
+                &self.alloc
+            }
 
-        let l10 = ListNode::from_vec(vec![1, 2, 3, 4, 5]);
-        let element = l10[1];
-        let element2 = l10[2];
-        assert_eq!(element, 2);
-        assert_eq!(element2, 3);
+            /// Returns the number of elements in the list in constant time
+            ///
+            /// # Example
+            /// ```
+            /// # use crate::data_structure::ds::linked_list::{LinkedList, ListNode};
+            /// let list = LinkedList::from(ListNode::from_vec(vec![1, 2, 3]));
+            /// assert_eq!(list.len(), 3);
+            /// ```
+            pub fn len(&self) -> usize {
+                self.len
+            }
 
-        let mut l11 = ListNode::from_vec(vec![1, 3, 4, 5]);
-        let _ = match l11.insert(1, 2) {
-            Ok(_) => (),
-            Err(e) => panic!("{e}"),
-        };
-        assert_eq!(l11, ListNode::from_vec(vec![1, 2, 3, 4, 5]));
+            /// Returns `true` if the list holds no elements
+            ///
+            /// # Example
+            /// ```
+            /// # use crate::data_structure::ds::linked_list::LinkedList;
+            /// let mut list = LinkedList::new();
+            /// assert!(list.is_empty());
+            /// list.push_front(1);
+            /// assert!(!list.is_empty());
+            /// ```
+            pub fn is_empty(&self) -> bool {
+                self.head.is_none()
+            }
 
-        let mut l12 = ListNode::from_vec(vec![1, 1, 2, 3, 4, 5]);
-        let _poped = l12.pop().unwrap();
-        assert_eq!(l12, ListNode::from_vec(vec![1, 2, 3, 4, 5]));
+            /// Returns a reference to the first node of the list, through which
+            /// every read-only `ListNode<T>` function can be called
+            ///
+            /// # Example
+            /// ```
+            /// # use crate::data_structure::ds::linked_list::{LinkedList, ListNode};
+            /// let list = LinkedList::from(ListNode::from_vec(vec![1, 2, 3]));
+            /// assert_eq!(list.head(), Some(ListNode::from_vec(vec![1, 2, 3]).as_ref()));
+            /// ```
+            pub fn head(&self) -> Option<&ListNode<T, A>> {
+                self.head.as_deref()
+            }
+
+            /// Consumes the list, returning its chain of nodes
+            ///
+            /// # Example
+            /// ```
+            /// # use crate::data_structure::ds::linked_list::{LinkedList, ListNode};
+            /// let list = LinkedList::from(ListNode::from_vec(vec![1, 2, 3]));
+            /// assert_eq!(list.into_head(), Some(ListNode::from_vec(vec![1, 2, 3])));
+            /// ```
+            pub fn into_head(self) -> Option<Box<ListNode<T, A>, A>> {
+                self.head
+            }
+
+            /// Returns a reference to the first element, or `None` if the list is empty
+            ///
+            /// # Example
+            /// ```
+            /// # use crate::data_structure::ds::linked_list::{LinkedList, ListNode};
+            /// let list = LinkedList::from(ListNode::from_vec(vec![1, 2, 3]));
+            /// assert_eq!(list.front(), Some(&1));
+            /// ```
+            pub fn front(&self) -> Option<&T> {
+                self.head.as_ref().map(|node| &node.val)
+            }
+
+            /// Returns a mutable reference to the first element, or `None` if the list is empty
+            ///
+            /// # Example
+            /// ```
+            /// # use crate::data_structure::ds::linked_list::{LinkedList, ListNode};
+            /// let mut list = LinkedList::from(ListNode::from_vec(vec![1, 2, 3]));
+            /// *list.front_mut().unwrap() = 0;
+            /// assert_eq!(list.front(), Some(&0));
+            /// ```
+            pub fn front_mut(&mut self) -> Option<&mut T> {
+                self.head.as_mut().map(|node| &mut node.val)
+            }
+
+            /// Pushes an element to the front of the list
+            ///
+            /// # Example
+            /// ```
+            /// # use crate::data_structure::ds::linked_list::{LinkedList, ListNode};
+            /// let mut list = LinkedList::new();
+            /// list.push_front(2);
+            /// list.push_front(1);
+            /// assert_eq!(list, LinkedList::from(ListNode::from_vec(vec![1, 2])));
+            /// ```
+            pub fn push_front(&mut self, val: T)
+            where
+                A: Clone,
+            {
+                let node = ListNode {
+                    val,
+                    next: self.head.take(),
+                };
+                self.head = Some(Box::new_in(node, self.alloc.clone()));
+                self.len += 1;
+            }
+
+            /// Pops the element on the front of the list, returning `None`
+            /// if the list is empty
+            ///
+            /// # Example
+            /// ```
+            /// # use crate::data_structure::ds::linked_list::{LinkedList, ListNode};
+            /// let mut list = LinkedList::from(ListNode::new(1));
+            /// assert_eq!(list.pop_front(), Some(1));
+            /// assert_eq!(list.pop_front(), None);
+            /// assert!(list.is_empty());
+            /// ```
+            pub fn pop_front(&mut self) -> Option<T> {
+                let val = ListNode::pop(&mut self.head)?;
+                self.len -= 1;
+                Some(val)
+            }
+
+            /// Pushes an element to the back of the list
+            ///
+            /// This walks the whole list, so it is `O(n)`.
+            ///
+            /// # Example
+            /// ```
+            /// # use crate::data_structure::ds::linked_list::{LinkedList, ListNode};
+            /// let mut list = LinkedList::new();
+            /// list.push_back(1);
+            /// list.push_back(2);
+            /// assert_eq!(list, LinkedList::from(ListNode::from_vec(vec![1, 2])));
+            /// ```
+            pub fn push_back(&mut self, val: T)
+            where
+                A: Clone,
+            {
+                let mut link = &mut self.head;
+                while let Some(node) = link {
+                    link = &mut node.next;
+                }
+                *link = Some(ListNode::new_in(val, self.alloc.clone()));
+                self.len += 1;
+            }
+
+            /// Pops the element on the back of the list, returning `None`
+            /// if the list is empty
+            ///
+            /// This walks the whole list, so it is `O(n)`.
+            ///
+            /// # Example
+            /// ```
+            /// # use crate::data_structure::ds::linked_list::{LinkedList, ListNode};
+            /// let mut list = LinkedList::from(ListNode::from_vec(vec![1, 2]));
+            /// assert_eq!(list.pop_back(), Some(2));
+            /// assert_eq!(list.pop_back(), Some(1));
+            /// assert_eq!(list.pop_back(), None);
+            /// ```
+            pub fn pop_back(&mut self) -> Option<T> {
+                let mut link = &mut self.head;
+                while link.as_ref()?.next.is_some() {
+                    link = &mut link.as_mut()?.next;
+                }
+                let (val, _) = ListNode::into_parts(link.take()?);
+                self.len -= 1;
+                Some(val)
+            }
+
+            /// Removes every element from the list
+            ///
+            /// # Example
+            /// ```
+            /// # use crate::data_structure::ds::linked_list::{LinkedList, ListNode};
+            /// let mut list = LinkedList::from(ListNode::from_vec(vec![1, 2, 3]));
+            /// list.clear();
+            /// assert!(list.is_empty());
+            /// ```
+            pub fn clear(&mut self) {
+                self.head = None;
+                self.len = 0;
+            }
+
+            /// Prints the list, see [ListNode::print](struct.ListNode.html#method.print)
+            ///
+            /// An empty list is printed as `None`.
+            pub fn print(&self)
+            where
+                T: Display,
+            {
+                match self.head.as_deref() {
+                    Some(node) => node.print(),
+                    None => println!("None"),
+                }
+            }
+
+            /// Deletes the first node that equals to the given `val: T`,
+            /// see [ListNode::delete](struct.ListNode.html#method.delete)
+            ///
+            /// # Example
+            /// ```
+            /// # use crate::data_structure::ds::linked_list::{LinkedList, ListNode};
+            /// # use crate::data_structure::ds::Error;
+            /// # fn foo() -> Result<(), Error> {
+            /// let mut list = LinkedList::from(ListNode::from_vec(vec![1, 2, 3]));
+            /// list.delete(1)?;
+            /// assert_eq!(list, LinkedList::from(ListNode::from_vec(vec![2, 3])));
+            /// # Ok(())
+            /// # }
+            /// # foo().unwrap();
+            /// ```
+            pub fn delete(&mut self, val: T) -> Result<(), Error>
+            where
+                T: PartialEq,
+            {
+                match self.head.as_deref_mut() {
+                    None => return Err(Error::NotFound),
+                    Some(node) if node.val == val => {
+                        self.pop_front();
+                        return Ok(());
+                    }
+                    Some(node) => node.delete(val)?,
+                }
+                self.len -= 1;
+                Ok(())
+            }
+
+            /// Finds the first node with the given `val: T`,
+            /// see [ListNode::find](struct.ListNode.html#method.find)
+            ///
+            /// # Example
+            /// ```
+            /// # use crate::data_structure::ds::{Error, linked_list::{LinkedList, ListNode}};
+            /// let list = LinkedList::from(ListNode::from_vec(vec![1, 2, 3]));
+            /// assert_eq!(list.find(2).map(|node| node.val), Ok(2));
+            /// assert_eq!(LinkedList::new().find(2), Err(Error::NotFound));
+            /// ```
+            pub fn find(&self, val: T) -> Result<&ListNode<T, A>, Error>
+            where
+                T: PartialEq,
+            {
+                self.head.as_deref().ok_or(Error::NotFound)?.find(val)
+            }
+
+            /// Inserts `val: T` in position `index: usize`,
+            /// see [ListNode::insert](struct.ListNode.html#method.insert)
+            ///
+            /// # Example
+            /// ```
+            /// # use crate::data_structure::ds::linked_list::{LinkedList, ListNode};
+            /// # use crate::data_structure::ds::Error;
+            /// # fn foo() -> Result<(), Error> {
+            /// let mut list = LinkedList::new();
+            /// list.insert(0, 1)?;
+            /// list.insert(1, 3)?;
+            /// list.insert(1, 2)?;
+            /// assert_eq!(list, LinkedList::from(ListNode::from_vec(vec![1, 2, 3])));
+            /// # Ok(())
+            /// # }
+            /// # foo().unwrap();
+            /// ```
+            pub fn insert(&mut self, index: usize, val: T) -> Result<(), Error>
+            where
+                A: Clone,
+            {
+                if index > self.len {
+                    return Err(Error::IndexOutOfRange { index, len: self.len });
+                }
+                let mut link = &mut self.head;
+                for _ in 0..index {
+                    link = &mut link.as_mut().expect("index is within the list").next;
+                }
+                let node = ListNode {
+                    val,
+                    next: link.take(),
+                };
+                *link = Some(Box::new_in(node, self.alloc.clone()));
+                self.len += 1;
+                Ok(())
+            }
+
+            /// Reverses the list, see [ListNode::reverse](struct.ListNode.html#method.reverse)
+            ///
+            /// # Example
+            /// ```
+            /// # use crate::data_structure::ds::linked_list::{LinkedList, ListNode};
+            /// let mut list = LinkedList::from(ListNode::from_vec(vec![1, 2, 3]));
+            /// list.reverse();
+            /// assert_eq!(list, LinkedList::from(ListNode::from_vec(vec![3, 2, 1])));
+            /// ```
+            pub fn reverse(&mut self) {
+                self.head = ListNode::reverse_chain(self.head.take());
+            }
+
+            /// Checks if whether the list contains the given `val: T` element
+            ///
+            /// # Example
+            /// ```
+            /// # use crate::data_structure::ds::linked_list::{LinkedList, ListNode};
+            /// let list = LinkedList::from(ListNode::from_vec(vec![1, 2, 3]));
+            /// assert!(list.contains(3));
+            /// assert!(!LinkedList::new().contains(3));
+            /// ```
+            pub fn contains(&self, val: T) -> bool
+            where
+                T: PartialEq,
+            {
+                self.head.as_ref().is_some_and(|node| node.contains(val))
+            }
+
+            /// Sorts the list in place, see [ListNode::sort](struct.ListNode.html#method.sort)
+            ///
+            /// # Example
+            /// ```
+            /// # use crate::data_structure::ds::linked_list::LinkedList;
+            /// let mut list = LinkedList::from(["b", "c", "a"]);
+            /// list.sort();
+            /// assert_eq!(list, LinkedList::from(["a", "b", "c"]));
+            /// ```
+            pub fn sort(&mut self)
+            where
+                T: Ord,
+            {
+                self.sort_by(T::cmp);
+            }
+
+            /// Sorts the list in place with the comparator `cmp`,
+            /// see [ListNode::sort_by](struct.ListNode.html#method.sort_by)
+            ///
+            /// # Example
+            /// ```
+            /// # use crate::data_structure::ds::linked_list::LinkedList;
+            /// let mut list = LinkedList::from([1, 3, 2]);
+            /// list.sort_by(|a, b| b.cmp(a));
+            /// assert_eq!(list, LinkedList::from([3, 2, 1]));
+            /// ```
+            pub fn sort_by<F>(&mut self, mut cmp: F)
+            where
+                F: FnMut(&T, &T) -> Ordering,
+            {
+                self.head = ListNode::merge_sort(self.head.take(), self.len, &mut cmp);
+            }
+
+            /// Sorts the list in place by the keys extracted by `f`,
+            /// see [ListNode::sort_by_key](struct.ListNode.html#method.sort_by_key)
+            ///
+            /// # Example
+            /// ```
+            /// # use crate::data_structure::ds::linked_list::LinkedList;
+            /// let mut list = LinkedList::from([-3, 1, -2]);
+            /// list.sort_by_key(|val: &i32| val.abs());
+            /// assert_eq!(list, LinkedList::from([1, -2, -3]));
+            /// ```
+            pub fn sort_by_key<K, F>(&mut self, mut f: F)
+            where
+                K: Ord,
+                F: FnMut(&T) -> K,
+            {
+                self.sort_by(|a, b| f(a).cmp(&f(b)));
+            }
+
+            /// Sorts the list in place without guaranteeing the order of equal elements,
+            /// see [ListNode::sort_unstable](struct.ListNode.html#method.sort_unstable)
+            pub fn sort_unstable(&mut self)
+            where
+                T: Ord,
+            {
+                self.sort();
+            }
+
+            /// Returns a cursor pointing at the first element of the list,
+            /// which keeps the cached length in sync with its edits
+            ///
+            /// # Example
+            /// ```
+            /// # use crate::data_structure::ds::linked_list::LinkedList;
+            /// let mut list = LinkedList::from([1, 2, 3]);
+            /// let mut cursor = list.cursor_front_mut();
+            /// cursor.move_next();
+            /// cursor.insert_after(0);
+            /// assert_eq!(list, LinkedList::from([1, 2, 0, 3]));
+            /// ```
+            pub fn cursor_front_mut(&mut self) -> CursorMut<'_, T, A> {
+                CursorMut {
+                    link: Some(&mut self.head),
+                    index: 0,
+                    len: Some(&mut self.len),
+                    alloc: &self.alloc,
+                }
+            }
+
+            /// Sorts the list in place through a parallel merge sort,
+            /// see [ListNode::par_sort](struct.ListNode.html#method.par_sort)
+            ///
+            /// # Example
+            /// ```
+            /// # use crate::data_structure::ds::linked_list::{LinkedList, SortConfig};
+            /// let mut list: LinkedList<i32> = (0..1_000).rev().collect();
+            /// list.par_sort(&SortConfig { threads: 4, sequential_cutoff: 100 });
+            /// assert!(list.iter().eq((0..1_000).collect::<Vec<_>>().iter()));
+            /// ```
+            pub fn par_sort(&mut self, config: &SortConfig)
+            where
+                T: Ord + Send,
+                A: Send,
+            {
+                self.par_sort_by(config, T::cmp);
+            }
+
+            /// Sorts the list in place through a parallel merge sort with the comparator `cmp`,
+            /// see [ListNode::par_sort_by](struct.ListNode.html#method.par_sort_by)
+            pub fn par_sort_by<F>(&mut self, config: &SortConfig, cmp: F)
+            where
+                T: Send,
+                A: Send,
+                F: Fn(&T, &T) -> Ordering + Sync,
+            {
+                self.head = ListNode::par_merge_sort(self.head.take(), self.len, config, &cmp);
+            }
+
+            /// Returns an iterator over references to the elements of the list
+            ///
+            /// # Example
+            /// ```
+            /// # use crate::data_structure::ds::linked_list::{LinkedList, ListNode};
+            /// let list = LinkedList::from(ListNode::from_vec(vec![1, 2, 3]));
+            /// let mut iter = list.iter();
+            /// assert_eq!(iter.size_hint(), (3, Some(3)));
+            /// assert_eq!(iter.next(), Some(&1));
+            /// assert_eq!(iter.size_hint(), (2, Some(2)));
+            /// ```
+            pub fn iter(&self) -> Iter<'_, T, A> {
+                Iter {
+                    next: self.head.as_deref(),
+                    len: Some(self.len),
+                }
+            }
+
+            /// Returns an iterator over mutable references to the elements of the list
+            ///
+            /// # Example
+            /// ```
+            /// # use crate::data_structure::ds::linked_list::{LinkedList, ListNode};
+            /// let mut list = LinkedList::from(ListNode::from_vec(vec![1, 2, 3]));
+            /// list.iter_mut().for_each(|val| *val += 1);
+            /// assert_eq!(list, LinkedList::from(ListNode::from_vec(vec![2, 3, 4])));
+            /// ```
+            pub fn iter_mut(&mut self) -> IterMut<'_, T, A> {
+                IterMut {
+                    next: self.head.as_deref_mut(),
+                    len: Some(self.len),
+                }
+            }
+        }
+
+        impl<T> Default for LinkedList<T> {
+            fn default() -> Self {
+                Self::new()
+            }
+        }
+
+        /// The list allocates its new nodes from the allocator of `head`
+        impl<T, A: Allocator + Clone> From<Box<ListNode<T, A>, A>> for LinkedList<T, A> {
+            fn from(head: Box<ListNode<T, A>, A>) -> Self {
+                LinkedList {
+                    len: head.iter().count(),
+                    alloc: Box::allocator(&head).clone(),
+                    head: Some(head),
+                }
+            }
+        }
+
+        impl<T: Debug, A: Allocator> Debug for LinkedList<T, A> {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.debug_struct("LinkedList")
+                    .field("head", &self.head)
+                    .field("len", &self.len)
+                    .finish()
+            }
+        }
+
+        impl<T: PartialEq, A: Allocator> PartialEq for LinkedList<T, A> {
+            fn eq(&self, other: &Self) -> bool {
+                self.len == other.len && self.head == other.head
+            }
+        }
+
+        /// Builds a list from an iterator, keeping the iteration order
+        ///
+        /// A `ListNode<T>` can't be empty, so this panics if the iterator yields nothing.
+        ///
+        /// # Example
+        /// ```
+        /// # use crate::data_structure::ds::{allocator::Box, linked_list::ListNode};
+        /// let list: Box<ListNode<i32>> = (1..=3).collect();
+        /// assert_eq!(list, ListNode::from_vec(vec![1, 2, 3]));
+        /// ```
+        impl<T> FromIterator<T> for Box<ListNode<T>> {
+            fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+                ListNode::try_from_iter(iter).expect("Iterator can't be empty")
+            }
+        }
+
+        /// Appends every element of the iterator to the back of the list
+        impl<T> Extend<T> for ListNode<T> {
+            fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+                let mut tail = self;
+                while tail.next.is_some() {
+                    tail = tail.next.as_deref_mut().unwrap();
+                }
+                for val in iter {
+                    tail = tail.next.insert(ListNode::new(val));
+                }
+            }
+        }
+
+        impl<'a, T: Copy + 'a> Extend<&'a T> for ListNode<T> {
+            fn extend<I: IntoIterator<Item = &'a T>>(&mut self, iter: I) {
+                self.extend(iter.into_iter().copied());
+            }
+        }
+
+        /// Panics if `N == 0`, as a `ListNode<T>` can't be empty
+        impl<T, const N: usize> From<[T; N]> for Box<ListNode<T>> {
+            fn from(arr: [T; N]) -> Self {
+                arr.into_iter().collect()
+            }
+        }
+
+        impl<T> FromIterator<T> for LinkedList<T> {
+            fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+                let mut list = LinkedList::new();
+                list.extend(iter);
+                list
+            }
+        }
+
+        /// Appends every element of the iterator to the back of the list
+        impl<T, A: Allocator + Clone> Extend<T> for LinkedList<T, A> {
+            fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+                let mut link = &mut self.head;
+                while let Some(node) = link {
+                    link = &mut node.next;
+                }
+                for val in iter {
+                    link = &mut link.insert(ListNode::new_in(val, self.alloc.clone())).next;
+                    self.len += 1;
+                }
+            }
+        }
+
+        impl<'a, T: Copy + 'a, A: Allocator + Clone> Extend<&'a T> for LinkedList<T, A> {
+            fn extend<I: IntoIterator<Item = &'a T>>(&mut self, iter: I) {
+                self.extend(iter.into_iter().copied());
+            }
+        }
+
+        impl<T, const N: usize> From<[T; N]> for LinkedList<T> {
+            fn from(arr: [T; N]) -> Self {
+                arr.into_iter().collect()
+            }
+        }
+
+        /// A cursor over a `ListNode<T>` chain which can edit the list
+        /// in the middle while walking it once
+        ///
+        /// The cursor points at a link of the chain: either at an element, or past
+        /// the last one, where [current](struct.CursorMut.html#method.current) returns `None`.
+        /// It is created by [ListNode::cursor_mut](struct.ListNode.html#method.cursor_mut)
+        /// and [LinkedList::cursor_front_mut](struct.LinkedList.html#method.cursor_front_mut).
+        ///
+        /// # Example
+        /// ```
+        /// # use crate::data_structure::ds::linked_list::LinkedList;
+        /// let mut list = LinkedList::from([1, 2, 3, 4, 5]);
+        /// let mut cursor = list.cursor_front_mut();
+        /// while let Some(val) = cursor.current() {
+        ///     if *val % 2 == 0 {
+        ///         cursor.remove_current();
+        ///     } else {
+        ///         cursor.move_next();
+        ///     }
+        /// }
+        /// assert_eq!(list, LinkedList::from([1, 3, 5]));
+        /// assert_eq!(list.len(), 3);
+        /// ```
+        pub struct CursorMut<'a, T, A: Allocator = Global> {
+            // Only `None` while `move_next` swaps it out
+            link: Option<&'a mut Option<Box<ListNode<T, A>, A>>>,
+            index: usize,
+            // Length of the owning `LinkedList<T>`, kept in sync with every edit
+            len: Option<&'a mut usize>,
+            // Where inserted elements are allocated
+            alloc: &'a A,
+        }
+
+        impl<'a, T, A: Allocator> CursorMut<'a, T, A> {
+            fn link(&mut self) -> &mut Option<Box<ListNode<T, A>, A>> {
+                self.link.as_deref_mut().expect("cursor always holds a link")
+            }
+
+            /// Returns the position of the cursor, which is the length
+            /// of the list once it has moved past the last element
+            pub fn index(&self) -> usize {
+                self.index
+            }
+
+            /// Returns a mutable reference to the element under the cursor,
+            /// or `None` if the cursor is past the end
+            ///
+            /// # Example
+            /// ```
+            /// # use crate::data_structure::ds::linked_list::ListNode;
+            /// let mut head = Some(ListNode::from_vec(vec![1, 2]));
+            /// let mut cursor = ListNode::cursor_mut(&mut head);
+            /// *cursor.current().unwrap() = 10;
+            /// assert_eq!(head, Some(ListNode::from_vec(vec![10, 2])));
+            /// ```
+            pub fn current(&mut self) -> Option<&mut T> {
+                self.link().as_mut().map(|node| &mut node.val)
+            }
+
+            /// Returns a mutable reference to the element after the cursor, if any
+            pub fn peek_next(&mut self) -> Option<&mut T> {
+                let node = self.link().as_mut()?;
+                node.next.as_mut().map(|next| &mut next.val)
+            }
+
+            /// Moves the cursor to the next element, doing nothing if it is already past the end
+            ///
+            /// # Example
+            /// ```
+            /// # use crate::data_structure::ds::linked_list::LinkedList;
+            /// let mut list = LinkedList::from([1, 2]);
+            /// let mut cursor = list.cursor_front_mut();
+            /// cursor.move_next();
+            /// assert_eq!(cursor.current(), Some(&mut 2));
+            /// cursor.move_next();
+            /// cursor.move_next();
+            /// assert_eq!(cursor.current(), None);
+            /// assert_eq!(cursor.index(), 2);
+            /// ```
+            pub fn move_next(&mut self) {
+                let link = self.link.take().expect("cursor always holds a link");
+                if link.is_none() {
+                    self.link = Some(link);
+                    return;
+                }
+                self.link = link.as_mut().map(|node| &mut node.next);
+                self.index += 1;
+            }
+
+            /// Inserts `val: T` after the element under the cursor
+            ///
+            /// If the cursor is past the end, the element is appended
+            /// to the list and becomes the one under the cursor.
+            ///
+            /// # Example
+            /// ```
+            /// # use crate::data_structure::ds::linked_list::LinkedList;
+            /// let mut list = LinkedList::new();
+            /// let mut cursor = list.cursor_front_mut();
+            /// cursor.insert_after(1);
+            /// cursor.insert_after(3);
+            /// cursor.insert_after(2);
+            /// assert_eq!(list, LinkedList::from([1, 2, 3]));
+            /// ```
+            pub fn insert_after(&mut self, val: T)
+            where
+                A: Clone,
+            {
+                let alloc = self.alloc.clone();
+                let link = self.link();
+                match link {
+                    Some(node) => node.next = Some(Box::new_in(ListNode { val, next: node.next.take() }, alloc)),
+                    None => *link = Some(ListNode::new_in(val, alloc)),
+                }
+                if let Some(len) = self.len.as_deref_mut() {
+                    *len += 1;
+                }
+            }
+
+            /// Removes the element under the cursor, returning it
+            ///
+            /// The cursor then points at the element that followed the removed one.
+            /// Returns `None` and leaves the list untouched if the cursor is past the end.
+            ///
+            /// # Example
+            /// ```
+            /// # use crate::data_structure::ds::linked_list::ListNode;
+            /// let mut head = Some(ListNode::from_vec(vec![1, 2, 3]));
+            /// let mut cursor = ListNode::cursor_mut(&mut head);
+            /// cursor.move_next();
+            /// assert_eq!(cursor.remove_current(), Some(2));
+            /// assert_eq!(cursor.current(), Some(&mut 3));
+            /// assert_eq!(head, Some(ListNode::from_vec(vec![1, 3])));
+            /// ```
+            pub fn remove_current(&mut self) -> Option<T> {
+                let link = self.link();
+                let (val, next) = ListNode::into_parts(link.take()?);
+                *link = next;
+                if let Some(len) = self.len.as_deref_mut() {
+                    *len -= 1;
+                }
+                Some(val)
+            }
+
+            /// Detaches every element after the one under the cursor, returning them as a new list
+            ///
+            /// # Example
+            /// ```
+            /// # use crate::data_structure::ds::linked_list::LinkedList;
+            /// let mut list = LinkedList::from([1, 2, 3, 4]);
+            /// let mut cursor = list.cursor_front_mut();
+            /// cursor.move_next();
+            /// let tail = cursor.split_after();
+            /// assert_eq!(tail, LinkedList::from([3, 4]));
+            /// assert_eq!(list, LinkedList::from([1, 2]));
+            /// ```
+            pub fn split_after(&mut self) -> LinkedList<T, A>
+            where
+                A: Clone,
+            {
+                let index = self.index;
+                let head = match self.link().as_mut() {
+                    Some(node) => node.next.take(),
+                    None => None,
+                };
+                let len = match self.len.as_deref_mut() {
+                    Some(len) if head.is_some() => {
+                        let split_len = *len - index - 1;
+                        *len = index + 1;
+                        split_len
+                    }
+                    _ => head.as_ref().map_or(0, |node| node.iter().count()),
+                };
+                LinkedList {
+                    head,
+                    len,
+                    alloc: self.alloc.clone(),
+                }
+            }
+
+            /// Moves every element of `list` in after the one under the cursor,
+            /// without cloning them
+            ///
+            /// If the cursor is past the end, `list` is appended and
+            /// its first element becomes the one under the cursor.
+            ///
+            /// # Example
+            /// ```
+            /// # use crate::data_structure::ds::linked_list::LinkedList;
+            /// let mut list = LinkedList::from([1, 4]);
+            /// let mut cursor = list.cursor_front_mut();
+            /// cursor.splice_after(LinkedList::from([2, 3]));
+            /// assert_eq!(list, LinkedList::from([1, 2, 3, 4]));
+            /// assert_eq!(list.len(), 4);
+            /// ```
+            pub fn splice_after(&mut self, list: LinkedList<T, A>) {
+                let LinkedList {
+                    head: Some(mut spliced),
+                    len: spliced_len,
+                    ..
+                } = list
+                else {
+                    return;
+                };
+
+                let link = self.link();
+                let slot = match link {
+                    Some(node) => &mut node.next,
+                    None => link,
+                };
+
+                let mut tail = spliced.as_mut();
+                while tail.next.is_some() {
+                    tail = tail.next.as_deref_mut().unwrap();
+                }
+                tail.next = slot.take();
+                *slot = Some(spliced);
+
+                if let Some(len) = self.len.as_deref_mut() {
+                    *len += spliced_len;
+                }
+            }
+        }
+
+        /// Borrowing iterator over a `ListNode<T>` chain, created by
+        /// [ListNode::iter](struct.ListNode.html#method.iter) and
+        /// [LinkedList::iter](struct.LinkedList.html#method.iter)
+        ///
+        /// The length is only known up front when iterating a `LinkedList<T>`,
+        /// otherwise `size_hint` reports a lower bound.
+        #[derive(Clone, Debug)]
+        pub struct Iter<'a, T, A: Allocator = Global> {
+            next: Option<&'a ListNode<T, A>>,
+            len: Option<usize>,
+        }
+
+        impl<'a, T, A: Allocator> Iter<'a, T, A> {
+            /// Iterates over the chain starting at `head`, which is `len` nodes long
+            pub(crate) fn with_len(head: Option<&'a ListNode<T, A>>, len: usize) -> Self {
+                Iter {
+                    next: head,
+                    len: Some(len),
+                }
+            }
+        }
+
+        impl<'a, T, A: Allocator> Iterator for Iter<'a, T, A> {
+            type Item = &'a T;
+
+            fn next(&mut self) -> Option<Self::Item> {
+                let node = self.next?;
+                self.next = node.next.as_deref();
+                if let Some(len) = self.len.as_mut() {
+                    *len -= 1;
+                }
+                Some(&node.val)
+            }
+
+            fn size_hint(&self) -> (usize, Option<usize>) {
+                size_hint(self.next.is_some(), self.len)
+            }
+        }
+
+        impl<T, A: Allocator> FusedIterator for Iter<'_, T, A> {}
+
+        /// Mutable borrowing iterator over a `ListNode<T>` chain, created by
+        /// [ListNode::iter_mut](struct.ListNode.html#method.iter_mut) and
+        /// [LinkedList::iter_mut](struct.LinkedList.html#method.iter_mut)
+        #[derive(Debug)]
+        pub struct IterMut<'a, T, A: Allocator = Global> {
+            next: Option<&'a mut ListNode<T, A>>,
+            len: Option<usize>,
+        }
+
+        impl<'a, T, A: Allocator> IterMut<'a, T, A> {
+            /// Iterates over the chain starting at `head`, which is `len` nodes long
+            pub(crate) fn with_len(head: Option<&'a mut ListNode<T, A>>, len: usize) -> Self {
+                IterMut {
+                    next: head,
+                    len: Some(len),
+                }
+            }
+        }
+
+        impl<'a, T, A: Allocator> Iterator for IterMut<'a, T, A> {
+            type Item = &'a mut T;
+
+            fn next(&mut self) -> Option<Self::Item> {
+                let node = self.next.take()?;
+                self.next = node.next.as_deref_mut();
+                if let Some(len) = self.len.as_mut() {
+                    *len -= 1;
+                }
+                Some(&mut node.val)
+            }
+
+            fn size_hint(&self) -> (usize, Option<usize>) {
+                size_hint(self.next.is_some(), self.len)
+            }
+        }
+
+        impl<T, A: Allocator> FusedIterator for IterMut<'_, T, A> {}
+
+        /// Owning iterator over a `ListNode<T>` chain, created by the
+        /// `IntoIterator` implementations of `ListNode<T>` and `LinkedList<T>`
+        ///
+        /// # Example
+        /// ```
+        /// # use crate::data_structure::ds::linked_list::ListNode;
+        /// let list = ListNode::from_vec(vec![String::from("a"), String::from("b")]);
+        /// let joined: String = list.into_iter().collect();
+        /// assert_eq!(joined, "ab");
+        /// ```
+        #[derive(Debug)]
+        pub struct IntoIter<T, A: Allocator = Global> {
+            next: Option<Box<ListNode<T, A>, A>>,
+            len: Option<usize>,
+        }
+
+        impl<T, A: Allocator> IntoIter<T, A> {
+            /// Iterates over the chain starting at `head`, which is `len` nodes long
+            pub(crate) fn with_len(head: Option<Box<ListNode<T, A>, A>>, len: usize) -> Self {
+                IntoIter {
+                    next: head,
+                    len: Some(len),
+                }
+            }
+        }
+
+        impl<T, A: Allocator> Iterator for IntoIter<T, A> {
+            type Item = T;
+
+            fn next(&mut self) -> Option<Self::Item> {
+                let (val, next) = ListNode::into_parts(self.next.take()?);
+                self.next = next;
+                if let Some(len) = self.len.as_mut() {
+                    *len -= 1;
+                }
+                Some(val)
+            }
+
+            fn size_hint(&self) -> (usize, Option<usize>) {
+                size_hint(self.next.is_some(), self.len)
+            }
+        }
+
+        impl<T, A: Allocator> FusedIterator for IntoIter<T, A> {}
+
+        fn size_hint(has_next: bool, len: Option<usize>) -> (usize, Option<usize>) {
+            match (has_next, len) {
+                (_, Some(len)) => (len, Some(len)),
+                (true, None) => (1, None),
+                (false, None) => (0, Some(0)),
+            }
+        }
+
+        impl<T> IntoIterator for ListNode<T> {
+            type Item = T;
+            type IntoIter = IntoIter<T>;
+
+            fn into_iter(self) -> Self::IntoIter {
+                Box::new(self).into_iter()
+            }
+        }
+
+        impl<T, A: Allocator> IntoIterator for Box<ListNode<T, A>, A> {
+            type Item = T;
+            type IntoIter = IntoIter<T, A>;
+
+            fn into_iter(self) -> Self::IntoIter {
+                IntoIter {
+                    next: Some(self),
+                    len: None,
+                }
+            }
+        }
+
+        impl<'a, T, A: Allocator> IntoIterator for &'a ListNode<T, A> {
+            type Item = &'a T;
+            type IntoIter = Iter<'a, T, A>;
+
+            fn into_iter(self) -> Self::IntoIter {
+                self.iter()
+            }
+        }
+
+        impl<'a, T, A: Allocator> IntoIterator for &'a mut ListNode<T, A> {
+            type Item = &'a mut T;
+            type IntoIter = IterMut<'a, T, A>;
+
+            fn into_iter(self) -> Self::IntoIter {
+                self.iter_mut()
+            }
+        }
+
+        impl<T, A: Allocator> IntoIterator for LinkedList<T, A> {
+            type Item = T;
+            type IntoIter = IntoIter<T, A>;
+
+            fn into_iter(self) -> Self::IntoIter {
+                IntoIter {
+                    next: self.head,
+                    len: Some(self.len),
+                }
+            }
+        }
+
+        impl<'a, T, A: Allocator> IntoIterator for &'a LinkedList<T, A> {
+            type Item = &'a T;
+            type IntoIter = Iter<'a, T, A>;
+
+            fn into_iter(self) -> Self::IntoIter {
+                self.iter()
+            }
+        }
+
+        impl<'a, T, A: Allocator> IntoIterator for &'a mut LinkedList<T, A> {
+            type Item = &'a mut T;
+            type IntoIter = IterMut<'a, T, A>;
+
+            fn into_iter(self) -> Self::IntoIter {
+                self.iter_mut()
+            }
+        }
+    }
+
+    /// This module provides a Doubly Linked List struct named `DoublyLinkedList`
+    ///
+    /// Pushing and popping at both ends is `O(1)`, and every iterator
+    /// can also be walked from the back.
+    ///
+    /// Functions implemented:
+    /// * [new](struct.DoublyLinkedList.html#method.new) -> `Self`
+    /// * [from_vec](struct.DoublyLinkedList.html#method.from_vec) -> `Self`
+    /// * [new_in](struct.DoublyLinkedList.html#method.new_in) -> `Self`
+    /// * [allocator](struct.DoublyLinkedList.html#method.allocator) -> `&A`
+    /// * [len](struct.DoublyLinkedList.html#method.len) -> `usize`
+    /// * [is_empty](struct.DoublyLinkedList.html#method.is_empty) -> `bool`
+    /// * [front](struct.DoublyLinkedList.html#method.front) -> `Option<&T>`
+    /// * [front_mut](struct.DoublyLinkedList.html#method.front_mut) -> `Option<&mut T>`
+    /// * [back](struct.DoublyLinkedList.html#method.back) -> `Option<&T>`
+    /// * [back_mut](struct.DoublyLinkedList.html#method.back_mut) -> `Option<&mut T>`
+    /// * [push_front](struct.DoublyLinkedList.html#method.push_front) -> `()`
+    /// * [push_back](struct.DoublyLinkedList.html#method.push_back) -> `()`
+    /// * [pop_front](struct.DoublyLinkedList.html#method.pop_front) -> `Option<T>`
+    /// * [pop_back](struct.DoublyLinkedList.html#method.pop_back) -> `Option<T>`
+    /// * [print](struct.DoublyLinkedList.html#method.print) -> `()`
+    /// * [find](struct.DoublyLinkedList.html#method.find) -> `Result<&T, Error>`
+    /// * [delete](struct.DoublyLinkedList.html#method.delete) -> `Result<(), Error>`
+    /// * [insert](struct.DoublyLinkedList.html#method.insert) -> `Result<(), Error>`
+    /// * [try_get](struct.DoublyLinkedList.html#method.try_get) -> `Result<&T, Error>`
+    /// * [try_get_mut](struct.DoublyLinkedList.html#method.try_get_mut) -> `Result<&mut T, Error>`
+    /// * [contains](struct.DoublyLinkedList.html#method.contains) -> `bool`
+    /// * [reverse](struct.DoublyLinkedList.html#method.reverse) -> `()`
+    /// * [sort](struct.DoublyLinkedList.html#method.sort) -> `()`
+    /// * [sort_by](struct.DoublyLinkedList.html#method.sort_by) -> `()`
+    /// * [sort_by_key](struct.DoublyLinkedList.html#method.sort_by_key) -> `()`
+    /// * [append](struct.DoublyLinkedList.html#method.append) -> `()`
+    /// * [split_off](struct.DoublyLinkedList.html#method.split_off) -> `Result<Self, Error>`
+    /// * [clear](struct.DoublyLinkedList.html#method.clear) -> `()`
+    /// * [iter](struct.DoublyLinkedList.html#method.iter) -> `Iter<'_, T>`
+    /// * [iter_mut](struct.DoublyLinkedList.html#method.iter_mut) -> `IterMut<'_, T>`
+    /// * [cursor_front_mut](struct.DoublyLinkedList.html#method.cursor_front_mut) -> `CursorMut<'_, T>`
+    /// * [cursor_back_mut](struct.DoublyLinkedList.html#method.cursor_back_mut) -> `CursorMut<'_, T>`
+    pub mod doubly_linked_list {
+        use crate::ds::Error;
+        use crate::ds::allocator::{Allocator, Global};
+        use std::alloc::{self, Layout};
+        use std::cmp::Ordering;
+        use std::fmt::{Debug, Display};
+        use std::iter::FusedIterator;
+        use std::marker::PhantomData;
+        use std::ops::{Index, IndexMut};
+        use std::ptr::NonNull;
+
+        struct Node<T> {
+            val: T,
+            prev: Link<T>,
+            next: Link<T>,
+        }
+
+        type Link<T> = Option<NonNull<Node<T>>>;
+
+        /// Doubly linked list implementation
+        ///
+        /// Nodes come from the allocator `A`, which defaults to [Global]. Moving
+        /// nodes from one list into another with `append` or `splice_after` needs
+        /// `A: PartialEq`, and panics unless both lists use equal allocators.
+        pub struct DoublyLinkedList<T, A: Allocator = Global> {
+            head: Link<T>,
+            tail: Link<T>,
+            len: usize,
+            alloc: A,
+            marker: PhantomData<Box<Node<T>>>,
+        }
+
+        // SAFETY: the list owns its nodes exclusively, like `Box<Node<T>>` would
+        unsafe impl<T: Send, A: Allocator + Send> Send for DoublyLinkedList<T, A> {}
+        unsafe impl<T: Sync, A: Allocator + Sync> Sync for DoublyLinkedList<T, A> {}
+
+        impl<T> DoublyLinkedList<T> {
+            /// Constructs a new, empty `DoublyLinkedList<T>`
+            ///
+            /// # Example
+            /// ```
+            /// # use crate::data_structure::ds::doubly_linked_list::DoublyLinkedList;
+            /// let list: DoublyLinkedList<i32> = DoublyLinkedList::new();
+            /// assert!(list.is_empty());
+            /// ```
+            pub fn new() -> Self {
+                DoublyLinkedList::new_in(Global)
+            }
+
+            /// Constructs a new instance of `DoublyLinkedList<T>` with a `Vec<T>`,
+            /// which unlike `ListNode::from_vec` may be empty
+            ///
+            /// # Example
+            /// ```
+            /// # use crate::data_structure::ds::doubly_linked_list::DoublyLinkedList;
+            /// let list = DoublyLinkedList::from_vec(vec![1, 2, 3]);
+            /// assert_eq!(list.len(), 3);
+            /// assert!(DoublyLinkedList::<i32>::from_vec(vec![]).is_empty());
+            /// ```
+            pub fn from_vec(vec: Vec<T>) -> Self {
+                vec.into_iter().collect()
+            }
+        }
+
+        impl<T, A: Allocator> DoublyLinkedList<T, A> {
+            /// Constructs a new, empty `DoublyLinkedList<T, A>` allocating its nodes from `alloc`
+            pub fn new_in(alloc: A) -> Self {
+                DoublyLinkedList {
+                    head: None,
+                    tail: None,
+                    len: 0,
+                    alloc,
+                    marker: PhantomData,
+                }
+            }
+
+            /// Returns the allocator the list allocates its nodes from
+            pub fn allocator(&self) -> &A {
+                &self.alloc
+            }
+
+            /// Returns the number of elements in the list
+            pub fn len(&self) -> usize {
+                self.len
+            }
+
+            /// Returns `true` if the list holds no elements
+            pub fn is_empty(&self) -> bool {
+                self.len == 0
+            }
+
+            /// Returns a reference to the first element, or `None` if the list is empty
+            ///
+            /// # Example
+            /// ```
+            /// # use crate::data_structure::ds::doubly_linked_list::DoublyLinkedList;
+            /// let list = DoublyLinkedList::from([1, 2, 3]);
+            /// assert_eq!(list.front(), Some(&1));
+            /// ```
+            pub fn front(&self) -> Option<&T> {
+                // SAFETY: `head` points to a node owned by the list
+                self.head.map(|node| unsafe { &(*node.as_ptr()).val })
+            }
+
+            /// Returns a mutable reference to the first element, or `None` if the list is empty
+            pub fn front_mut(&mut self) -> Option<&mut T> {
+                // SAFETY: `head` points to a node owned by the list, borrowed mutably through `self`
+                self.head.map(|node| unsafe { &mut (*node.as_ptr()).val })
+            }
+
+            /// Returns a reference to the last element, or `None` if the list is empty
+            ///
+            /// # Example
+            /// ```
+            /// # use crate::data_structure::ds::doubly_linked_list::DoublyLinkedList;
+            /// let list = DoublyLinkedList::from([1, 2, 3]);
+            /// assert_eq!(list.back(), Some(&3));
+            /// ```
+            pub fn back(&self) -> Option<&T> {
+                // SAFETY: `tail` points to a node owned by the list
+                self.tail.map(|node| unsafe { &(*node.as_ptr()).val })
+            }
+
+            /// Returns a mutable reference to the last element, or `None` if the list is empty
+            pub fn back_mut(&mut self) -> Option<&mut T> {
+                // SAFETY: `tail` points to a node owned by the list, borrowed mutably through `self`
+                self.tail.map(|node| unsafe { &mut (*node.as_ptr()).val })
+            }
+
+            /// Pushes an element to the front of the list in `O(1)`
+            ///
+            /// # Example
+            /// ```
+            /// # use crate::data_structure::ds::doubly_linked_list::DoublyLinkedList;
+            /// let mut list = DoublyLinkedList::new();
+            /// list.push_front(2);
+            /// list.push_front(1);
+            /// assert_eq!(list, DoublyLinkedList::from([1, 2]));
+            /// ```
+            pub fn push_front(&mut self, val: T) {
+                // SAFETY: the new node is not linked anywhere yet
+                unsafe { self.link_after(None, self.new_node(val)) }
+            }
+
+            /// Pushes an element to the back of the list in `O(1)`
+            ///
+            /// # Example
+            /// ```
+            /// # use crate::data_structure::ds::doubly_linked_list::DoublyLinkedList;
+            /// let mut list = DoublyLinkedList::new();
+            /// list.push_back(1);
+            /// list.push_back(2);
+            /// assert_eq!(list, DoublyLinkedList::from([1, 2]));
+            /// ```
+            pub fn push_back(&mut self, val: T) {
+                // SAFETY: `tail` is owned by the list and the new node is not linked anywhere yet
+                unsafe { self.link_after(self.tail, self.new_node(val)) }
+            }
+
+            /// Pops the element on the front of the list in `O(1)`,
+            /// returning `None` if the list is empty
+            ///
+            /// # Example
+            /// ```
+            /// # use crate::data_structure::ds::doubly_linked_list::DoublyLinkedList;
+            /// let mut list = DoublyLinkedList::from([1, 2]);
+            /// assert_eq!(list.pop_front(), Some(1));
+            /// assert_eq!(list.pop_front(), Some(2));
+            /// assert_eq!(list.pop_front(), None);
+            /// ```
+            pub fn pop_front(&mut self) -> Option<T> {
+                // SAFETY: `head` is a node owned by the list
+                self.head.map(|node| unsafe { self.unlink(node).val })
+            }
+
+            /// Pops the element on the back of the list in `O(1)`,
+            /// returning `None` if the list is empty
+            ///
+            /// # Example
+            /// ```
+            /// # use crate::data_structure::ds::doubly_linked_list::DoublyLinkedList;
+            /// let mut list = DoublyLinkedList::from([1, 2]);
+            /// assert_eq!(list.pop_back(), Some(2));
+            /// assert_eq!(list.pop_back(), Some(1));
+            /// assert_eq!(list.pop_back(), None);
+            /// ```
+            pub fn pop_back(&mut self) -> Option<T> {
+                // SAFETY: `tail` is a node owned by the list
+                self.tail.map(|node| unsafe { self.unlink(node).val })
+            }
+
+            /// Prints the list
+            ///
+            /// # Example
+            /// ```
+            /// # use crate::data_structure::ds::doubly_linked_list::DoublyLinkedList;
+            /// let list = DoublyLinkedList::from([1, 2, 3]);
+            /// list.print(); //Output = None <- 1 <-> 2 <-> 3 -> None
+            /// ```
+            pub fn print(&self)
+            where
+                T: Display,
+            {
+                print!("None <- ");
+                for (i, val) in self.iter().enumerate() {
+                    if i > 0 {
+                        print!(" <-> ");
+                    }
+                    print!("{}", val);
+                }
+                println!(" -> None");
+            }
+
+            /// Finds the first element equal to the given `val: T`,
+            /// returning `Err(Error::NotFound)` if there is none
+            ///
+            /// # Example
+            /// ```
+            /// # use crate::data_structure::ds::{Error, doubly_linked_list::DoublyLinkedList};
+            /// let list = DoublyLinkedList::from([1, 2, 3]);
+            /// assert_eq!(list.find(2), Ok(&2));
+            /// assert_eq!(list.find(4), Err(Error::NotFound));
+            /// ```
+            pub fn find(&self, val: T) -> Result<&T, Error>
+            where
+                T: PartialEq,
+            {
+                self.iter().find(|node_val| **node_val == val).ok_or(Error::NotFound)
+            }
+
+            /// Deletes the first element equal to the given `val: T`,
+            /// returning `Err(Error::NotFound)` if there is none
+            ///
+            /// # Example
+            /// ```
+            /// # use crate::data_structure::ds::{Error, doubly_linked_list::DoublyLinkedList};
+            /// let mut list = DoublyLinkedList::from([1, 2, 2, 3]);
+            /// assert_eq!(list.delete(2), Ok(()));
+            /// assert_eq!(list, DoublyLinkedList::from([1, 2, 3]));
+            /// assert_eq!(list.delete(4), Err(Error::NotFound));
+            /// ```
+            pub fn delete(&mut self, val: T) -> Result<(), Error>
+            where
+                T: PartialEq,
+            {
+                let mut current = self.head;
+                while let Some(node) = current {
+                    // SAFETY: every node reachable from `head` is owned by the list
+                    unsafe {
+                        if (*node.as_ptr()).val == val {
+                            self.unlink(node);
+                            return Ok(());
+                        }
+                        current = (*node.as_ptr()).next;
+                    }
+                }
+                Err(Error::NotFound)
+            }
+
+            /// Inserts `val: T` in position `index: usize`, walking from whichever end is closer
+            ///
+            /// if the index is out of range, the function would return
+            /// `Err(Error::IndexOutOfRange)`
+            ///
+            /// # Example
+            /// ```
+            /// # use crate::data_structure::ds::doubly_linked_list::DoublyLinkedList;
+            /// let mut list = DoublyLinkedList::from([1, 3]);
+            /// list.insert(1, 2).unwrap();
+            /// list.insert(3, 4).unwrap();
+            /// assert_eq!(list, DoublyLinkedList::from([1, 2, 3, 4]));
+            /// assert!(list.insert(6, 0).is_err());
+            /// ```
+            pub fn insert(&mut self, index: usize, val: T) -> Result<(), Error> {
+                if index > self.len {
+                    return Err(Error::IndexOutOfRange { index, len: self.len });
+                }
+                let prev = if index == 0 { None } else { self.node_at(index - 1) };
+                // SAFETY: `prev` is owned by the list and the new node is not linked anywhere yet
+                unsafe { self.link_after(prev, self.new_node(val)) };
+                Ok(())
+            }
+
+            /// Returns a reference to the element at `index: usize`,
+            /// or `Err(Error::IndexOutOfRange)` instead of panicking like `list[index]`
+            ///
+            /// # Example
+            /// ```
+            /// # use crate::data_structure::ds::{Error, doubly_linked_list::DoublyLinkedList};
+            /// let list = DoublyLinkedList::from([1, 2, 3]);
+            /// assert_eq!(list.try_get(2), Ok(&3));
+            /// assert_eq!(list[1], 2);
+            /// assert_eq!(list.try_get(3), Err(Error::IndexOutOfRange { index: 3, len: 3 }));
+            /// ```
+            pub fn try_get(&self, index: usize) -> Result<&T, Error> {
+                match self.node_at(index) {
+                    // SAFETY: the node is owned by the list
+                    Some(node) => Ok(unsafe { &(*node.as_ptr()).val }),
+                    None => Err(Error::IndexOutOfRange { index, len: self.len }),
+                }
+            }
+
+            /// Returns a mutable reference to the element at `index: usize`,
+            /// or `Err(Error::IndexOutOfRange)` instead of panicking like `list[index]`
+            pub fn try_get_mut(&mut self, index: usize) -> Result<&mut T, Error> {
+                match self.node_at(index) {
+                    // SAFETY: the node is owned by the list, borrowed mutably through `self`
+                    Some(node) => Ok(unsafe { &mut (*node.as_ptr()).val }),
+                    None => Err(Error::IndexOutOfRange { index, len: self.len }),
+                }
+            }
+
+            /// Checks if whether the list contains the given `val: T` element
+            ///
+            /// # Example
+            /// ```
+            /// # use crate::data_structure::ds::doubly_linked_list::DoublyLinkedList;
+            /// let list = DoublyLinkedList::from([1, 2, 3]);
+            /// assert!(list.contains(3));
+            /// assert!(!list.contains(4));
+            /// ```
+            pub fn contains(&self, val: T) -> bool
+            where
+                T: PartialEq,
+            {
+                self.iter().any(|node_val| *node_val == val)
+            }
+
+            /// Reverses the list in place by swapping the links of every node
+            ///
+            /// # Example
+            /// ```
+            /// # use crate::data_structure::ds::doubly_linked_list::DoublyLinkedList;
+            /// let mut list = DoublyLinkedList::from([String::from("a"), String::from("b")]);
+            /// list.reverse();
+            /// assert_eq!(list, DoublyLinkedList::from([String::from("b"), String::from("a")]));
+            /// ```
+            pub fn reverse(&mut self) {
+                let mut current = self.head;
+                while let Some(node) = current {
+                    // SAFETY: every node reachable from `head` is owned by the list
+                    unsafe {
+                        let node = &mut *node.as_ptr();
+                        std::mem::swap(&mut node.prev, &mut node.next);
+                        current = node.prev;
+                    }
+                }
+                std::mem::swap(&mut self.head, &mut self.tail);
+            }
+
+            /// Sorts the list, keeping equal elements in order
+            ///
+            /// # Example
+            /// ```
+            /// # use crate::data_structure::ds::doubly_linked_list::DoublyLinkedList;
+            /// let mut list = DoublyLinkedList::from([3, 1, 2]);
+            /// list.sort();
+            /// assert_eq!(list, DoublyLinkedList::from([1, 2, 3]));
+            /// ```
+            pub fn sort(&mut self)
+            where
+                T: Ord,
+            {
+                self.sort_by(T::cmp);
+            }
+
+            /// Sorts the list with the comparator `cmp`, keeping equal elements in order
+            ///
+            /// The nodes are relinked rather than their values moved.
+            ///
+            /// # Example
+            /// ```
+            /// # use crate::data_structure::ds::doubly_linked_list::DoublyLinkedList;
+            /// let mut list = DoublyLinkedList::from([1, 3, 2]);
+            /// list.sort_by(|a, b| b.cmp(a));
+            /// assert_eq!(list, DoublyLinkedList::from([3, 2, 1]));
+            /// ```
+            pub fn sort_by<F>(&mut self, mut cmp: F)
+            where
+                F: FnMut(&T, &T) -> Ordering,
+            {
+                let mut nodes = Vec::with_capacity(self.len);
+                let mut current = self.head;
+                while let Some(node) = current {
+                    nodes.push(node);
+                    // SAFETY: every node reachable from `head` is owned by the list
+                    current = unsafe { (*node.as_ptr()).next };
+                }
+
+                // SAFETY: the nodes stay owned by the list while they are compared
+                nodes.sort_by(|a, b| unsafe { cmp(&(*a.as_ptr()).val, &(*b.as_ptr()).val) });
+
+                let mut prev = None;
+                for &node in &nodes {
+                    // SAFETY: each node is relinked exactly once, in sorted order
+                    unsafe {
+                        (*node.as_ptr()).prev = prev;
+                        (*node.as_ptr()).next = None;
+                        if let Some(prev) = prev {
+                            (*prev.as_ptr()).next = Some(node);
+                        }
+                    }
+                    prev = Some(node);
+                }
+                self.head = nodes.first().copied();
+                self.tail = prev;
+            }
+
+            /// Sorts the list by the keys extracted by `f`, keeping equal elements in order
+            ///
+            /// # Example
+            /// ```
+            /// # use crate::data_structure::ds::doubly_linked_list::DoublyLinkedList;
+            /// let mut list = DoublyLinkedList::from(["ccc", "a", "bb"]);
+            /// list.sort_by_key(|s| s.len());
+            /// assert_eq!(list, DoublyLinkedList::from(["a", "bb", "ccc"]));
+            /// ```
+            pub fn sort_by_key<K, F>(&mut self, mut f: F)
+            where
+                K: Ord,
+                F: FnMut(&T) -> K,
+            {
+                self.sort_by(|a, b| f(a).cmp(&f(b)));
+            }
+
+            /// Splits the list in two at `at: usize`, returning everything from
+            /// that index on and keeping the first `at` elements
+            ///
+            /// if the index is out of range, the function would return
+            /// `Err(Error::IndexOutOfRange)`
+            ///
+            /// # Example
+            /// ```
+            /// # use crate::data_structure::ds::doubly_linked_list::DoublyLinkedList;
+            /// let mut list = DoublyLinkedList::from([1, 2, 3, 4]);
+            /// let tail = list.split_off(1).unwrap();
+            /// assert_eq!(list, DoublyLinkedList::from([1]));
+            /// assert_eq!(tail, DoublyLinkedList::from([2, 3, 4]));
+            /// ```
+            pub fn split_off(&mut self, at: usize) -> Result<Self, Error>
+            where
+                A: Clone,
+            {
+                if at > self.len {
+                    return Err(Error::IndexOutOfRange { index: at, len: self.len });
+                }
+                let node = if at == 0 { None } else { self.node_at(at - 1) };
+                // SAFETY: `node` is owned by the list and sits at index `at - 1`
+                Ok(unsafe { self.split_after_node(node, at) })
+            }
+
+            /// Removes every element from the list
+            pub fn clear(&mut self) {
+                while self.pop_front().is_some() {}
+            }
+
+            /// Returns a double-ended iterator over references to the elements of the list
+            ///
+            /// # Example
+            /// ```
+            /// # use crate::data_structure::ds::doubly_linked_list::DoublyLinkedList;
+            /// let list = DoublyLinkedList::from([1, 2, 3]);
+            /// assert_eq!(list.iter().rev().collect::<Vec<_>>(), vec![&3, &2, &1]);
+            /// ```
+            pub fn iter(&self) -> Iter<'_, T> {
+                Iter {
+                    head: self.head,
+                    tail: self.tail,
+                    len: self.len,
+                    marker: PhantomData,
+                }
+            }
+
+            /// Returns a double-ended iterator over mutable references to the elements of the list
+            ///
+            /// # Example
+            /// ```
+            /// # use crate::data_structure::ds::doubly_linked_list::DoublyLinkedList;
+            /// let mut list = DoublyLinkedList::from([1, 2, 3]);
+            /// list.iter_mut().rev().for_each(|val| *val *= 2);
+            /// assert_eq!(list, DoublyLinkedList::from([2, 4, 6]));
+            /// ```
+            pub fn iter_mut(&mut self) -> IterMut<'_, T> {
+                IterMut {
+                    head: self.head,
+                    tail: self.tail,
+                    len: self.len,
+                    marker: PhantomData,
+                }
+            }
+
+            /// Returns a cursor pointing at the first element of the list
+            ///
+            /// # Example
+            /// ```
+            /// # use crate::data_structure::ds::doubly_linked_list::DoublyLinkedList;
+            /// let mut list = DoublyLinkedList::from([1, 3]);
+            /// let mut cursor = list.cursor_front_mut();
+            /// cursor.insert_after(2);
+            /// assert_eq!(list, DoublyLinkedList::from([1, 2, 3]));
+            /// ```
+            pub fn cursor_front_mut(&mut self) -> CursorMut<'_, T, A> {
+                CursorMut {
+                    current: self.head,
+                    index: 0,
+                    list: self,
+                }
+            }
+
+            /// Returns a cursor pointing at the last element of the list
+            ///
+            /// # Example
+            /// ```
+            /// # use crate::data_structure::ds::doubly_linked_list::DoublyLinkedList;
+            /// let mut list = DoublyLinkedList::from([1, 3]);
+            /// let mut cursor = list.cursor_back_mut();
+            /// cursor.insert_before(2);
+            /// assert_eq!(list, DoublyLinkedList::from([1, 2, 3]));
+            /// ```
+            pub fn cursor_back_mut(&mut self) -> CursorMut<'_, T, A> {
+                CursorMut {
+                    current: self.tail,
+                    index: self.len.saturating_sub(1),
+                    list: self,
+                }
+            }
+
+            fn new_node(&self, val: T) -> NonNull<Node<T>> {
+                let layout = Layout::new::<Node<T>>();
+                let node = match self.alloc.allocate(layout) {
+                    Ok(ptr) => ptr.cast::<Node<T>>(),
+                    Err(_) => alloc::handle_alloc_error(layout),
+                };
+                // SAFETY: `node` was just allocated with the layout of a `Node<T>`
+                unsafe {
+                    node.as_ptr().write(Node {
+                        val,
+                        prev: None,
+                        next: None,
+                    });
+                }
+                node
+            }
+
+            /// Returns the node at `index`, walking from whichever end is closer
+            fn node_at(&self, index: usize) -> Link<T> {
+                if index >= self.len {
+                    return None;
+                }
+                // SAFETY: every node reachable from `head` or `tail` is owned by the list,
+                // and `index < len` keeps the walk inside the list
+                unsafe {
+                    if index < self.len / 2 {
+                        let mut node = self.head?;
+                        for _ in 0..index {
+                            node = (*node.as_ptr()).next?;
+                        }
+                        Some(node)
+                    } else {
+                        let mut node = self.tail?;
+                        for _ in index + 1..self.len {
+                            node = (*node.as_ptr()).prev?;
+                        }
+                        Some(node)
+                    }
+                }
+            }
+
+            /// Links `node` in right after `prev`, or at the front if `prev` is `None`
+            ///
+            /// # Safety
+            /// `prev` must be owned by this list and `node` must not be linked anywhere.
+            unsafe fn link_after(&mut self, prev: Link<T>, node: NonNull<Node<T>>) {
+                let next = match prev {
+                    Some(prev) => (*prev.as_ptr()).next,
+                    None => self.head,
+                };
+                (*node.as_ptr()).prev = prev;
+                (*node.as_ptr()).next = next;
+                match prev {
+                    Some(prev) => (*prev.as_ptr()).next = Some(node),
+                    None => self.head = Some(node),
+                }
+                match next {
+                    Some(next) => (*next.as_ptr()).prev = Some(node),
+                    None => self.tail = Some(node),
+                }
+                self.len += 1;
+            }
+
+            /// Unlinks `node` from the list and frees it, handing back its contents
+            ///
+            /// # Safety
+            /// `node` must be owned by this list.
+            unsafe fn unlink(&mut self, node: NonNull<Node<T>>) -> Node<T> {
+                let ptr = node;
+                let node = ptr.as_ptr().read();
+                self.alloc.deallocate(ptr.cast(), Layout::new::<Node<T>>());
+                match node.prev {
+                    Some(prev) => (*prev.as_ptr()).next = node.next,
+                    None => self.head = node.next,
+                }
+                match node.next {
+                    Some(next) => (*next.as_ptr()).prev = node.prev,
+                    None => self.tail = node.prev,
+                }
+                self.len -= 1;
+                node
+            }
+
+            /// Detaches everything after `node` (everything if `node` is `None`)
+            /// into a new list, where `at` is the index following `node`
+            ///
+            /// # Safety
+            /// `node` must be owned by this list and sit at index `at - 1`.
+            unsafe fn split_after_node(&mut self, node: Link<T>, at: usize) -> Self
+            where
+                A: Clone,
+            {
+                let head = match node {
+                    Some(node) => (*node.as_ptr()).next.take(),
+                    None => self.head.take(),
+                };
+                let Some(head) = head else {
+                    return DoublyLinkedList::new_in(self.alloc.clone());
+                };
+                (*head.as_ptr()).prev = None;
+
+                let split = DoublyLinkedList {
+                    head: Some(head),
+                    tail: self.tail,
+                    len: self.len - at,
+                    alloc: self.alloc.clone(),
+                    marker: PhantomData,
+                };
+                self.tail = node;
+                self.len = at;
+                split
+            }
+
+            /// Links every node of `other` in right after `node`, or at the front if it is `None`,
+            /// leaving `other` empty
+            ///
+            /// # Safety
+            /// `node` must be owned by this list, and the allocator of `other` must be
+            /// able to free the nodes of this one.
+            unsafe fn splice_after_node(&mut self, node: Link<T>, other: &mut Self) {
+                let (Some(other_head), Some(other_tail)) = (other.head.take(), other.tail.take()) else {
+                    return;
+                };
+                let next = match node {
+                    Some(node) => (*node.as_ptr()).next,
+                    None => self.head,
+                };
+                (*other_head.as_ptr()).prev = node;
+                (*other_tail.as_ptr()).next = next;
+                match node {
+                    Some(node) => (*node.as_ptr()).next = Some(other_head),
+                    None => self.head = Some(other_head),
+                }
+                match next {
+                    Some(next) => (*next.as_ptr()).prev = Some(other_tail),
+                    None => self.tail = Some(other_tail),
+                }
+                self.len += std::mem::take(&mut other.len);
+            }
+        }
+
+        impl<T, A: Allocator + PartialEq> DoublyLinkedList<T, A> {
+            /// Moves every element of `other` to the back of the list in `O(1)`,
+            /// leaving `other` empty
+            ///
+            /// # Panics
+            /// Panics if the two lists don't use equal allocators, as the moved nodes
+            /// are freed by this list's allocator from then on
+            ///
+            /// # Example
+            /// ```
+            /// # use crate::data_structure::ds::doubly_linked_list::DoublyLinkedList;
+            /// let mut list = DoublyLinkedList::from([1, 2]);
+            /// let mut other = DoublyLinkedList::from([3, 4]);
+            /// list.append(&mut other);
+            /// assert_eq!(list, DoublyLinkedList::from([1, 2, 3, 4]));
+            /// assert!(other.is_empty());
+            /// ```
+            pub fn append(&mut self, other: &mut Self) {
+                assert!(self.alloc == other.alloc, "Can't move nodes between lists with different allocators");
+                // SAFETY: `tail` is owned by the list, `other` is a separate list and
+                // equal allocators free each other's nodes
+                unsafe { self.splice_after_node(self.tail, other) }
+            }
+        }
+
+        impl<T, A: Allocator> Drop for DoublyLinkedList<T, A> {
+            fn drop(&mut self) {
+                self.clear();
+            }
+        }
+
+        impl<T> Default for DoublyLinkedList<T> {
+            fn default() -> Self {
+                Self::new()
+            }
+        }
+
+        impl<T: Clone, A: Allocator + Clone> Clone for DoublyLinkedList<T, A> {
+            fn clone(&self) -> Self {
+                let mut list = DoublyLinkedList::new_in(self.alloc.clone());
+                list.extend(self.iter().cloned());
+                list
+            }
+        }
+
+        impl<T: Debug, A: Allocator> Debug for DoublyLinkedList<T, A> {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.debug_list().entries(self.iter()).finish()
+            }
+        }
+
+        impl<T: PartialEq, A: Allocator, B: Allocator> PartialEq<DoublyLinkedList<T, B>>
+            for DoublyLinkedList<T, A>
+        {
+            fn eq(&self, other: &DoublyLinkedList<T, B>) -> bool {
+                self.len == other.len && self.iter().eq(other.iter())
+            }
+        }
+
+        impl<T, A: Allocator> Index<usize> for DoublyLinkedList<T, A> {
+            type Output = T;
+
+            fn index(&self, index: usize) -> &Self::Output {
+                self.try_get(index).unwrap_or_else(|e| panic!("{}", e))
+            }
+        }
+
+        impl<T, A: Allocator> IndexMut<usize> for DoublyLinkedList<T, A> {
+            fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+                self.try_get_mut(index).unwrap_or_else(|e| panic!("{}", e))
+            }
+        }
+
+        impl<T> FromIterator<T> for DoublyLinkedList<T> {
+            fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+                let mut list = DoublyLinkedList::new();
+                list.extend(iter);
+                list
+            }
+        }
+
+        /// Appends every element of the iterator to the back of the list
+        impl<T, A: Allocator> Extend<T> for DoublyLinkedList<T, A> {
+            fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+                for val in iter {
+                    self.push_back(val);
+                }
+            }
+        }
+
+        impl<'a, T: Copy + 'a, A: Allocator> Extend<&'a T> for DoublyLinkedList<T, A> {
+            fn extend<I: IntoIterator<Item = &'a T>>(&mut self, iter: I) {
+                self.extend(iter.into_iter().copied());
+            }
+        }
+
+        impl<T, const N: usize> From<[T; N]> for DoublyLinkedList<T> {
+            fn from(arr: [T; N]) -> Self {
+                arr.into_iter().collect()
+            }
+        }
+
+        /// A cursor over a `DoublyLinkedList<T>` which can move both ways
+        /// and edit the list around the element under it
+        ///
+        /// Past either end the cursor sits on a "ghost" position, where
+        /// [current](struct.CursorMut.html#method.current) returns `None`;
+        /// moving on from it wraps around to the other end of the list.
+        ///
+        /// # Example
+        /// ```
+        /// # use crate::data_structure::ds::doubly_linked_list::DoublyLinkedList;
+        /// let mut list = DoublyLinkedList::from([1, 2, 3]);
+        /// let mut cursor = list.cursor_back_mut();
+        /// cursor.move_prev();
+        /// assert_eq!(cursor.remove_current(), Some(2));
+        /// assert_eq!(cursor.current(), Some(&mut 3));
+        /// cursor.move_next();
+        /// assert_eq!(cursor.current(), None);
+        /// cursor.move_next();
+        /// assert_eq!(cursor.current(), Some(&mut 1));
+        /// ```
+        pub struct CursorMut<'a, T, A: Allocator = Global> {
+            current: Link<T>,
+            // Equals `list.len` on the ghost position
+            index: usize,
+            list: &'a mut DoublyLinkedList<T, A>,
+        }
+
+        impl<T, A: Allocator> CursorMut<'_, T, A> {
+            /// Returns the position of the cursor, which is the length
+            /// of the list on the ghost position
+            pub fn index(&self) -> usize {
+                self.index
+            }
+
+            /// Returns a mutable reference to the element under the cursor,
+            /// or `None` on the ghost position
+            pub fn current(&mut self) -> Option<&mut T> {
+                // SAFETY: `current` is owned by the list, borrowed mutably through the cursor
+                self.current.map(|node| unsafe { &mut (*node.as_ptr()).val })
+            }
+
+            /// Returns a mutable reference to the element after the cursor, if any
+            pub fn peek_next(&mut self) -> Option<&mut T> {
+                // SAFETY: every node reachable from the list is owned by it
+                unsafe {
+                    let next = match self.current {
+                        Some(node) => (*node.as_ptr()).next,
+                        None => self.list.head,
+                    };
+                    next.map(|node| &mut (*node.as_ptr()).val)
+                }
+            }
+
+            /// Returns a mutable reference to the element before the cursor, if any
+            pub fn peek_prev(&mut self) -> Option<&mut T> {
+                // SAFETY: every node reachable from the list is owned by it
+                unsafe {
+                    let prev = match self.current {
+                        Some(node) => (*node.as_ptr()).prev,
+                        None => self.list.tail,
+                    };
+                    prev.map(|node| &mut (*node.as_ptr()).val)
+                }
+            }
+
+            /// Moves the cursor to the next element, or from the last
+            /// element to the ghost position and from there to the first element
+            pub fn move_next(&mut self) {
+                match self.current {
+                    // SAFETY: `current` is owned by the list
+                    Some(node) => {
+                        self.current = unsafe { (*node.as_ptr()).next };
+                        self.index += 1;
+                    }
+                    None => {
+                        self.current = self.list.head;
+                        self.index = 0;
+                    }
+                }
+            }
+
+            /// Moves the cursor to the previous element, or from the first
+            /// element to the ghost position and from there to the last element
+            pub fn move_prev(&mut self) {
+                match self.current {
+                    // SAFETY: `current` is owned by the list
+                    Some(node) => {
+                        self.current = unsafe { (*node.as_ptr()).prev };
+                        self.index = match self.current {
+                            Some(_) => self.index - 1,
+                            None => self.list.len,
+                        };
+                    }
+                    None => {
+                        self.current = self.list.tail;
+                        self.index = self.list.len.saturating_sub(1);
+                    }
+                }
+            }
+
+            /// Inserts `val: T` after the element under the cursor,
+            /// or at the front of the list on the ghost position
+            pub fn insert_after(&mut self, val: T) {
+                // SAFETY: `current` is owned by the list and the new node is not linked anywhere yet
+                unsafe {
+                    self.list
+                        .link_after(self.current, self.list.new_node(val))
+                };
+                if self.current.is_none() {
+                    self.index += 1;
+                }
+            }
+
+            /// Inserts `val: T` before the element under the cursor,
+            /// or at the back of the list on the ghost position
+            pub fn insert_before(&mut self, val: T) {
+                let prev = match self.current {
+                    // SAFETY: `current` is owned by the list
+                    Some(node) => unsafe { (*node.as_ptr()).prev },
+                    None => self.list.tail,
+                };
+                // SAFETY: `prev` is owned by the list and the new node is not linked anywhere yet
+                unsafe { self.list.link_after(prev, self.list.new_node(val)) };
+                self.index += 1;
+            }
+
+            /// Removes the element under the cursor, returning it
+            ///
+            /// The cursor then points at the element that followed the removed one.
+            /// Returns `None` and leaves the list untouched on the ghost position.
+            pub fn remove_current(&mut self) -> Option<T> {
+                let node = self.current?;
+                // SAFETY: `current` is owned by the list
+                let node = unsafe { self.list.unlink(node) };
+                self.current = node.next;
+                Some(node.val)
+            }
+
+            /// Detaches every element after the one under the cursor,
+            /// or the whole list on the ghost position, returning them as a new list
+            ///
+            /// # Example
+            /// ```
+            /// # use crate::data_structure::ds::doubly_linked_list::DoublyLinkedList;
+            /// let mut list = DoublyLinkedList::from([1, 2, 3]);
+            /// let mut cursor = list.cursor_front_mut();
+            /// assert_eq!(cursor.split_after(), DoublyLinkedList::from([2, 3]));
+            /// assert_eq!(list, DoublyLinkedList::from([1]));
+            /// ```
+            pub fn split_after(&mut self) -> DoublyLinkedList<T, A>
+            where
+                A: Clone,
+            {
+                let at = match self.current {
+                    Some(_) => self.index + 1,
+                    None => 0,
+                };
+                // SAFETY: `current` is owned by the list and sits at `at - 1`
+                let split = unsafe { self.list.split_after_node(self.current, at) };
+                if self.current.is_none() {
+                    self.index = 0;
+                }
+                split
+            }
+
+        }
+
+        impl<T, A: Allocator + PartialEq> CursorMut<'_, T, A> {
+            /// Moves every element of `list` in after the one under the cursor,
+            /// or at the front of the list on the ghost position, in `O(1)`
+            ///
+            /// # Panics
+            /// Panics if `list` doesn't use an allocator equal to the cursor's list
+            ///
+            /// # Example
+            /// ```
+            /// # use crate::data_structure::ds::doubly_linked_list::DoublyLinkedList;
+            /// let mut list = DoublyLinkedList::from([1, 4]);
+            /// let mut cursor = list.cursor_front_mut();
+            /// cursor.splice_after(DoublyLinkedList::from([2, 3]));
+            /// assert_eq!(list, DoublyLinkedList::from([1, 2, 3, 4]));
+            /// ```
+            pub fn splice_after(&mut self, mut list: DoublyLinkedList<T, A>) {
+                assert!(self.list.alloc == list.alloc, "Can't move nodes between lists with different allocators");
+                let spliced_len = list.len;
+                // SAFETY: `current` is owned by the list, `list` is a separate one and
+                // equal allocators free each other's nodes
+                unsafe { self.list.splice_after_node(self.current, &mut list) };
+                if self.current.is_none() {
+                    self.index += spliced_len;
+                }
+            }
+        }
+
+        /// Borrowing double-ended iterator over a `DoublyLinkedList<T>`
+        pub struct Iter<'a, T> {
+            head: Link<T>,
+            tail: Link<T>,
+            len: usize,
+            marker: PhantomData<&'a Node<T>>,
+        }
+
+        // SAFETY: `Iter` only hands out shared references to the elements
+        unsafe impl<T: Sync> Send for Iter<'_, T> {}
+        unsafe impl<T: Sync> Sync for Iter<'_, T> {}
+
+        impl<T> Clone for Iter<'_, T> {
+            fn clone(&self) -> Self {
+                Iter { ..*self }
+            }
+        }
+
+        impl<'a, T> Iterator for Iter<'a, T> {
+            type Item = &'a T;
+
+            fn next(&mut self) -> Option<Self::Item> {
+                if self.len == 0 {
+                    return None;
+                }
+                self.head.map(|node| {
+                    self.len -= 1;
+                    // SAFETY: the node is owned by the list borrowed for `'a`
+                    unsafe {
+                        self.head = (*node.as_ptr()).next;
+                        &(*node.as_ptr()).val
+                    }
+                })
+            }
+
+            fn size_hint(&self) -> (usize, Option<usize>) {
+                (self.len, Some(self.len))
+            }
+        }
+
+        impl<T> DoubleEndedIterator for Iter<'_, T> {
+            fn next_back(&mut self) -> Option<Self::Item> {
+                if self.len == 0 {
+                    return None;
+                }
+                self.tail.map(|node| {
+                    self.len -= 1;
+                    // SAFETY: the node is owned by the list borrowed for `'a`
+                    unsafe {
+                        self.tail = (*node.as_ptr()).prev;
+                        &(*node.as_ptr()).val
+                    }
+                })
+            }
+        }
+
+        impl<T> ExactSizeIterator for Iter<'_, T> {}
+
+        impl<T> FusedIterator for Iter<'_, T> {}
+
+        /// Mutable borrowing double-ended iterator over a `DoublyLinkedList<T>`
+        pub struct IterMut<'a, T> {
+            head: Link<T>,
+            tail: Link<T>,
+            len: usize,
+            marker: PhantomData<&'a mut Node<T>>,
+        }
+
+        // SAFETY: `IterMut` hands out each mutable reference to the elements once
+        unsafe impl<T: Send> Send for IterMut<'_, T> {}
+        unsafe impl<T: Sync> Sync for IterMut<'_, T> {}
+
+        impl<'a, T> Iterator for IterMut<'a, T> {
+            type Item = &'a mut T;
+
+            fn next(&mut self) -> Option<Self::Item> {
+                if self.len == 0 {
+                    return None;
+                }
+                self.head.map(|node| {
+                    self.len -= 1;
+                    // SAFETY: the node is owned by the list mutably borrowed for `'a`,
+                    // and `len` stops the iterator before the ends cross
+                    unsafe {
+                        self.head = (*node.as_ptr()).next;
+                        &mut (*node.as_ptr()).val
+                    }
+                })
+            }
+
+            fn size_hint(&self) -> (usize, Option<usize>) {
+                (self.len, Some(self.len))
+            }
+        }
+
+        impl<T> DoubleEndedIterator for IterMut<'_, T> {
+            fn next_back(&mut self) -> Option<Self::Item> {
+                if self.len == 0 {
+                    return None;
+                }
+                self.tail.map(|node| {
+                    self.len -= 1;
+                    // SAFETY: the node is owned by the list mutably borrowed for `'a`,
+                    // and `len` stops the iterator before the ends cross
+                    unsafe {
+                        self.tail = (*node.as_ptr()).prev;
+                        &mut (*node.as_ptr()).val
+                    }
+                })
+            }
+        }
+
+        impl<T> ExactSizeIterator for IterMut<'_, T> {}
+
+        impl<T> FusedIterator for IterMut<'_, T> {}
+
+        /// Owning double-ended iterator over a `DoublyLinkedList<T>`
+        ///
+        /// # Example
+        /// ```
+        /// # use crate::data_structure::ds::doubly_linked_list::DoublyLinkedList;
+        /// let list = DoublyLinkedList::from([1, 2, 3]);
+        /// assert_eq!(list.into_iter().rev().collect::<Vec<_>>(), vec![3, 2, 1]);
+        /// ```
+        pub struct IntoIter<T, A: Allocator = Global> {
+            list: DoublyLinkedList<T, A>,
+        }
+
+        impl<T, A: Allocator> Iterator for IntoIter<T, A> {
+            type Item = T;
+
+            fn next(&mut self) -> Option<Self::Item> {
+                self.list.pop_front()
+            }
+
+            fn size_hint(&self) -> (usize, Option<usize>) {
+                (self.list.len, Some(self.list.len))
+            }
+        }
+
+        impl<T, A: Allocator> DoubleEndedIterator for IntoIter<T, A> {
+            fn next_back(&mut self) -> Option<Self::Item> {
+                self.list.pop_back()
+            }
+        }
+
+        impl<T, A: Allocator> ExactSizeIterator for IntoIter<T, A> {}
+
+        impl<T, A: Allocator> FusedIterator for IntoIter<T, A> {}
+
+        impl<T, A: Allocator> IntoIterator for DoublyLinkedList<T, A> {
+            type Item = T;
+            type IntoIter = IntoIter<T, A>;
+
+            fn into_iter(self) -> Self::IntoIter {
+                IntoIter { list: self }
+            }
+        }
+
+        impl<'a, T, A: Allocator> IntoIterator for &'a DoublyLinkedList<T, A> {
+            type Item = &'a T;
+            type IntoIter = Iter<'a, T>;
+
+            fn into_iter(self) -> Self::IntoIter {
+                self.iter()
+            }
+        }
+
+        impl<'a, T, A: Allocator> IntoIterator for &'a mut DoublyLinkedList<T, A> {
+            type Item = &'a mut T;
+            type IntoIter = IterMut<'a, T>;
+
+            fn into_iter(self) -> Self::IntoIter {
+                self.iter_mut()
+            }
+        }
+    }
+
+    /// This module provides a Stack struct named `Stack`
+    ///
+    /// The elements live in a [StackStorage], which is a `Vector<T>` by default;
+    /// `ListStack<T, A>` is the same stack on top of a `LinkedList<T, A>`, and a
+    /// `DoublyLinkedList<T, A>` can hold a stack whose bottom has to be dropped cheaply.
+    ///
+    /// Functions Implemented:
+    /// * [new](struct.Stack.html#method.new) -> `Self`
+    /// * [from_vec](struct.Stack.html#method.from_vec) -> `Self`
+    /// * [with_storage](struct.Stack.html#method.with_storage) -> `Self`
+    /// * [storage_mut](struct.Stack.html#method.storage_mut) -> `&mut S`
+    /// * [len](struct.Stack.html#method.len) -> `usize`
+    /// * [is_empty](struct.Stack.html#method.is_empty) -> `bool`
+    /// * [print](struct.Stack.html#method.print) -> `()`
+    /// * [pop](struct.Stack.html#method.pop) -> `Option<T>`
+    /// * [try_pop](struct.Stack.html#method.try_pop) -> `Result<T, Error>`
+    /// * [push](struct.Stack.html#method.push) -> `()`
+    /// * [peek](struct.Stack.html#method.peek) -> `Option<&T>`
+    /// * [peek_mut](struct.Stack.html#method.peek_mut) -> `Option<&mut T>`
+    /// * [clear](struct.Stack.html#method.clear) -> `()`
+    /// * [iter](struct.Stack.html#method.iter) -> `S::Iter<'_>`
+    ///
+    /// `BoundedStack<T, N>` is a fixed-capacity stack stored inline, which handles
+    /// pushes past `N` according to its [OverflowPolicy].
+    ///
+    /// `MinMaxStack<T>` tracks its minimum and maximum in `O(1)`, and
+    /// [next_greater], [next_smaller] and [next_by] answer "next greater element"
+    /// queries over slices with a monotonic stack.
+    ///
+    /// `ConcurrentStack<T>` is a lock-free stack for sharing between threads,
+    /// reclaiming popped nodes with hazard pointers.
+    pub mod stack {
+        use std::fmt::{Debug, Display};
+        use std::iter::{Chain, Rev};
+        use std::marker::PhantomData;
+        use std::mem::{ManuallyDrop, MaybeUninit};
+        use std::ptr;
+        use std::slice;
+        #[cfg(not(loom))]
+        use std::sync::atomic::{AtomicBool, AtomicPtr, AtomicUsize, Ordering};
+        #[cfg(loom)]
+        use loom::sync::atomic::{AtomicBool, AtomicPtr, AtomicUsize, Ordering};
+        use crate::ds::Error;
+        use crate::ds::allocator::{Allocator, Global};
+        use crate::ds::doubly_linked_list::{self, DoublyLinkedList};
+        use crate::ds::linked_list::{self, LinkedList};
+        use crate::ds::vector::Vector;
+
+        /// Storage a [Stack] keeps its elements in, with the top of the stack at
+        /// whichever end the storage can push and pop at in `O(1)`
+        pub trait StackStorage<T> {
+            /// Iterator over the elements from the top of the stack down
+            type Iter<'a>: Iterator<Item = &'a T>
+            where
+                Self: 'a,
+                T: 'a;
+
+            /// Returns the number of elements stored
+            fn len(&self) -> usize;
+
+            /// Returns `true` if nothing is stored
+            fn is_empty(&self) -> bool {
+                self.len() == 0
+            }
+
+            /// Puts `val` on the top
+            fn push(&mut self, val: T);
+
+            /// Takes the top element off
+            fn pop(&mut self) -> Option<T>;
+
+            /// Returns the top element
+            fn peek(&self) -> Option<&T>;
+
+            /// Returns the top element mutably
+            fn peek_mut(&mut self) -> Option<&mut T>;
+
+            /// Drops every element
+            fn clear(&mut self);
+
+            /// Iterates from the top down
+            fn iter(&self) -> Self::Iter<'_>;
+        }
+
+        /// The top of the stack is the back of the vector
+        impl<T, A: Allocator> StackStorage<T> for Vector<T, A> {
+            type Iter<'a> = Rev<slice::Iter<'a, T>> where A: 'a, T: 'a;
+
+            fn len(&self) -> usize {
+                Vector::len(self)
+            }
+
+            fn push(&mut self, val: T) {
+                Vector::push(self, val)
+            }
+
+            fn pop(&mut self) -> Option<T> {
+                Vector::pop(self)
+            }
+
+            fn peek(&self) -> Option<&T> {
+                self.last()
+            }
+
+            fn peek_mut(&mut self) -> Option<&mut T> {
+                self.last_mut()
+            }
+
+            fn clear(&mut self) {
+                Vector::clear(self)
+            }
+
+            fn iter(&self) -> Self::Iter<'_> {
+                self.as_slice().iter().rev()
+            }
+        }
+
+        /// The top of the stack is the front of the list
+        impl<T, A: Allocator + Clone> StackStorage<T> for LinkedList<T, A> {
+            type Iter<'a> = linked_list::Iter<'a, T, A> where A: 'a, T: 'a;
+
+            fn len(&self) -> usize {
+                LinkedList::len(self)
+            }
+
+            fn push(&mut self, val: T) {
+                self.push_front(val)
+            }
+
+            fn pop(&mut self) -> Option<T> {
+                self.pop_front()
+            }
+
+            fn peek(&self) -> Option<&T> {
+                self.front()
+            }
+
+            fn peek_mut(&mut self) -> Option<&mut T> {
+                self.front_mut()
+            }
+
+            fn clear(&mut self) {
+                LinkedList::clear(self)
+            }
+
+            fn iter(&self) -> Self::Iter<'_> {
+                LinkedList::iter(self)
+            }
+        }
+
+        /// The top of the stack is the back of the list, so the bottom can be
+        /// dropped in `O(1)` as well
+        impl<T, A: Allocator> StackStorage<T> for DoublyLinkedList<T, A> {
+            type Iter<'a> = Rev<doubly_linked_list::Iter<'a, T>> where A: 'a, T: 'a;
+
+            fn len(&self) -> usize {
+                DoublyLinkedList::len(self)
+            }
+
+            fn push(&mut self, val: T) {
+                self.push_back(val)
+            }
+
+            fn pop(&mut self) -> Option<T> {
+                self.pop_back()
+            }
+
+            fn peek(&self) -> Option<&T> {
+                self.back()
+            }
+
+            fn peek_mut(&mut self) -> Option<&mut T> {
+                self.back_mut()
+            }
+
+            fn clear(&mut self) {
+                DoublyLinkedList::clear(self)
+            }
+
+            fn iter(&self) -> Self::Iter<'_> {
+                DoublyLinkedList::iter(self).rev()
+            }
+        }
+
+        /// Stack implementation
+        ///
+        /// # Example
+        /// ```
+        /// # use crate::data_structure::ds::stack::{ListStack, Stack};
+        /// let mut stack = Stack::new();
+        /// stack.push(1);
+        /// stack.push(2);
+        /// assert_eq!(stack.pop(), Some(2));
+        ///
+        /// let mut list_stack = ListStack::default();
+        /// list_stack.push(1);
+        /// assert_eq!(list_stack.peek(), Some(&1));
+        /// ```
+        pub struct Stack<T, S: StackStorage<T> = Vector<T>> {
+            storage: S,
+            marker: PhantomData<T>,
+        }
+
+        /// A [Stack] backed by a singly linked list, which never moves its elements
+        pub type ListStack<T, A = Global> = Stack<T, LinkedList<T, A>>;
+
+        impl<T> Stack<T> {
+            /// Constructs a new, empty `Stack<T>` backed by a `Vector<T>`
+            ///
+            /// # Example
+            /// ```
+            /// # use crate::data_structure::ds::stack::Stack;
+            /// let stack: Stack<i32> = Stack::new();
+            /// assert!(stack.is_empty());
+            /// assert_eq!(stack.peek(), None);
+            /// ```
+            pub fn new() -> Self {
+                Stack::with_storage(Vector::new())
+            }
+
+            /// Constructs a new instance of `Stack<T>` with the provided
+            /// `Vec<T>`, whose first element ends up on top
+            ///
+            /// # Example
+            /// ```
+            /// # use crate::data_structure::ds::stack::Stack;
+            /// let mut stack = Stack::from_vec(vec![1, 2, 3]);
+            /// assert_eq!(stack.pop(), Some(1));
+            /// assert_eq!(stack.len(), 2);
+            /// ```
+            pub fn from_vec(vec: Vec<T>) -> Self {
+                vec.into_iter().rev().collect()
+            }
+        }
+
+        impl<T, S: StackStorage<T>> Stack<T, S> {
+            /// Constructs a stack on top of `storage`, whose top element becomes
+            /// the top of the stack
+            ///
+            /// This is how a stack picks its allocator, through `Vector::new_in`
+            /// or `LinkedList::new_in`.
+            ///
+            /// # Example
+            /// ```
+            /// # use crate::data_structure::ds::{allocator::Global, stack::Stack, vector::Vector};
+            /// let mut stack = Stack::with_storage(Vector::new_in(Global));
+            /// stack.push(1);
+            /// assert_eq!(stack.peek(), Some(&1));
+            /// ```
+            pub fn with_storage(storage: S) -> Self {
+                Stack {
+                    storage,
+                    marker: PhantomData,
+                }
+            }
+
+            /// Consumes the stack, returning its storage
+            pub fn into_storage(self) -> S {
+                self.storage
+            }
+
+            /// Returns the storage mutably, e.g. to drop elements from the bottom
+            /// of a stack kept in a `DoublyLinkedList<T>`
+            ///
+            /// # Example
+            /// ```
+            /// # use crate::data_structure::ds::{doubly_linked_list::DoublyLinkedList, stack::Stack};
+            /// let mut stack = Stack::with_storage(DoublyLinkedList::new());
+            /// stack.extend([1, 2, 3]);
+            /// assert_eq!(stack.storage_mut().pop_front(), Some(1));
+            /// assert_eq!(stack.pop(), Some(3));
+            /// assert_eq!(stack.len(), 1);
+            /// ```
+            pub fn storage_mut(&mut self) -> &mut S {
+                &mut self.storage
+            }
+
+            /// Returns the number of elements on the stack
+            pub fn len(&self) -> usize {
+                self.storage.len()
+            }
+
+            /// Returns `true` if the stack holds no elements
+            pub fn is_empty(&self) -> bool {
+                self.storage.is_empty()
+            }
+
+            /// Prints the given Stack.
+            ///
+            /// # Example Output
+            ///
+            /// ```text
+            /// +---+---+---+---+---+
+            /// | 1 | 2 | 3 | 4 | 5 |
+            /// +---+---+---+---+---+
+            ///   ↑
+            ///  HEAD
+            /// ```
+            ///
+            /// The stack is displayed with the top (HEAD) indicated.
+            pub fn print(&self)
+            where T:
+                Display
+            {
+                let separator = "+---".repeat(self.len()) + "+";
+                println!("{}", separator);
+                for val in self.iter() {
+                    print!("| {:^2}", val);
+                }
+                println!("|");
+                println!("{}", separator);
+                println!("  ↑");
+                println!(" HEAD");
+            }
+
+            /// Pops the top element of the stack off, returning `None` if it is empty
+            ///
+            /// # Example
+            /// ```
+            /// # use crate::data_structure::ds::stack::Stack;
+            /// let mut stack = Stack::from_vec(vec![1, 2]);
+            /// assert_eq!(stack.pop(), Some(1));
+            /// assert_eq!(stack.pop(), Some(2));
+            /// assert_eq!(stack.pop(), None);
+            /// ```
+            pub fn pop(&mut self) -> Option<T> {
+                self.storage.pop()
+            }
+
+            /// Pops the top element of the stack off, returning
+            /// `Err(Error::Empty)` if there is none
+            ///
+            /// # Example
+            /// ```
+            /// # use crate::data_structure::ds::{Error, stack::Stack};
+            /// let mut stack = Stack::from_vec(vec![1]);
+            /// assert_eq!(stack.try_pop(), Ok(1));
+            /// assert_eq!(stack.try_pop(), Err(Error::Empty));
+            /// ```
+            pub fn try_pop(&mut self) -> Result<T, Error> {
+                self.pop().ok_or(Error::Empty)
+            }
+
+            /// Pushes an element on to the top of the stack
+            ///
+            /// # Example
+            /// ```
+            /// # use crate::data_structure::ds::stack::Stack;
+            /// let mut stack = Stack::from_vec(vec![2, 3, 4, 5]);
+            /// stack.push(1);
+            /// assert_eq!(stack, Stack::from_vec(vec![1, 2, 3, 4, 5]));
+            /// ```
+            pub fn push(&mut self, val: T) {
+                self.storage.push(val);
+            }
+
+            /// Returns the top element of the stack, or `None` if it is empty
+            ///
+            /// # Example
+            /// ```
+            /// # use crate::data_structure::ds::stack::Stack;
+            /// let mut stack = Stack::from_vec(vec![1, 2]);
+            /// assert_eq!(stack.peek(), Some(&1));
+            /// stack.clear();
+            /// assert_eq!(stack.peek(), None);
+            /// ```
+            pub fn peek(&self) -> Option<&T> {
+                self.storage.peek()
+            }
+
+            /// Returns the top element of the stack mutably, or `None` if it is empty
+            ///
+            /// # Example
+            /// ```
+            /// # use crate::data_structure::ds::stack::Stack;
+            /// let mut stack = Stack::from_vec(vec![1, 2]);
+            /// if let Some(top) = stack.peek_mut() {
+            ///     *top = 5;
+            /// }
+            /// assert_eq!(stack.pop(), Some(5));
+            /// ```
+            pub fn peek_mut(&mut self) -> Option<&mut T> {
+                self.storage.peek_mut()
+            }
+
+            /// Removes every element from the stack
+            ///
+            /// # Example
+            /// ```
+            /// # use crate::data_structure::ds::stack::Stack;
+            /// let mut stack = Stack::from_vec(vec![1, 2, 3]);
+            /// stack.clear();
+            /// assert!(stack.is_empty());
+            /// ```
+            pub fn clear(&mut self) {
+                self.storage.clear();
+            }
+
+            /// Returns an iterator over the stack from the top down
+            ///
+            /// # Example
+            /// ```
+            /// # use crate::data_structure::ds::stack::Stack;
+            /// let mut stack = Stack::new();
+            /// stack.extend([1, 2, 3]);
+            /// assert_eq!(stack.iter().collect::<Vec<_>>(), vec![&3, &2, &1]);
+            /// ```
+            pub fn iter(&self) -> S::Iter<'_> {
+                self.storage.iter()
+            }
+        }
+
+        impl<T, S: StackStorage<T> + Default> Default for Stack<T, S> {
+            fn default() -> Self {
+                Stack::with_storage(S::default())
+            }
+        }
+
+        impl<T, S: StackStorage<T> + Clone> Clone for Stack<T, S> {
+            fn clone(&self) -> Self {
+                Stack::with_storage(self.storage.clone())
+            }
+        }
+
+        /// Lists the elements from the top down
+        impl<T: Debug, S: StackStorage<T>> Debug for Stack<T, S> {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.debug_list().entries(self.iter()).finish()
+            }
+        }
+
+        /// Stacks are equal if they hold the same elements in the same order,
+        /// whatever they are stored in
+        impl<T: PartialEq, S: StackStorage<T>, R: StackStorage<T>> PartialEq<Stack<T, R>> for Stack<T, S> {
+            fn eq(&self, other: &Stack<T, R>) -> bool {
+                self.len() == other.len() && self.iter().eq(other.iter())
+            }
+        }
+
+        impl<'a, T, S: StackStorage<T>> IntoIterator for &'a Stack<T, S> {
+            type Item = &'a T;
+            type IntoIter = S::Iter<'a>;
+
+            fn into_iter(self) -> Self::IntoIter {
+                self.iter()
+            }
+        }
+
+        /// Pushes every element of the iterator in order onto an empty stack,
+        /// so the last one ends up on top, as with [Extend]
+        ///
+        /// # Example
+        /// ```
+        /// # use crate::data_structure::ds::stack::Stack;
+        /// let stack: Stack<i32> = (1..=3).collect();
+        /// assert_eq!(stack.peek(), Some(&3));
+        /// assert_eq!(stack.len(), 3);
+        /// ```
+        impl<T, S: StackStorage<T> + Default> FromIterator<T> for Stack<T, S> {
+            fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+                let mut stack = Stack::default();
+                stack.extend(iter);
+                stack
+            }
+        }
+
+        /// Pushes every element of the iterator in order, so the last one ends up on top
+        ///
+        /// # Example
+        /// ```
+        /// # use crate::data_structure::ds::stack::Stack;
+        /// let mut stack = Stack::from_vec(vec![1]);
+        /// stack.extend([2, 3]);
+        /// assert_eq!(stack.peek(), Some(&3));
+        /// assert_eq!(stack.len(), 3);
+        /// ```
+        impl<T, S: StackStorage<T>> Extend<T> for Stack<T, S> {
+            fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+                for val in iter {
+                    self.push(val);
+                }
+            }
+        }
+
+        impl<'a, T: Copy + 'a, S: StackStorage<T>> Extend<&'a T> for Stack<T, S> {
+            fn extend<I: IntoIterator<Item = &'a T>>(&mut self, iter: I) {
+                self.extend(iter.into_iter().copied());
+            }
+        }
+
+        /// The first element of the array ends up on top, as with
+        /// [from_vec](struct.Stack.html#method.from_vec)
+        impl<T, const N: usize> From<[T; N]> for Stack<T> {
+            fn from(mut arr: [T; N]) -> Self {
+                arr.reverse();
+                arr.into_iter().collect()
+            }
+        }
+
+        /// What a [BoundedStack] does with a push once it is full
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+        pub enum OverflowPolicy {
+            /// Drops the pushed element and returns `Err(Error::CapacityExceeded)`
+            #[default]
+            Reject,
+            /// Drops the element at the bottom of the stack to make room
+            DropOldest,
+        }
+
+        /// Stack holding at most `N` elements inline, which never allocates
+        ///
+        /// The elements sit in a ring buffer, so evicting the oldest one under
+        /// `OverflowPolicy::DropOldest` is `O(1)`.
+        ///
+        /// # Example
+        /// ```
+        /// # use crate::data_structure::ds::{Error, stack::{BoundedStack, OverflowPolicy}};
+        /// let mut stack: BoundedStack<i32, 2> = BoundedStack::new();
+        /// assert_eq!(stack.push(1), Ok(()));
+        /// assert_eq!(stack.push(2), Ok(()));
+        /// assert_eq!(stack.push(3), Err(Error::CapacityExceeded));
+        ///
+        /// let mut history: BoundedStack<i32, 2> = BoundedStack::with_policy(OverflowPolicy::DropOldest);
+        /// history.extend([1, 2, 3]);
+        /// assert_eq!(history.iter().collect::<Vec<_>>(), vec![&3, &2]);
+        /// ```
+        pub struct BoundedStack<T, const N: usize> {
+            buf: [MaybeUninit<T>; N],
+            // Index of the bottom element; `len` elements from it onwards are initialised
+            head: usize,
+            len: usize,
+            policy: OverflowPolicy,
+        }
+
+        impl<T, const N: usize> BoundedStack<T, N> {
+            /// Constructs a new, empty `BoundedStack<T, N>` rejecting pushes once full
+            pub const fn new() -> Self {
+                BoundedStack::with_policy(OverflowPolicy::Reject)
+            }
+
+            /// Constructs a new, empty `BoundedStack<T, N>` handling overflow with `policy`
+            pub const fn with_policy(policy: OverflowPolicy) -> Self {
+                BoundedStack {
+                    buf: [const { MaybeUninit::uninit() }; N],
+                    head: 0,
+                    len: 0,
+                    policy,
+                }
+            }
+
+            /// Returns the policy applied to pushes on a full stack
+            pub fn policy(&self) -> OverflowPolicy {
+                self.policy
+            }
+
+            /// Returns the number of elements on the stack
+            pub fn len(&self) -> usize {
+                self.len
+            }
+
+            /// Returns `true` if the stack holds no elements
+            pub fn is_empty(&self) -> bool {
+                self.len == 0
+            }
+
+            /// Returns `true` if the next push overflows
+            pub fn is_full(&self) -> bool {
+                self.len == N
+            }
+
+            /// Returns the maximum number of elements, `N`
+            pub fn capacity(&self) -> usize {
+                N
+            }
+
+            /// Maps a position counted from the bottom to its slot in `buf`
+            fn slot(&self, pos: usize) -> usize {
+                let slot = self.head + pos;
+                if slot >= N { slot - N } else { slot }
+            }
+
+            /// Pushes an element on to the top of the stack, applying the overflow
+            /// policy if it is full
+            ///
+            /// # Example
+            /// ```
+            /// # use crate::data_structure::ds::{Error, stack::{BoundedStack, OverflowPolicy}};
+            /// let mut stack: BoundedStack<i32, 1> = BoundedStack::new();
+            /// assert_eq!(stack.push(1), Ok(()));
+            /// assert_eq!(stack.push(2), Err(Error::CapacityExceeded));
+            /// assert_eq!(stack.peek(), Some(&1));
+            /// ```
+            pub fn push(&mut self, val: T) -> Result<(), Error> {
+                if self.len == N {
+                    match self.policy {
+                        OverflowPolicy::Reject => return Err(Error::CapacityExceeded),
+                        // Nothing to evict, so the new element is the oldest one
+                        OverflowPolicy::DropOldest if N == 0 => return Ok(()),
+                        OverflowPolicy::DropOldest => {
+                            let oldest = self.head;
+                            self.head = self.slot(1);
+                            self.len -= 1;
+                            // SAFETY: `buf[oldest]` was the initialised bottom element
+                            // and is no longer counted by `len`
+                            unsafe { self.buf[oldest].assume_init_drop() };
+                        }
+                    }
+                }
+                let top = self.slot(self.len);
+                self.buf[top].write(val);
+                self.len += 1;
+                Ok(())
+            }
+
+            /// Pops the top element of the stack off, returning `None` if it is empty
+            pub fn pop(&mut self) -> Option<T> {
+                if self.len == 0 {
+                    return None;
+                }
+                self.len -= 1;
+                let top = self.slot(self.len);
+                // SAFETY: `buf[top]` was initialised and is no longer counted by `len`
+                Some(unsafe { self.buf[top].assume_init_read() })
+            }
+
+            /// Returns the top element of the stack, or `None` if it is empty
+            pub fn peek(&self) -> Option<&T> {
+                let (bottom, top) = self.as_slices();
+                top.last().or(bottom.last())
+            }
+
+            /// Returns the top element of the stack mutably, or `None` if it is empty
+            pub fn peek_mut(&mut self) -> Option<&mut T> {
+                if self.len == 0 {
+                    return None;
+                }
+                let top = self.slot(self.len - 1);
+                // SAFETY: `buf[top]` is the initialised top element
+                Some(unsafe { self.buf[top].assume_init_mut() })
+            }
+
+            /// Removes every element from the stack
+            pub fn clear(&mut self) {
+                while self.pop().is_some() {}
+                self.head = 0;
+            }
+
+            /// Returns the elements from the bottom up as two slices, the second of
+            /// which is only non-empty once the ring buffer has wrapped around
+            pub fn as_slices(&self) -> (&[T], &[T]) {
+                let first_len = self.len.min(N - self.head);
+                let base = self.buf.as_ptr() as *const T;
+                // SAFETY: `buf[head..head + first_len]` and `buf[..len - first_len]`
+                // are the initialised elements, in order
+                unsafe {
+                    (
+                        slice::from_raw_parts(base.add(self.head), first_len),
+                        slice::from_raw_parts(base, self.len - first_len),
+                    )
+                }
+            }
+
+            /// Returns an iterator over the stack from the top down
+            pub fn iter(&self) -> Chain<Rev<slice::Iter<'_, T>>, Rev<slice::Iter<'_, T>>> {
+                let (bottom, top) = self.as_slices();
+                top.iter().rev().chain(bottom.iter().rev())
+            }
+        }
+
+        impl<T, const N: usize> Drop for BoundedStack<T, N> {
+            fn drop(&mut self) {
+                self.clear();
+            }
+        }
+
+        impl<T, const N: usize> Default for BoundedStack<T, N> {
+            fn default() -> Self {
+                Self::new()
+            }
+        }
+
+        impl<T: Clone, const N: usize> Clone for BoundedStack<T, N> {
+            fn clone(&self) -> Self {
+                let mut stack = BoundedStack::with_policy(self.policy);
+                let (bottom, top) = self.as_slices();
+                stack.extend(bottom.iter().chain(top).cloned());
+                stack
+            }
+        }
+
+        /// Lists the elements from the top down
+        impl<T: Debug, const N: usize> Debug for BoundedStack<T, N> {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.debug_list().entries(self.iter()).finish()
+            }
+        }
+
+        impl<T: PartialEq, const N: usize, const M: usize> PartialEq<BoundedStack<T, M>> for BoundedStack<T, N> {
+            fn eq(&self, other: &BoundedStack<T, M>) -> bool {
+                self.len == other.len && self.iter().eq(other.iter())
+            }
+        }
+
+        /// Pushes every element of the iterator in order, so the last one ends up on top
+        ///
+        /// Elements that overflow a stack with `OverflowPolicy::Reject` are dropped.
+        impl<T, const N: usize> Extend<T> for BoundedStack<T, N> {
+            fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+                for val in iter {
+                    let _ = self.push(val);
+                }
+            }
+        }
+
+        /// Stack of `Ord` elements which keeps track of its minimum and maximum,
+        /// answering both in `O(1)`
+        ///
+        /// Every entry remembers where the minimum and maximum were when it was
+        /// pushed, so popping restores them without a scan and elements never
+        /// need to be cloned.
+        ///
+        /// # Example
+        /// ```
+        /// # use crate::data_structure::ds::stack::MinMaxStack;
+        /// let mut stack = MinMaxStack::new();
+        /// stack.extend([3, 1, 4, 1, 5]);
+        /// assert_eq!((stack.min(), stack.max()), (Some(&1), Some(&5)));
+        /// stack.pop();
+        /// stack.pop();
+        /// stack.pop();
+        /// assert_eq!((stack.min(), stack.max()), (Some(&1), Some(&3)));
+        /// ```
+        #[derive(Clone)]
+        pub struct MinMaxStack<T: Ord> {
+            entries: Vector<MinMaxEntry<T>>,
+        }
+
+        #[derive(Clone)]
+        struct MinMaxEntry<T> {
+            val: T,
+            // Positions of the minimum and maximum among the entries up to this one
+            min: usize,
+            max: usize,
+        }
+
+        impl<T: Ord> MinMaxStack<T> {
+            /// Constructs a new, empty `MinMaxStack<T>`
+            pub fn new() -> Self {
+                MinMaxStack {
+                    entries: Vector::new(),
+                }
+            }
+
+            /// Returns the number of elements on the stack
+            pub fn len(&self) -> usize {
+                self.entries.len()
+            }
+
+            /// Returns `true` if the stack holds no elements
+            pub fn is_empty(&self) -> bool {
+                self.entries.is_empty()
+            }
+
+            /// Pushes an element on to the top of the stack
+            pub fn push(&mut self, val: T) {
+                let pos = self.entries.len();
+                let (min, max) = match self.entries.last() {
+                    Some(top) => (
+                        if val < self.entries[top.min].val { pos } else { top.min },
+                        if val > self.entries[top.max].val { pos } else { top.max },
+                    ),
+                    None => (pos, pos),
+                };
+                self.entries.push(MinMaxEntry { val, min, max });
+            }
+
+            /// Pops the top element of the stack off, returning `None` if it is empty
+            pub fn pop(&mut self) -> Option<T> {
+                self.entries.pop().map(|entry| entry.val)
+            }
+
+            /// Returns the top element of the stack, or `None` if it is empty
+            pub fn peek(&self) -> Option<&T> {
+                self.entries.last().map(|entry| &entry.val)
+            }
+
+            /// Returns the smallest element on the stack, the deepest one if there
+            /// are several, or `None` if it is empty
+            pub fn min(&self) -> Option<&T> {
+                self.entries.last().map(|top| &self.entries[top.min].val)
+            }
+
+            /// Returns the largest element on the stack, the deepest one if there
+            /// are several, or `None` if it is empty
+            pub fn max(&self) -> Option<&T> {
+                self.entries.last().map(|top| &self.entries[top.max].val)
+            }
+
+            /// Removes every element from the stack
+            pub fn clear(&mut self) {
+                self.entries.clear();
+            }
+
+            /// Returns an iterator over the stack from the top down
+            pub fn iter(&self) -> impl DoubleEndedIterator<Item = &T> + ExactSizeIterator {
+                self.entries.as_slice().iter().rev().map(|entry| &entry.val)
+            }
+        }
+
+        impl<T: Ord> Default for MinMaxStack<T> {
+            fn default() -> Self {
+                Self::new()
+            }
+        }
+
+        /// Lists the elements from the top down
+        impl<T: Ord + Debug> Debug for MinMaxStack<T> {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.debug_list().entries(self.iter()).finish()
+            }
+        }
+
+        /// Pushes every element of the iterator in order, so the last one ends up on top
+        impl<T: Ord> Extend<T> for MinMaxStack<T> {
+            fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+                for val in iter {
+                    self.push(val);
+                }
+            }
+        }
+
+        /// Finds, for every element of `vals`, the index of the nearest element after
+        /// it that `supersedes` it, or `None` if there is none
+        ///
+        /// `supersedes(later, earlier)` must behave like a strict order such as `>`.
+        /// This runs in `O(n)` with a monotonic stack of the indices still waiting
+        /// for an answer.
+        ///
+        /// # Example
+        /// ```
+        /// # use crate::data_structure::ds::stack::next_by;
+        /// let words = ["a", "abc", "ab", "abcd"];
+        /// let longer = next_by(&words, |later, earlier| later.len() > earlier.len());
+        /// assert_eq!(longer, vec![Some(1), Some(3), Some(3), None]);
+        /// ```
+        pub fn next_by<T, F>(vals: &[T], mut supersedes: F) -> Vec<Option<usize>>
+        where
+            F: FnMut(&T, &T) -> bool,
+        {
+            let mut next = vec![None; vals.len()];
+            let mut pending: Stack<usize> = Stack::new();
+            for (i, val) in vals.iter().enumerate() {
+                while let Some(&waiting) = pending.peek() {
+                    if !supersedes(val, &vals[waiting]) {
+                        break;
+                    }
+                    pending.pop();
+                    next[waiting] = Some(i);
+                }
+                pending.push(i);
+            }
+            next
+        }
+
+        /// Finds, for every element of `vals`, the index of the nearest strictly
+        /// greater element after it
+        ///
+        /// # Example
+        /// ```
+        /// # use crate::data_structure::ds::stack::next_greater;
+        /// assert_eq!(next_greater(&[2, 1, 2, 4, 3]), vec![Some(3), Some(2), Some(3), None, None]);
+        /// ```
+        pub fn next_greater<T: Ord>(vals: &[T]) -> Vec<Option<usize>> {
+            next_by(vals, |later, earlier| later > earlier)
+        }
+
+        /// Finds, for every element of `vals`, the index of the nearest strictly
+        /// smaller element after it
+        ///
+        /// # Example
+        /// ```
+        /// # use crate::data_structure::ds::stack::next_smaller;
+        /// assert_eq!(next_smaller(&[2, 1, 2, 4, 3]), vec![Some(1), None, None, Some(4), None]);
+        /// ```
+        pub fn next_smaller<T: Ord>(vals: &[T]) -> Vec<Option<usize>> {
+            next_by(vals, |later, earlier| later < earlier)
+        }
+
+        /// Number of retired nodes after which a pop tries to free them
+        #[cfg(not(loom))]
+        const RECLAIM_THRESHOLD: usize = 64;
+        /// Low enough for the few pops of a loom model to free nodes
+        #[cfg(loom)]
+        const RECLAIM_THRESHOLD: usize = 2;
+
+        /// A node of a [ConcurrentStack]
+        struct SharedNode<T> {
+            val: ManuallyDrop<T>,
+            /// Never changes once the node is pushed, so it can be read while
+            /// other threads pop the node
+            next: *mut SharedNode<T>,
+            /// Links the node into the retired list once popped
+            retired_next: *mut SharedNode<T>,
+        }
+
+        /// A hazard pointer: a node `ptr` points at isn't freed until it stops
+        /// pointing there
+        struct HazardSlot<T> {
+            ptr: AtomicPtr<SharedNode<T>>,
+            /// Whether a push or pop currently owns the slot
+            active: AtomicBool,
+            next: *mut HazardSlot<T>,
+        }
+
+        impl<T> HazardSlot<T> {
+            /// Clears the hazard pointer and gives the slot up
+            fn release(&self) {
+                self.ptr.store(ptr::null_mut(), Ordering::SeqCst);
+                self.active.store(false, Ordering::SeqCst);
+            }
+        }
+
+        /// A lock-free stack which can be shared between threads
+        ///
+        /// This is a Treiber stack: `push` and `pop` swap the head node with an
+        /// atomic compare-and-swap, retrying if another thread got there first.
+        /// Popped nodes may still be read by threads racing for them, so they are
+        /// freed through hazard pointers: a pushing or popping thread publishes the
+        /// head it is about to use, and retired nodes are only freed once no hazard
+        /// pointer points at them. This also rules out the ABA problem, as a node can't be
+        /// freed and its address reused while a thread is comparing against it.
+        ///
+        /// The protocol relies on publishing a hazard pointer, unlinking a node and
+        /// scanning the hazard pointers being totally ordered, so the atomics are
+        /// sequentially consistent throughout.
+        ///
+        /// # Example
+        /// ```
+        /// # use crate::data_structure::ds::stack::ConcurrentStack;
+        /// # use std::thread;
+        /// let stack = ConcurrentStack::new();
+        /// thread::scope(|scope| {
+        ///     for t in 0..4 {
+        ///         let stack = &stack;
+        ///         scope.spawn(move || (0..100).for_each(|i| stack.push(t * 100 + i)));
+        ///     }
+        /// });
+        ///
+        /// let mut popped = Vec::new();
+        /// while let Some(val) = stack.pop() {
+        ///     popped.push(val);
+        /// }
+        /// popped.sort();
+        /// assert_eq!(popped, (0..400).collect::<Vec<_>>());
+        /// ```
+        pub struct ConcurrentStack<T> {
+            head: AtomicPtr<SharedNode<T>>,
+            /// Hazard slots, only ever added to until the stack is dropped
+            slots: AtomicPtr<HazardSlot<T>>,
+            /// Popped nodes waiting to be freed
+            retired: AtomicPtr<SharedNode<T>>,
+            retired_len: AtomicUsize,
+            marker: PhantomData<T>,
+        }
+
+        // SAFETY: values are only ever moved in and out, never shared, so sending
+        // them between threads is enough
+        unsafe impl<T: Send> Send for ConcurrentStack<T> {}
+        unsafe impl<T: Send> Sync for ConcurrentStack<T> {}
+
+        impl<T> ConcurrentStack<T> {
+            /// Constructs an empty `ConcurrentStack`
+            #[cfg(not(loom))]
+            pub const fn new() -> Self {
+                ConcurrentStack {
+                    head: AtomicPtr::new(ptr::null_mut()),
+                    slots: AtomicPtr::new(ptr::null_mut()),
+                    retired: AtomicPtr::new(ptr::null_mut()),
+                    retired_len: AtomicUsize::new(0),
+                    marker: PhantomData,
+                }
+            }
+
+            /// Constructs an empty `ConcurrentStack`, which can't be done in a
+            /// `const fn` with the atomics of loom
+            #[cfg(loom)]
+            pub fn new() -> Self {
+                ConcurrentStack {
+                    head: AtomicPtr::new(ptr::null_mut()),
+                    slots: AtomicPtr::new(ptr::null_mut()),
+                    retired: AtomicPtr::new(ptr::null_mut()),
+                    retired_len: AtomicUsize::new(0),
+                    marker: PhantomData,
+                }
+            }
+
+            /// Returns `true` if the stack was empty at the time of the call
+            pub fn is_empty(&self) -> bool {
+                self.head.load(Ordering::SeqCst).is_null()
+            }
+
+            /// Pushes `val` on top of the stack
+            pub fn push(&self, val: T) {
+                let node = Box::into_raw(Box::new(SharedNode {
+                    val: ManuallyDrop::new(val),
+                    next: ptr::null_mut(),
+                    retired_next: ptr::null_mut(),
+                }));
+                // The head is protected even though it isn't read, as comparing
+                // against a node which was freed and whose address was reused would
+                // link the new node to a dangling pointer
+                let slot = self.acquire_slot();
+                loop {
+                    let head = self.protect_head(slot);
+                    // SAFETY: the node isn't shared until the exchange succeeds
+                    unsafe { (*node).next = head };
+                    if self
+                        .head
+                        .compare_exchange_weak(head, node, Ordering::SeqCst, Ordering::SeqCst)
+                        .is_ok()
+                    {
+                        break;
+                    }
+                }
+                slot.release();
+            }
+
+            /// Pops the top of the stack, returning `None` if it is empty
+            pub fn pop(&self) -> Option<T> {
+                let slot = self.acquire_slot();
+                let popped = loop {
+                    let head = self.protect_head(slot);
+                    if head.is_null() {
+                        break None;
+                    }
+                    // SAFETY: the hazard pointer keeps the node alive, and its
+                    // `next` never changes once pushed
+                    let next = unsafe { (*head).next };
+                    if self
+                        .head
+                        .compare_exchange(head, next, Ordering::SeqCst, Ordering::SeqCst)
+                        .is_ok()
+                    {
+                        break Some(head);
+                    }
+                };
+                slot.release();
+
+                popped.map(|node| {
+                    // SAFETY: winning the exchange unlinked the node, so no other
+                    // thread reads its value, which is never read again after this
+                    let val = unsafe { ManuallyDrop::into_inner(ptr::read(ptr::addr_of!((*node).val))) };
+                    self.retire(node);
+                    val
+                })
+            }
+
+            /// Loads the head and publishes it in `slot`, after which it isn't freed
+            /// until the slot is cleared or reused
+            fn protect_head(&self, slot: &HazardSlot<T>) -> *mut SharedNode<T> {
+                let mut head = self.head.load(Ordering::SeqCst);
+                loop {
+                    // Either `reclaim` sees the hazard pointer, or this sees the
+                    // node leave the head. A node is only retired after leaving the
+                    // head, so if it is still the head it is protected from here on.
+                    slot.ptr.store(head, Ordering::SeqCst);
+                    let current = self.head.load(Ordering::SeqCst);
+                    if current == head {
+                        // The pointer loaded last is the one to use, as the first
+                        // may point at a freed node whose address was reused
+                        return current;
+                    }
+                    head = current;
+                }
+            }
+
+            /// Finds an unused hazard slot, adding one if every slot is in use
+            fn acquire_slot(&self) -> &HazardSlot<T> {
+                let mut slot = self.slots.load(Ordering::SeqCst);
+                while !slot.is_null() {
+                    // SAFETY: slots are only freed when the stack is dropped
+                    let current = unsafe { &*slot };
+                    if current
+                        .active
+                        .compare_exchange(false, true, Ordering::SeqCst, Ordering::SeqCst)
+                        .is_ok()
+                    {
+                        return current;
+                    }
+                    slot = current.next;
+                }
+
+                let slot = Box::into_raw(Box::new(HazardSlot {
+                    ptr: AtomicPtr::new(ptr::null_mut()),
+                    active: AtomicBool::new(true),
+                    next: ptr::null_mut(),
+                }));
+                let mut head = self.slots.load(Ordering::SeqCst);
+                loop {
+                    // SAFETY: the slot isn't shared until the exchange succeeds
+                    unsafe { (*slot).next = head };
+                    match self.slots.compare_exchange_weak(head, slot, Ordering::SeqCst, Ordering::SeqCst) {
+                        // SAFETY: slots are only freed when the stack is dropped
+                        Ok(_) => return unsafe { &*slot },
+                        Err(current) => head = current,
+                    }
+                }
+            }
+
+            /// Adds a popped `node` to the retired list, freeing the retired nodes
+            /// nothing points at once there are enough of them
+            fn retire(&self, node: *mut SharedNode<T>) {
+                self.push_retired(node);
+                if self.retired_len.fetch_add(1, Ordering::Relaxed) + 1 >= RECLAIM_THRESHOLD {
+                    self.reclaim();
+                }
+            }
+
+            fn push_retired(&self, node: *mut SharedNode<T>) {
+                let mut head = self.retired.load(Ordering::SeqCst);
+                loop {
+                    // SAFETY: only the thread holding a retired list reads
+                    // `retired_next`, and it gets the list after this exchange
+                    unsafe { (*node).retired_next = head };
+                    match self.retired.compare_exchange_weak(head, node, Ordering::SeqCst, Ordering::SeqCst) {
+                        Ok(_) => return,
+                        Err(current) => head = current,
+                    }
+                }
+            }
+
+            /// Frees every retired node no hazard pointer points at
+            fn reclaim(&self) {
+                let mut node = self.retired.swap(ptr::null_mut(), Ordering::SeqCst);
+                let mut hazards = Vector::new();
+                let mut slot = self.slots.load(Ordering::SeqCst);
+                while !slot.is_null() {
+                    // SAFETY: slots are only freed when the stack is dropped
+                    let current = unsafe { &*slot };
+                    let hazard = current.ptr.load(Ordering::SeqCst);
+                    if !hazard.is_null() {
+                        hazards.push(hazard);
+                    }
+                    slot = current.next;
+                }
+
+                let mut taken = 0;
+                while !node.is_null() {
+                    // SAFETY: swapping the list out made this thread its only reader
+                    let next = unsafe { (*node).retired_next };
+                    if hazards.contains(&node) {
+                        self.push_retired(node);
+                    } else {
+                        // SAFETY: the node was unlinked before being retired, and no
+                        // hazard pointer protected it after that, so no thread can
+                        // reach it anymore. Its value was already moved out.
+                        drop(unsafe { Box::from_raw(node) });
+                        taken += 1;
+                    }
+                    node = next;
+                }
+                self.retired_len.fetch_sub(taken, Ordering::Relaxed);
+            }
+        }
+
+        impl<T> Drop for ConcurrentStack<T> {
+            fn drop(&mut self) {
+                // Loaded rather than read through `get_mut`, which loom's atomics lack
+                let mut node = self.head.load(Ordering::Relaxed);
+                while !node.is_null() {
+                    // SAFETY: `&mut self` means no other thread uses the stack
+                    let mut boxed = unsafe { Box::from_raw(node) };
+                    // SAFETY: the value of a node still on the stack was never taken
+                    unsafe { ManuallyDrop::drop(&mut boxed.val) };
+                    node = boxed.next;
+                }
+                let mut node = self.retired.load(Ordering::Relaxed);
+                while !node.is_null() {
+                    // SAFETY: as above, the values of retired nodes were moved out
+                    let boxed = unsafe { Box::from_raw(node) };
+                    node = boxed.retired_next;
+                }
+                let mut slot = self.slots.load(Ordering::Relaxed);
+                while !slot.is_null() {
+                    // SAFETY: as above
+                    let boxed = unsafe { Box::from_raw(slot) };
+                    slot = boxed.next;
+                }
+            }
+        }
+
+        impl<T> Default for ConcurrentStack<T> {
+            fn default() -> Self {
+                Self::new()
+            }
+        }
+
+        impl<T> Debug for ConcurrentStack<T> {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.debug_struct("ConcurrentStack").finish_non_exhaustive()
+            }
+        }
+    }
+
+    /// This module provides a first-in, first-out queue named `Queue`, built on
+    /// the nodes of [linked_list](super::linked_list)
+    ///
+    /// Functions Implemented:
+    /// * [new](struct.Queue.html#method.new) -> `Self`
+    /// * [from_vec](struct.Queue.html#method.from_vec) -> `Self`
+    /// * [len](struct.Queue.html#method.len) -> `usize`
+    /// * [is_empty](struct.Queue.html#method.is_empty) -> `bool`
+    /// * [print](struct.Queue.html#method.print) -> `()`
+    /// * [enqueue](struct.Queue.html#method.enqueue) -> `()`
+    /// * [dequeue](struct.Queue.html#method.dequeue) -> `Option<T>`
+    /// * [try_dequeue](struct.Queue.html#method.try_dequeue) -> `Result<T, Error>`
+    /// * [push](struct.Queue.html#method.push) -> `()`
+    /// * [pop](struct.Queue.html#method.pop) -> `Option<T>`
+    /// * [try_pop](struct.Queue.html#method.try_pop) -> `Result<T, Error>`
+    /// * [peek](struct.Queue.html#method.peek) -> `Option<&T>`
+    /// * [peek_mut](struct.Queue.html#method.peek_mut) -> `Option<&mut T>`
+    /// * [peek_back](struct.Queue.html#method.peek_back) -> `Option<&T>`
+    /// * [peek_back_mut](struct.Queue.html#method.peek_back_mut) -> `Option<&mut T>`
+    /// * [clear](struct.Queue.html#method.clear) -> `()`
+    /// * [iter](struct.Queue.html#method.iter) -> `Iter<'_, T>`
+    /// * [iter_mut](struct.Queue.html#method.iter_mut) -> `IterMut<'_, T>`
+    ///
+    /// `push`, `pop`, `try_pop`, `peek` and `peek_mut` mirror
+    /// [Stack](super::stack::Stack), so the two can be swapped to turn a
+    /// depth-first search into a breadth-first one.
+    pub mod queue {
+        use std::fmt::{self, Debug, Display};
+        use std::marker::PhantomData;
+        use std::ptr::NonNull;
+        use crate::ds::Error;
+        use crate::ds::allocator::Box;
+        use crate::ds::linked_list::{IntoIter, Iter, IterMut, ListNode};
+
+        /// First-in, first-out queue of `ListNode<T>`s
+        ///
+        /// Elements are dequeued from the front of the chain and enqueued after
+        /// its last node, which the queue keeps a pointer to so both are `O(1)`.
+        ///
+        /// # Example
+        /// ```
+        /// # use crate::data_structure::ds::queue::Queue;
+        /// let mut queue = Queue::new();
+        /// queue.enqueue(1);
+        /// queue.enqueue(2);
+        /// assert_eq!(queue.dequeue(), Some(1));
+        /// queue.enqueue(3);
+        /// assert_eq!(queue.iter().collect::<Vec<_>>(), vec![&2, &3]);
+        /// ```
+        pub struct Queue<T> {
+            /// First node, owning the rest of the chain
+            head: Option<NonNull<ListNode<T>>>,
+            /// Last node, pointing into the chain with the tag of the box owning it,
+            /// so that borrows of the chain don't invalidate it
+            tail: Option<NonNull<ListNode<T>>>,
+            len: usize,
+            marker: PhantomData<Box<ListNode<T>>>,
+        }
+
+        // SAFETY: the queue owns its nodes like a `Box<ListNode<T>>` does
+        unsafe impl<T: Send> Send for Queue<T> {}
+        unsafe impl<T: Sync> Sync for Queue<T> {}
+
+        impl<T> Queue<T> {
+            /// Constructs a new, empty `Queue<T>`
+            ///
+            /// # Example
+            /// ```
+            /// # use crate::data_structure::ds::queue::Queue;
+            /// let queue: Queue<i32> = Queue::new();
+            /// assert!(queue.is_empty());
+            /// assert_eq!(queue.peek(), None);
+            /// ```
+            pub const fn new() -> Self {
+                Queue {
+                    head: None,
+                    tail: None,
+                    len: 0,
+                    marker: PhantomData,
+                }
+            }
+
+            /// Constructs a new instance of `Queue<T>` with the provided
+            /// `Vec<T>`, whose first element ends up at the front
+            ///
+            /// # Example
+            /// ```
+            /// # use crate::data_structure::ds::queue::Queue;
+            /// let mut queue = Queue::from_vec(vec![1, 2, 3]);
+            /// assert_eq!(queue.dequeue(), Some(1));
+            /// assert_eq!(queue.len(), 2);
+            /// ```
+            pub fn from_vec(vec: Vec<T>) -> Self {
+                vec.into_iter().collect()
+            }
+
+            /// Returns the number of elements in the queue
+            pub fn len(&self) -> usize {
+                self.len
+            }
+
+            /// Returns `true` if the queue holds no elements
+            pub fn is_empty(&self) -> bool {
+                self.len == 0
+            }
+
+            /// Prints the given Queue, as formatted by its `Display` implementation
+            pub fn print(&self)
+            where
+                T: Display,
+            {
+                println!("{}", self);
+            }
+
+            /// Adds an element to the back of the queue
+            ///
+            /// # Example
+            /// ```
+            /// # use crate::data_structure::ds::queue::Queue;
+            /// let mut queue = Queue::from_vec(vec![1, 2]);
+            /// queue.enqueue(3);
+            /// assert_eq!(queue, Queue::from_vec(vec![1, 2, 3]));
+            /// ```
+            pub fn enqueue(&mut self, val: T) {
+                let node = match self.tail {
+                    None => {
+                        let node = NonNull::from(Box::leak(ListNode::new(val)));
+                        self.head = Some(node);
+                        node
+                    }
+                    Some(tail) => {
+                        // SAFETY: `tail` points at the last node of the chain,
+                        // which the queue owns
+                        let next = unsafe { (*tail.as_ptr()).next.insert(ListNode::new(val)) };
+                        // SAFETY: `next` is a valid box. Its pointer is taken without
+                        // reborrowing so that it keeps the tag of the box.
+                        unsafe { NonNull::new_unchecked(Box::as_mut_ptr(next)) }
+                    }
+                };
+                self.tail = Some(node);
+                self.len += 1;
+            }
+
+            /// Removes the element at the front of the queue, returning `None` if it is empty
+            ///
+            /// # Example
+            /// ```
+            /// # use crate::data_structure::ds::queue::Queue;
+            /// let mut queue = Queue::from_vec(vec![1, 2]);
+            /// assert_eq!(queue.dequeue(), Some(1));
+            /// assert_eq!(queue.dequeue(), Some(2));
+            /// assert_eq!(queue.dequeue(), None);
+            /// ```
+            pub fn dequeue(&mut self) -> Option<T> {
+                let head = self.head?;
+                // SAFETY: `head` came out of a box the queue owns
+                let (val, next) = ListNode::into_parts(unsafe { Box::from_raw(head.as_ptr()) });
+                self.head = next.map(|next| NonNull::from(Box::leak(next)));
+                self.len -= 1;
+                // The box of the last node was just moved if it was the second,
+                // so its pointer is taken again
+                if self.len <= 1 {
+                    self.tail = self.head;
+                }
+                Some(val)
+            }
+
+            /// Removes the element at the front of the queue, returning
+            /// `Err(Error::Empty)` if there is none
+            ///
+            /// # Example
+            /// ```
+            /// # use crate::data_structure::ds::{Error, queue::Queue};
+            /// let mut queue = Queue::from_vec(vec![1]);
+            /// assert_eq!(queue.try_dequeue(), Ok(1));
+            /// assert_eq!(queue.try_dequeue(), Err(Error::Empty));
+            /// ```
+            pub fn try_dequeue(&mut self) -> Result<T, Error> {
+                self.dequeue().ok_or(Error::Empty)
+            }
+
+            /// Same as [enqueue](struct.Queue.html#method.enqueue), mirroring `Stack::push`
+            pub fn push(&mut self, val: T) {
+                self.enqueue(val);
+            }
+
+            /// Same as [dequeue](struct.Queue.html#method.dequeue), mirroring `Stack::pop`
+            pub fn pop(&mut self) -> Option<T> {
+                self.dequeue()
+            }
+
+            /// Same as [try_dequeue](struct.Queue.html#method.try_dequeue), mirroring `Stack::try_pop`
+            pub fn try_pop(&mut self) -> Result<T, Error> {
+                self.try_dequeue()
+            }
+
+            /// Returns the element at the front of the queue, or `None` if it is empty
+            ///
+            /// # Example
+            /// ```
+            /// # use crate::data_structure::ds::queue::Queue;
+            /// let mut queue = Queue::from_vec(vec![1, 2]);
+            /// assert_eq!(queue.peek(), Some(&1));
+            /// queue.clear();
+            /// assert_eq!(queue.peek(), None);
+            /// ```
+            pub fn peek(&self) -> Option<&T> {
+                // SAFETY: the queue owns the node, and the borrow of `self` keeps it alive
+                self.head.map(|head| unsafe { &(*head.as_ptr()).val })
+            }
+
+            /// Returns the element at the front of the queue mutably, or `None` if it is empty
+            pub fn peek_mut(&mut self) -> Option<&mut T> {
+                // SAFETY: as above, borrowing `self` mutably
+                self.head.map(|head| unsafe { &mut (*head.as_ptr()).val })
+            }
+
+            /// Returns the element at the back of the queue, or `None` if it is empty
+            ///
+            /// # Example
+            /// ```
+            /// # use crate::data_structure::ds::queue::Queue;
+            /// let mut queue = Queue::from_vec(vec![1, 2]);
+            /// if let Some(back) = queue.peek_back_mut() {
+            ///     *back = 5;
+            /// }
+            /// assert_eq!(queue.peek_back(), Some(&5));
+            /// ```
+            pub fn peek_back(&self) -> Option<&T> {
+                // SAFETY: the queue owns the node, and the borrow of `self` keeps it alive
+                self.tail.map(|tail| unsafe { &(*tail.as_ptr()).val })
+            }
+
+            /// Returns the element at the back of the queue mutably, or `None` if it is empty
+            pub fn peek_back_mut(&mut self) -> Option<&mut T> {
+                // SAFETY: as above, borrowing `self` mutably
+                self.tail.map(|tail| unsafe { &mut (*tail.as_ptr()).val })
+            }
+
+            /// Removes every element from the queue
+            pub fn clear(&mut self) {
+                if let Some(head) = self.head.take() {
+                    // SAFETY: `head` came out of a box the queue owns
+                    drop(unsafe { Box::from_raw(head.as_ptr()) });
+                }
+                self.tail = None;
+                self.len = 0;
+            }
+
+            /// Returns an iterator over the queue from front to back
+            ///
+            /// # Example
+            /// ```
+            /// # use crate::data_structure::ds::queue::Queue;
+            /// let queue = Queue::from([1, 2, 3]);
+            /// assert_eq!(queue.iter().collect::<Vec<_>>(), vec![&1, &2, &3]);
+            /// ```
+            pub fn iter(&self) -> Iter<'_, T> {
+                // SAFETY: the queue owns the node, and the borrow of `self` keeps it alive
+                Iter::with_len(self.head.map(|head| unsafe { &*head.as_ptr() }), self.len)
+            }
+
+            /// Returns an iterator over mutable references to the elements, from front to back
+            ///
+            /// # Example
+            /// ```
+            /// # use crate::data_structure::ds::queue::Queue;
+            /// let mut queue = Queue::from([1, 2, 3]);
+            /// queue.iter_mut().for_each(|val| *val *= 10);
+            /// assert_eq!(queue, Queue::from([10, 20, 30]));
+            /// ```
+            pub fn iter_mut(&mut self) -> IterMut<'_, T> {
+                // SAFETY: as above, borrowing `self` mutably
+                IterMut::with_len(self.head.map(|head| unsafe { &mut *head.as_ptr() }), self.len)
+            }
+        }
+
+        impl<T> Drop for Queue<T> {
+            fn drop(&mut self) {
+                self.clear();
+            }
+        }
+
+        impl<T> Default for Queue<T> {
+            fn default() -> Self {
+                Self::new()
+            }
+        }
+
+        impl<T: Clone> Clone for Queue<T> {
+            fn clone(&self) -> Self {
+                self.iter().cloned().collect()
+            }
+        }
+
+        /// Lists the elements from front to back
+        impl<T: Debug> Debug for Queue<T> {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.debug_list().entries(self.iter()).finish()
+            }
+        }
+
+        /// Draws the queue from front to back, like `Stack::print`, with every
+        /// cell as wide as the widest element
+        ///
+        /// # Example
+        /// ```
+        /// # use crate::data_structure::ds::queue::Queue;
+        /// let queue = Queue::from([1, 2, 3]);
+        /// let drawn = "\
+        /// +---+---+---+
+        /// | 1 | 2 | 3 |
+        /// +---+---+---+
+        ///   ↑       ↑
+        /// FRONT   BACK";
+        /// assert_eq!(queue.to_string(), drawn);
+        /// ```
+        impl<T: Display> Display for Queue<T> {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                let cells: Vec<String> = self.iter().map(|val| val.to_string()).collect();
+                let width = cells.iter().map(|cell| cell.chars().count()).max().unwrap_or(1).max(1);
+                let separator = format!("+{}", "-".repeat(width + 2)).repeat(self.len) + "+";
+                writeln!(f, "{}", separator)?;
+                for cell in &cells {
+                    write!(f, "| {:^width$} ", cell)?;
+                }
+                writeln!(f, "|")?;
+                writeln!(f, "{}", separator)?;
+
+                // Column of the arrow under the middle of the element at `index`
+                let arrow = |index: usize| index * (width + 3) + 2 + (width - 1) / 2;
+                let front = arrow(0);
+                match self.len {
+                    0 => write!(f, "(empty)"),
+                    1 => write!(f, "{}↑\n{}FRONT/BACK", " ".repeat(front), " ".repeat(front.saturating_sub(5))),
+                    len => {
+                        // Each label is centered on its arrow, but BACK has to
+                        // leave room for FRONT when the cells are narrow
+                        let back = arrow(len - 1);
+                        let front_label = front - 2;
+                        let back_label = (back - 2).max(front_label + 6);
+                        write!(
+                            f,
+                            "{}↑{}↑\n{}FRONT{}BACK",
+                            " ".repeat(front),
+                            " ".repeat(back - front - 1),
+                            " ".repeat(front_label),
+                            " ".repeat(back_label - front_label - 5)
+                        )
+                    }
+                }
+            }
+        }
+
+        /// Queues are equal if they hold the same elements in the same order
+        impl<T: PartialEq> PartialEq for Queue<T> {
+            fn eq(&self, other: &Self) -> bool {
+                self.len == other.len && self.iter().eq(other.iter())
+            }
+        }
+
+        impl<T: Eq> Eq for Queue<T> {}
+
+        impl<'a, T> IntoIterator for &'a Queue<T> {
+            type Item = &'a T;
+            type IntoIter = Iter<'a, T>;
+
+            fn into_iter(self) -> Self::IntoIter {
+                self.iter()
+            }
+        }
+
+        impl<'a, T> IntoIterator for &'a mut Queue<T> {
+            type Item = &'a mut T;
+            type IntoIter = IterMut<'a, T>;
+
+            fn into_iter(self) -> Self::IntoIter {
+                self.iter_mut()
+            }
+        }
+
+        /// Dequeues every element from front to back
+        impl<T> IntoIterator for Queue<T> {
+            type Item = T;
+            type IntoIter = IntoIter<T>;
+
+            fn into_iter(mut self) -> Self::IntoIter {
+                // SAFETY: `head` came out of a box the queue owns, and is taken
+                // so the queue doesn't drop it
+                let head = self.head.take().map(|head| unsafe { Box::from_raw(head.as_ptr()) });
+                IntoIter::with_len(head, self.len)
+            }
+        }
+
+        /// Builds a queue whose front is the first element yielded,
+        /// matching [from_vec](struct.Queue.html#method.from_vec)
+        impl<T> FromIterator<T> for Queue<T> {
+            fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+                let mut queue = Queue::new();
+                queue.extend(iter);
+                queue
+            }
+        }
+
+        /// Enqueues every element of the iterator in order
+        impl<T> Extend<T> for Queue<T> {
+            fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+                for val in iter {
+                    self.enqueue(val);
+                }
+            }
+        }
+
+        impl<'a, T: Copy + 'a> Extend<&'a T> for Queue<T> {
+            fn extend<I: IntoIterator<Item = &'a T>>(&mut self, iter: I) {
+                self.extend(iter.into_iter().copied());
+            }
+        }
+
+        /// The first element of the array ends up at the front
+        impl<T, const N: usize> From<[T; N]> for Queue<T> {
+            fn from(arr: [T; N]) -> Self {
+                arr.into_iter().collect()
+            }
+        }
+    }
+
+    /// This module provides a growable array named `Vector`
+    ///
+    /// Functions Implemented:
+    /// * [new](struct.Vector.html#method.new) -> `Self`
+    /// * [with_capacity](struct.Vector.html#method.with_capacity) -> `Self`
+    /// * [new_in](struct.Vector.html#method.new_in) -> `Self`
+    /// * [with_capacity_in](struct.Vector.html#method.with_capacity_in) -> `Self`
+    /// * [allocator](struct.Vector.html#method.allocator) -> `&A`
+    /// * [len](struct.Vector.html#method.len) -> `usize`
+    /// * [capacity](struct.Vector.html#method.capacity) -> `usize`
+    /// * [is_empty](struct.Vector.html#method.is_empty) -> `bool`
+    /// * [grow](struct.Vector.html#method.grow) -> `()`
+    /// * [reserve](struct.Vector.html#method.reserve) -> `()`
+    /// * [reserve_exact](struct.Vector.html#method.reserve_exact) -> `()`
+    /// * [try_reserve](struct.Vector.html#method.try_reserve) -> `Result<(), Error>`
+    /// * [try_reserve_exact](struct.Vector.html#method.try_reserve_exact) -> `Result<(), Error>`
+    /// * [shrink_to_fit](struct.Vector.html#method.shrink_to_fit) -> `()`
+    /// * [growth_policy](struct.Vector.html#method.growth_policy) -> `&GrowthPolicy`
+    /// * [set_growth_policy](struct.Vector.html#method.set_growth_policy) -> `()`
+    /// * [push](struct.Vector.html#method.push) -> `()`
+    /// * [pop](struct.Vector.html#method.pop) -> `Option<T>`
+    /// * [insert](struct.Vector.html#method.insert) -> `()`
+    /// * [try_insert](struct.Vector.html#method.try_insert) -> `Result<(), Error>`
+    /// * [remove](struct.Vector.html#method.remove) -> `T`
+    /// * [try_remove](struct.Vector.html#method.try_remove) -> `Result<T, Error>`
+    /// * [swap_remove](struct.Vector.html#method.swap_remove) -> `T`
+    /// * [truncate](struct.Vector.html#method.truncate) -> `()`
+    /// * [clear](struct.Vector.html#method.clear) -> `()`
+    /// * [drain](struct.Vector.html#method.drain) -> `Drain<'_, T>`
+    /// * [splice](struct.Vector.html#method.splice) -> `Splice<'_, I::IntoIter>`
+    /// * [as_slice](struct.Vector.html#method.as_slice) -> `&[T]`
+    /// * [as_mut_slice](struct.Vector.html#method.as_mut_slice) -> `&mut [T]`
+    ///
+    /// `Vector<T>` dereferences to `[T]`, so `get`, `get_mut`, `iter`, `sort`
+    /// and every other slice method can be called on it directly.
+    pub mod vector {
+        use std::alloc::{self, Layout};
+        use std::fmt::{self, Debug};
+        use std::iter::FusedIterator;
+        use std::ops::{Bound, Deref, DerefMut, RangeBounds};
+        use std::ptr::NonNull;
+        use std::ptr;
+        use std::slice;
+        use std::sync::Arc;
+        use crate::ds::Error;
+        use crate::ds::allocator::{Allocator, Global};
+
+        /// Growable array implementation
+        ///
+        /// Zero-sized element types never allocate: their capacity is `usize::MAX`
+        /// and only the length is tracked.
+        ///
+        /// The buffer comes from the allocator `A`, which defaults to [Global].
+        pub struct Vector<T, A: Allocator = Global> {
+            ptr: NonNull<T>,
+            len: usize,
+            cap: usize,
+            alloc: A,
+            growth: GrowthPolicy,
+        }
+
+        /// How a `Vector<T>` picks its next capacity when it runs out of room
+        ///
+        /// The vector never grows to less than what is required, and never
+        /// allocates fewer than a handful of elements at once (8 one-byte elements,
+        /// 4 elements up to 1 KiB, or 1 larger element), whatever the policy.
+        ///
+        /// # Example
+        /// ```
+        /// # use crate::data_structure::ds::vector::{GrowthPolicy, Vector};
+        /// use std::sync::Arc;
+        ///
+        /// let factor = 4;
+        /// let mut vector = Vector::new();
+        /// vector.set_growth_policy(GrowthPolicy::Custom(Arc::new(move |cap| cap * factor)));
+        /// for i in 0..5 {
+        ///     vector.push(i);
+        /// }
+        /// assert_eq!(vector.capacity(), 16);
+        /// ```
+        #[derive(Clone, Default)]
+        pub enum GrowthPolicy {
+            /// Doubles the capacity, keeping pushes amortised `O(1)`
+            #[default]
+            Doubling,
+            /// Grows the capacity by half, trading a few more reallocations for less
+            /// unused memory
+            OneAndHalf,
+            /// Grows the capacity by a fixed number of elements
+            Fixed(usize),
+            /// Computes the next capacity from the current one with a closure,
+            /// which may capture state and is shared by the clones of the policy
+            Custom(Arc<dyn Fn(usize) -> usize + Send + Sync>),
+        }
+
+        impl GrowthPolicy {
+            /// Returns the capacity to grow to from `cap`, before the required
+            /// and minimum capacities are taken into account
+            fn next_capacity(&self, cap: usize) -> usize {
+                match self {
+                    GrowthPolicy::Doubling => cap.saturating_mul(2),
+                    GrowthPolicy::OneAndHalf => cap.saturating_add(cap / 2),
+                    GrowthPolicy::Fixed(step) => cap.saturating_add(*step),
+                    GrowthPolicy::Custom(next) => next(cap),
+                }
+            }
+        }
+
+        impl fmt::Debug for GrowthPolicy {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                match self {
+                    GrowthPolicy::Doubling => write!(f, "Doubling"),
+                    GrowthPolicy::OneAndHalf => write!(f, "OneAndHalf"),
+                    GrowthPolicy::Fixed(step) => f.debug_tuple("Fixed").field(step).finish(),
+                    GrowthPolicy::Custom(_) => f.debug_tuple("Custom").finish_non_exhaustive(),
+                }
+            }
+        }
+
+        /// Why growing a buffer failed, keeping the layout around for `handle_alloc_error`
+        enum ReserveError {
+            Overflow,
+            Alloc(Layout),
+        }
+
+        impl From<ReserveError> for Error {
+            fn from(e: ReserveError) -> Self {
+                match e {
+                    ReserveError::Overflow => Error::CapacityOverflow,
+                    ReserveError::Alloc(_) => Error::AllocFailed,
+                }
+            }
+        }
+
+        /// Panics on capacity overflow and aborts through `handle_alloc_error` when
+        /// the allocator fails, like `Vec` does
+        fn handle_reserve(result: Result<(), ReserveError>) {
+            match result {
+                Ok(()) => {}
+                Err(ReserveError::Overflow) => panic!("{}", Error::CapacityOverflow),
+                Err(ReserveError::Alloc(layout)) => alloc::handle_alloc_error(layout),
+            }
+        }
+
+        // SAFETY: the vector owns its buffer exclusively, like `Box<[T]>` would
+        unsafe impl<T: Send, A: Allocator + Send> Send for Vector<T, A> {}
+        unsafe impl<T: Sync, A: Allocator + Sync> Sync for Vector<T, A> {}
+
+        impl<T> Vector<T> {
+            /// Constructs a new, empty `Vector<T>` without allocating
+            ///
+            /// # Example
+            /// ```
+            /// # use crate::data_structure::ds::vector::Vector;
+            /// let vector: Vector<i32> = Vector::new();
+            /// assert!(vector.is_empty());
+            /// assert_eq!(vector.capacity(), 0);
+            ///
+            /// let units: Vector<()> = Vector::new();
+            /// assert_eq!(units.capacity(), usize::MAX);
+            /// ```
+            pub fn new() -> Self {
+                Vector::new_in(Global)
+            }
+
+            /// Constructs a new, empty `Vector<T>` with room for at least `capacity`
+            /// elements
+            ///
+            /// # Example
+            /// ```
+            /// # use crate::data_structure::ds::vector::Vector;
+            /// let mut vector = Vector::with_capacity(10);
+            /// assert_eq!(vector.capacity(), 10);
+            /// vector.push(1);
+            /// assert_eq!(vector.capacity(), 10);
+            /// ```
+            pub fn with_capacity(capacity: usize) -> Self {
+                Vector::with_capacity_in(capacity, Global)
+            }
+        }
+
+        impl<T, A: Allocator> Vector<T, A> {
+            const IS_ZST: bool = size_of::<T>() == 0;
+
+            /// Smallest capacity worth allocating: tiny elements get a few slots at
+            /// once, while elements over 1 KiB are allocated one at a time
+            const MIN_NON_ZERO_CAP: usize = match size_of::<T>() {
+                1 => 8,
+                size if size <= 1024 => 4,
+                _ => 1,
+            };
+
+            /// Constructs a new, empty `Vector<T, A>` allocating from `alloc`,
+            /// without allocating yet
+            pub fn new_in(alloc: A) -> Self {
+                Vector {
+                    ptr: NonNull::dangling(),
+                    len: 0,
+                    cap: if Self::IS_ZST { usize::MAX } else { 0 },
+                    alloc,
+                    growth: GrowthPolicy::default(),
+                }
+            }
+
+            /// Constructs a new, empty `Vector<T, A>` allocating from `alloc`,
+            /// with room for at least `capacity` elements
+            pub fn with_capacity_in(capacity: usize, alloc: A) -> Self {
+                let mut vector = Vector::new_in(alloc);
+                vector.reserve_exact(capacity);
+                vector
+            }
+
+            /// Returns the allocator the vector allocates from
+            pub fn allocator(&self) -> &A {
+                &self.alloc
+            }
+
+            /// Returns the number of elements in the vector
+            pub fn len(&self) -> usize {
+                self.len
+            }
+
+            /// Returns the number of elements the vector can hold without reallocating
+            pub fn capacity(&self) -> usize {
+                self.cap
+            }
+
+            /// Returns `true` if the vector contains no elements
+            pub fn is_empty(&self) -> bool {
+                self.len == 0
+            }
+
+            /// Grows the capacity of the vector according to its growth policy,
+            /// allocating room for a few elements if it hasn't allocated yet
+            ///
+            /// # Example
+            /// ```
+            /// # use crate::data_structure::ds::vector::Vector;
+            /// let mut vector: Vector<u32> = Vector::new();
+            /// vector.grow();
+            /// assert_eq!(vector.capacity(), 4);
+            /// vector.grow();
+            /// assert_eq!(vector.capacity(), 8);
+            /// ```
+            pub fn grow(&mut self) {
+                let result = match self.cap.checked_add(1) {
+                    Some(required) => self.grow_amortized(required),
+                    None => Err(ReserveError::Overflow),
+                };
+                handle_reserve(result);
+            }
+
+            /// Reserves room for at least `additional` more elements, growing
+            /// according to the growth policy so that repeated calls stay amortised `O(1)`
+            ///
+            /// # Panics
+            /// Panics if the new capacity overflows, see
+            /// [try_reserve](struct.Vector.html#method.try_reserve) for a non-panicking version
+            ///
+            /// # Example
+            /// ```
+            /// # use crate::data_structure::ds::vector::Vector;
+            /// let mut vector = Vector::from([1]);
+            /// vector.reserve(10);
+            /// assert!(vector.capacity() >= 11);
+            /// ```
+            pub fn reserve(&mut self, additional: usize) {
+                handle_reserve(self.try_reserve_inner(additional, false));
+            }
+
+            /// Reserves room for exactly `additional` more elements
+            ///
+            /// # Panics
+            /// Panics if the new capacity overflows, see
+            /// [try_reserve_exact](struct.Vector.html#method.try_reserve_exact) for a
+            /// non-panicking version
+            ///
+            /// # Example
+            /// ```
+            /// # use crate::data_structure::ds::vector::Vector;
+            /// let mut vector = Vector::from([1]);
+            /// vector.reserve_exact(10);
+            /// assert_eq!(vector.capacity(), 11);
+            /// ```
+            pub fn reserve_exact(&mut self, additional: usize) {
+                handle_reserve(self.try_reserve_inner(additional, true));
+            }
+
+            /// Reserves room for at least `additional` more elements like
+            /// [reserve](struct.Vector.html#method.reserve)
+            ///
+            /// Returns `Error::CapacityOverflow` if the new capacity doesn't fit in
+            /// memory, and `Error::AllocFailed` if the allocator runs out of it.
+            ///
+            /// # Example
+            /// ```
+            /// # use crate::data_structure::ds::{Error, vector::Vector};
+            /// let mut vector = Vector::from([1u64]);
+            /// assert_eq!(vector.try_reserve(10), Ok(()));
+            /// assert_eq!(vector.try_reserve(usize::MAX), Err(Error::CapacityOverflow));
+            /// assert_eq!(vector.try_reserve(usize::MAX / 8), Err(Error::CapacityOverflow));
+            /// ```
+            pub fn try_reserve(&mut self, additional: usize) -> Result<(), Error> {
+                self.try_reserve_inner(additional, false).map_err(Error::from)
+            }
+
+            /// Reserves room for exactly `additional` more elements like
+            /// [reserve_exact](struct.Vector.html#method.reserve_exact)
+            ///
+            /// Returns `Error::CapacityOverflow` if the new capacity doesn't fit in
+            /// memory, and `Error::AllocFailed` if the allocator runs out of it.
+            pub fn try_reserve_exact(&mut self, additional: usize) -> Result<(), Error> {
+                self.try_reserve_inner(additional, true).map_err(Error::from)
+            }
+
+            /// Shrinks the capacity of the vector to its length, freeing the buffer
+            /// entirely if it is empty
+            ///
+            /// # Example
+            /// ```
+            /// # use crate::data_structure::ds::vector::Vector;
+            /// let mut vector = Vector::with_capacity(10);
+            /// vector.push(1);
+            /// vector.shrink_to_fit();
+            /// assert_eq!(vector.capacity(), 1);
+            /// ```
+            pub fn shrink_to_fit(&mut self) {
+                if !Self::IS_ZST && self.cap > self.len {
+                    handle_reserve(self.realloc(self.len));
+                }
+            }
+
+            /// Returns the policy the vector grows its capacity by
+            pub fn growth_policy(&self) -> &GrowthPolicy {
+                &self.growth
+            }
+
+            /// Sets the policy the vector grows its capacity by from now on
+            ///
+            /// # Example
+            /// ```
+            /// # use crate::data_structure::ds::vector::{GrowthPolicy, Vector};
+            /// let mut vector = Vector::with_capacity(10);
+            /// vector.set_growth_policy(GrowthPolicy::Fixed(5));
+            /// vector.extend(0..11);
+            /// assert_eq!(vector.capacity(), 15);
+            /// ```
+            pub fn set_growth_policy(&mut self, growth: GrowthPolicy) {
+                self.growth = growth;
+            }
+
+            fn try_reserve_inner(&mut self, additional: usize, exact: bool) -> Result<(), ReserveError> {
+                let required = self.len.checked_add(additional).ok_or(ReserveError::Overflow)?;
+                if required <= self.cap {
+                    return Ok(());
+                }
+                if exact {
+                    self.realloc(required)
+                } else {
+                    self.grow_amortized(required)
+                }
+            }
+
+            /// Grows the buffer to at least `required` elements, following the growth
+            /// policy and never allocating fewer than `MIN_NON_ZERO_CAP` elements
+            fn grow_amortized(&mut self, required: usize) -> Result<(), ReserveError> {
+                let new_cap = self.growth.next_capacity(self.cap).max(required).max(Self::MIN_NON_ZERO_CAP);
+                self.realloc(new_cap)
+            }
+
+            /// Moves the buffer to an allocation of exactly `new_cap` elements,
+            /// which must not be less than `len`
+            fn realloc(&mut self, new_cap: usize) -> Result<(), ReserveError> {
+                debug_assert!(new_cap >= self.len);
+                if Self::IS_ZST {
+                    return if new_cap > self.cap { Err(ReserveError::Overflow) } else { Ok(()) };
+                }
+                let new_layout = Layout::array::<T>(new_cap).map_err(|_| ReserveError::Overflow)?;
+
+                if new_cap == 0 {
+                    self.dealloc();
+                    self.ptr = NonNull::dangling();
+                    self.cap = 0;
+                    return Ok(());
+                }
+
+                let new_ptr = if self.cap == 0 {
+                    self.alloc.allocate(new_layout)
+                } else {
+                    let old_layout = Layout::array::<T>(self.cap).unwrap();
+                    let old_ptr = self.ptr.cast();
+                    // SAFETY: the buffer was allocated by `alloc` with `old_layout`
+                    unsafe {
+                        if new_cap > self.cap {
+                            self.alloc.grow(old_ptr, old_layout, new_layout)
+                        } else {
+                            self.alloc.shrink(old_ptr, old_layout, new_layout)
+                        }
+                    }
+                };
+
+                self.ptr = new_ptr.map_err(|_| ReserveError::Alloc(new_layout))?.cast();
+                self.cap = new_cap;
+                Ok(())
+            }
+
+            /// Frees the buffer without touching the elements
+            fn dealloc(&mut self) {
+                if !Self::IS_ZST && self.cap != 0 {
+                    unsafe {
+                        self.alloc.deallocate(self.ptr.cast(), Layout::array::<T>(self.cap).unwrap());
+                    }
+                }
+            }
+
+            pub fn push(&mut self, val: T) {
+                if self.len == self.cap { self.grow() }
+
+                unsafe {
+                    ptr::write(self.ptr.as_ptr().add(self.len), val);
+                }
+
+                self.len += 1;
+            }
+
+            pub fn pop(&mut self) -> Option<T> {
+                if self.len == 0 {
+                    None
+                } else {
+                    self.len -= 1;
+                    unsafe {
+                        Some(ptr::read(self.ptr.as_ptr().add(self.len)))
+                    }
+                }
+            }
+
+            /// Inserts `val` at `index`, shifting every element after it to the right
+            ///
+            /// # Panics
+            /// Panics if `index > len`, see [try_insert](struct.Vector.html#method.try_insert)
+            /// for a non-panicking version
+            ///
+            /// # Example
+            /// ```
+            /// # use crate::data_structure::ds::vector::Vector;
+            /// let mut vector = Vector::from([1, 3]);
+            /// vector.insert(1, 2);
+            /// vector.insert(3, 4);
+            /// assert_eq!(vector, [1, 2, 3, 4]);
+            /// ```
+            pub fn insert(&mut self, index: usize, val: T) {
+                if let Err(e) = self.try_insert(index, val) {
+                    panic!("{e}");
+                }
+            }
+
+            /// Inserts `val` at `index`, shifting every element after it to the right
+            ///
+            /// Returns `Error::IndexOutOfRange` if `index > len`.
+            ///
+            /// # Example
+            /// ```
+            /// # use crate::data_structure::ds::vector::Vector;
+            /// # use crate::data_structure::ds::Error;
+            /// let mut vector = Vector::from([1, 2]);
+            /// assert_eq!(vector.try_insert(3, 4), Err(Error::IndexOutOfRange { index: 3, len: 2 }));
+            /// ```
+            pub fn try_insert(&mut self, index: usize, val: T) -> Result<(), Error> {
+                if index > self.len {
+                    return Err(Error::IndexOutOfRange { index, len: self.len });
+                }
+                if self.len == self.cap { self.grow() }
+
+                unsafe {
+                    let p = self.ptr.as_ptr().add(index);
+                    ptr::copy(p, p.add(1), self.len - index);
+                    ptr::write(p, val);
+                }
+
+                self.len += 1;
+                Ok(())
+            }
+
+            /// Removes and returns the element at `index`, shifting every element
+            /// after it to the left
+            ///
+            /// # Panics
+            /// Panics if `index >= len`, see [try_remove](struct.Vector.html#method.try_remove)
+            /// for a non-panicking version
+            ///
+            /// # Example
+            /// ```
+            /// # use crate::data_structure::ds::vector::Vector;
+            /// let mut vector = Vector::from([1, 2, 3]);
+            /// assert_eq!(vector.remove(0), 1);
+            /// assert_eq!(vector, [2, 3]);
+            /// ```
+            pub fn remove(&mut self, index: usize) -> T {
+                match self.try_remove(index) {
+                    Ok(val) => val,
+                    Err(e) => panic!("{e}"),
+                }
+            }
+
+            /// Removes and returns the element at `index`, shifting every element
+            /// after it to the left
+            ///
+            /// Returns `Error::IndexOutOfRange` if `index >= len`.
+            pub fn try_remove(&mut self, index: usize) -> Result<T, Error> {
+                if index >= self.len {
+                    return Err(Error::IndexOutOfRange { index, len: self.len });
+                }
+
+                self.len -= 1;
+                unsafe {
+                    let p = self.ptr.as_ptr().add(index);
+                    let val = ptr::read(p);
+                    ptr::copy(p.add(1), p, self.len - index);
+                    Ok(val)
+                }
+            }
+
+            /// Removes and returns the element at `index` in `O(1)`, replacing it
+            /// with the last element
+            ///
+            /// # Panics
+            /// Panics if `index >= len`
+            ///
+            /// # Example
+            /// ```
+            /// # use crate::data_structure::ds::vector::Vector;
+            /// let mut vector = Vector::from([1, 2, 3, 4]);
+            /// assert_eq!(vector.swap_remove(0), 1);
+            /// assert_eq!(vector, [4, 2, 3]);
+            /// ```
+            pub fn swap_remove(&mut self, index: usize) -> T {
+                if index >= self.len {
+                    panic!("{}", Error::IndexOutOfRange { index, len: self.len });
+                }
+
+                self.len -= 1;
+                unsafe {
+                    let base = self.ptr.as_ptr();
+                    let val = ptr::read(base.add(index));
+                    ptr::copy(base.add(self.len), base.add(index), 1);
+                    val
+                }
+            }
+
+            /// Shortens the vector to `len` elements, dropping the rest. Does nothing
+            /// if `len` is not less than the current length
+            ///
+            /// # Example
+            /// ```
+            /// # use crate::data_structure::ds::vector::Vector;
+            /// let mut vector = Vector::from([1, 2, 3]);
+            /// vector.truncate(1);
+            /// assert_eq!(vector, [1]);
+            /// assert_eq!(vector.capacity(), 3);
+            /// ```
+            pub fn truncate(&mut self, len: usize) {
+                if len >= self.len {
+                    return;
+                }
+                let tail = ptr::slice_from_raw_parts_mut(
+                    unsafe { self.ptr.as_ptr().add(len) },
+                    self.len - len,
+                );
+                // Shrink first so a panicking destructor leaks instead of double dropping
+                self.len = len;
+                unsafe { ptr::drop_in_place(tail) };
+            }
+
+            /// Drops every element, keeping the allocated capacity
+            pub fn clear(&mut self) {
+                self.truncate(0);
+            }
+
+            /// Removes the elements in `range` and returns them as an iterator
+            ///
+            /// The elements are removed even if the iterator isn't fully consumed.
+            ///
+            /// # Panics
+            /// Panics if the range starts after it ends or ends after `len`
+            ///
+            /// # Example
+            /// ```
+            /// # use crate::data_structure::ds::vector::Vector;
+            /// let mut vector = Vector::from([1, 2, 3, 4, 5]);
+            /// let drained: Vec<_> = vector.drain(1..3).collect();
+            /// assert_eq!(drained, vec![2, 3]);
+            /// assert_eq!(vector, [1, 4, 5]);
+            ///
+            /// vector.drain(..);
+            /// assert!(vector.is_empty());
+            /// ```
+            pub fn drain<R: RangeBounds<usize>>(&mut self, range: R) -> Drain<'_, T, A> {
+                let (start, end) = resolve_range(range, self.len);
+                let tail_len = self.len - end;
+                self.len = start;
+                Drain {
+                    vec: self,
+                    idx: start,
+                    end,
+                    tail_start: end,
+                    tail_len,
+                }
+            }
+
+            /// Replaces the elements in `range` with `replace_with`, returning the
+            /// removed elements as an iterator
+            ///
+            /// The replacement happens when the returned iterator is dropped.
+            ///
+            /// # Panics
+            /// Panics if the range starts after it ends or ends after `len`
+            ///
+            /// # Example
+            /// ```
+            /// # use crate::data_structure::ds::vector::Vector;
+            /// let mut vector = Vector::from([1, 2, 3, 4]);
+            /// let removed: Vec<_> = vector.splice(1..3, [7, 8, 9]).collect();
+            /// assert_eq!(removed, vec![2, 3]);
+            /// assert_eq!(vector, [1, 7, 8, 9, 4]);
+            /// ```
+            pub fn splice<R, I>(&mut self, range: R, replace_with: I) -> Splice<'_, I::IntoIter, A>
+            where
+                R: RangeBounds<usize>,
+                I: IntoIterator<Item = T>,
+            {
+                Splice {
+                    drain: self.drain(range),
+                    replace_with: replace_with.into_iter(),
+                }
+            }
+
+            /// Returns the elements as a slice
+            pub fn as_slice(&self) -> &[T] {
+                unsafe { slice::from_raw_parts(self.ptr.as_ptr(), self.len) }
+            }
+
+            /// Returns the elements as a mutable slice
+            pub fn as_mut_slice(&mut self) -> &mut [T] {
+                unsafe { slice::from_raw_parts_mut(self.ptr.as_ptr(), self.len) }
+            }
+
+            /// Returns a pointer to the start of the buffer, valid for `capacity`
+            /// elements whatever the length, for structures that manage the
+            /// initialized part of the buffer themselves
+            pub(crate) fn buf_ptr(&self) -> *mut T {
+                self.ptr.as_ptr()
+            }
+        }
+
+        impl<T> Default for Vector<T> {
+            fn default() -> Self {
+                Self::new()
+            }
+        }
+
+        impl<T, A: Allocator> Deref for Vector<T, A> {
+            type Target = [T];
+
+            fn deref(&self) -> &[T] {
+                self.as_slice()
+            }
+        }
+
+        impl<T, A: Allocator> DerefMut for Vector<T, A> {
+            fn deref_mut(&mut self) -> &mut [T] {
+                self.as_mut_slice()
+            }
+        }
+
+        impl<T: Clone, A: Allocator + Clone> Clone for Vector<T, A> {
+            fn clone(&self) -> Self {
+                let mut vector = Vector::with_capacity_in(self.len, self.alloc.clone());
+                vector.extend(self.iter().cloned());
+                vector
+            }
+        }
+
+        impl<T: Debug, A: Allocator> Debug for Vector<T, A> {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.debug_list().entries(self.iter()).finish()
+            }
+        }
+
+        impl<T: PartialEq, A: Allocator, B: Allocator> PartialEq<Vector<T, B>> for Vector<T, A> {
+            fn eq(&self, other: &Vector<T, B>) -> bool {
+                self.as_slice() == other.as_slice()
+            }
+        }
+
+        impl<T: Eq, A: Allocator> Eq for Vector<T, A> {}
+
+        impl<T: PartialEq, A: Allocator, const N: usize> PartialEq<[T; N]> for Vector<T, A> {
+            fn eq(&self, other: &[T; N]) -> bool {
+                self.as_slice() == other
+            }
+        }
+
+        impl<T: PartialEq, A: Allocator> PartialEq<[T]> for Vector<T, A> {
+            fn eq(&self, other: &[T]) -> bool {
+                self.as_slice() == other
+            }
+        }
+
+        impl<T> FromIterator<T> for Vector<T> {
+            fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+                let iter = iter.into_iter();
+                let mut vector = Vector::with_capacity(iter.size_hint().0);
+                vector.extend(iter);
+                vector
+            }
+        }
+
+        impl<T, A: Allocator> Extend<T> for Vector<T, A> {
+            fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+                let iter = iter.into_iter();
+                self.reserve(iter.size_hint().0);
+                for val in iter {
+                    self.push(val);
+                }
+            }
+        }
+
+        impl<'a, T: Copy + 'a, A: Allocator> Extend<&'a T> for Vector<T, A> {
+            fn extend<I: IntoIterator<Item = &'a T>>(&mut self, iter: I) {
+                self.extend(iter.into_iter().copied());
+            }
+        }
+
+        impl<T, const N: usize> From<[T; N]> for Vector<T> {
+            fn from(arr: [T; N]) -> Self {
+                arr.into_iter().collect()
+            }
+        }
+
+        impl<T, A: Allocator> Drop for Vector<T, A> {
+            fn drop(&mut self) {
+                self.clear();
+                self.dealloc();
+            }
+        }
+
+        impl<T, A: Allocator> IntoIterator for Vector<T, A> {
+            type Item = T;
+            type IntoIter = IntoIter<T, A>;
+
+            /// Consumes the vector, yielding its elements front to back while
+            /// reusing its allocation
+            fn into_iter(mut self) -> IntoIter<T, A> {
+                let end = self.len;
+                // The iterator owns the elements from here on, the vector only the buffer
+                self.len = 0;
+                IntoIter {
+                    buf: self,
+                    start: 0,
+                    end,
+                }
+            }
+        }
+
+        impl<'a, T, A: Allocator> IntoIterator for &'a Vector<T, A> {
+            type Item = &'a T;
+            type IntoIter = slice::Iter<'a, T>;
+
+            fn into_iter(self) -> Self::IntoIter {
+                self.iter()
+            }
+        }
+
+        impl<'a, T, A: Allocator> IntoIterator for &'a mut Vector<T, A> {
+            type Item = &'a mut T;
+            type IntoIter = slice::IterMut<'a, T>;
+
+            fn into_iter(self) -> Self::IntoIter {
+                self.iter_mut()
+            }
+        }
+
+        /// Owning iterator over a `Vector<T>`, created by its `IntoIterator` impl
+        ///
+        /// # Example
+        /// ```
+        /// # use crate::data_structure::ds::vector::Vector;
+        /// let vector = Vector::from([1, 2, 3, 4]);
+        /// let mut iter = vector.into_iter();
+        /// assert_eq!(iter.next_back(), Some(4));
+        /// assert_eq!(iter.as_slice(), [1, 2, 3]);
+        /// assert_eq!(iter.collect::<Vec<_>>(), vec![1, 2, 3]);
+        /// ```
+        pub struct IntoIter<T, A: Allocator = Global> {
+            // Keeps the buffer alive with `len == 0`; the elements in
+            // `start..end` are owned by the iterator
+            buf: Vector<T, A>,
+            start: usize,
+            end: usize,
+        }
+
+        impl<T, A: Allocator> IntoIter<T, A> {
+            /// Returns the elements that haven't been yielded yet as a slice
+            pub fn as_slice(&self) -> &[T] {
+                unsafe {
+                    slice::from_raw_parts(self.buf.ptr.as_ptr().add(self.start), self.end - self.start)
+                }
+            }
+        }
+
+        impl<T, A: Allocator> Iterator for IntoIter<T, A> {
+            type Item = T;
+
+            fn next(&mut self) -> Option<T> {
+                if self.start == self.end {
+                    return None;
+                }
+                let val = unsafe { ptr::read(self.buf.ptr.as_ptr().add(self.start)) };
+                self.start += 1;
+                Some(val)
+            }
+
+            fn size_hint(&self) -> (usize, Option<usize>) {
+                let len = self.end - self.start;
+                (len, Some(len))
+            }
+        }
+
+        impl<T, A: Allocator> DoubleEndedIterator for IntoIter<T, A> {
+            fn next_back(&mut self) -> Option<T> {
+                if self.start == self.end {
+                    return None;
+                }
+                self.end -= 1;
+                unsafe { Some(ptr::read(self.buf.ptr.as_ptr().add(self.end))) }
+            }
+        }
+
+        impl<T, A: Allocator> ExactSizeIterator for IntoIter<T, A> {}
+
+        impl<T, A: Allocator> FusedIterator for IntoIter<T, A> {}
+
+        impl<T: Debug, A: Allocator> Debug for IntoIter<T, A> {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.debug_tuple("IntoIter").field(&self.as_slice()).finish()
+            }
+        }
+
+        impl<T, A: Allocator> Drop for IntoIter<T, A> {
+            fn drop(&mut self) {
+                let remaining = ptr::slice_from_raw_parts_mut(
+                    unsafe { self.buf.ptr.as_ptr().add(self.start) },
+                    self.end - self.start,
+                );
+                self.start = self.end;
+                // `buf` still frees the allocation if an element's destructor panics
+                unsafe { ptr::drop_in_place(remaining) };
+            }
+        }
+
+        /// Draining iterator over a range of a `Vector<T>`, created by
+        /// [Vector::drain](struct.Vector.html#method.drain)
+        ///
+        /// The vector is truncated to the start of the range as soon as the drain
+        /// is created, so leaking it with `mem::forget` only leaks the drained
+        /// elements and the tail, it never exposes moved-out values.
+        pub struct Drain<'a, T, A: Allocator = Global> {
+            vec: &'a mut Vector<T, A>,
+            // Elements in `idx..end` haven't been yielded yet
+            idx: usize,
+            end: usize,
+            tail_start: usize,
+            tail_len: usize,
+        }
+
+        impl<T, A: Allocator> Drain<'_, T, A> {
+            /// Returns the elements that haven't been yielded yet as a slice
+            pub fn as_slice(&self) -> &[T] {
+                unsafe {
+                    slice::from_raw_parts(self.vec.ptr.as_ptr().add(self.idx), self.end - self.idx)
+                }
+            }
+
+            /// Moves the tail back so it follows `vec.len`, restoring the vector
+            fn restore_tail(&mut self) {
+                let start = self.vec.len;
+                if start != self.tail_start {
+                    unsafe {
+                        let base = self.vec.ptr.as_ptr();
+                        ptr::copy(base.add(self.tail_start), base.add(start), self.tail_len);
+                    }
+                    self.tail_start = start;
+                }
+                self.vec.len = start + self.tail_len;
+                self.tail_len = 0;
+            }
+        }
+
+        impl<T, A: Allocator> Iterator for Drain<'_, T, A> {
+            type Item = T;
+
+            fn next(&mut self) -> Option<T> {
+                if self.idx == self.end {
+                    return None;
+                }
+                let val = unsafe { ptr::read(self.vec.ptr.as_ptr().add(self.idx)) };
+                self.idx += 1;
+                Some(val)
+            }
+
+            fn size_hint(&self) -> (usize, Option<usize>) {
+                let len = self.end - self.idx;
+                (len, Some(len))
+            }
+        }
+
+        impl<T, A: Allocator> DoubleEndedIterator for Drain<'_, T, A> {
+            fn next_back(&mut self) -> Option<T> {
+                if self.idx == self.end {
+                    return None;
+                }
+                self.end -= 1;
+                unsafe { Some(ptr::read(self.vec.ptr.as_ptr().add(self.end))) }
+            }
+        }
+
+        impl<T, A: Allocator> ExactSizeIterator for Drain<'_, T, A> {}
+
+        impl<T, A: Allocator> FusedIterator for Drain<'_, T, A> {}
+
+        impl<T: Debug, A: Allocator> Debug for Drain<'_, T, A> {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.debug_tuple("Drain").field(&self.as_slice()).finish()
+            }
+        }
+
+        impl<T, A: Allocator> Drop for Drain<'_, T, A> {
+            fn drop(&mut self) {
+                /// Restores the tail even if dropping the remaining elements panics
+                struct TailGuard<'r, 'a, T, A: Allocator>(&'r mut Drain<'a, T, A>);
+
+                impl<T, A: Allocator> Drop for TailGuard<'_, '_, T, A> {
+                    fn drop(&mut self) {
+                        self.0.restore_tail();
+                    }
+                }
+
+                let guard = TailGuard(self);
+                let remaining = ptr::slice_from_raw_parts_mut(
+                    unsafe { guard.0.vec.ptr.as_ptr().add(guard.0.idx) },
+                    guard.0.end - guard.0.idx,
+                );
+                guard.0.idx = guard.0.end;
+                unsafe { ptr::drop_in_place(remaining) };
+            }
+        }
+
+        /// Splicing iterator over a `Vector<T>`, created by
+        /// [Vector::splice](struct.Vector.html#method.splice)
+        ///
+        /// Yields the removed elements; the replacement is inserted when it is
+        /// dropped, after any removed elements that weren't yielded are dropped.
+        #[derive(Debug)]
+        pub struct Splice<'a, I: Iterator, A: Allocator = Global> {
+            drain: Drain<'a, I::Item, A>,
+            replace_with: I,
+        }
+
+        impl<I: Iterator, A: Allocator> Iterator for Splice<'_, I, A> {
+            type Item = I::Item;
+
+            fn next(&mut self) -> Option<I::Item> {
+                self.drain.next()
+            }
+
+            fn size_hint(&self) -> (usize, Option<usize>) {
+                self.drain.size_hint()
+            }
+        }
+
+        impl<I: Iterator, A: Allocator> DoubleEndedIterator for Splice<'_, I, A> {
+            fn next_back(&mut self) -> Option<I::Item> {
+                self.drain.next_back()
+            }
+        }
+
+        impl<I: Iterator, A: Allocator> ExactSizeIterator for Splice<'_, I, A> {}
+
+        impl<I: Iterator, A: Allocator> Drop for Splice<'_, I, A> {
+            fn drop(&mut self) {
+                self.drain.by_ref().for_each(drop);
+
+                // Fill the gap left by the drained range first; `vec.len` only
+                // counts written elements so a panicking `replace_with` is sound
+                let drain = &mut self.drain;
+                while drain.vec.len < drain.tail_start {
+                    let Some(val) = self.replace_with.next() else {
+                        return;
+                    };
+                    unsafe { ptr::write(drain.vec.ptr.as_ptr().add(drain.vec.len), val) };
+                    drain.vec.len += 1;
+                }
+
+                let mut rest: Vector<I::Item> = self.replace_with.by_ref().collect();
+                if rest.is_empty() {
+                    return;
+                }
+
+                // Make room for the rest by moving the tail further back
+                let extra = rest.len();
+                let drain = &mut self.drain;
+                let tail_end = drain.tail_start + drain.tail_len;
+                drain.vec.reserve(tail_end - drain.vec.len + extra);
+                unsafe {
+                    let base = drain.vec.ptr.as_ptr();
+                    ptr::copy(base.add(drain.tail_start), base.add(drain.tail_start + extra), drain.tail_len);
+                    drain.tail_start += extra;
+                    ptr::copy_nonoverlapping(rest.ptr.as_ptr(), base.add(drain.vec.len), extra);
+                    rest.len = 0;
+                }
+                drain.vec.len += extra;
+            }
+        }
+
+        /// Converts `range` into `start..end` indices, checking them against `len`
+        pub(crate) fn resolve_range<R: RangeBounds<usize>>(range: R, len: usize) -> (usize, usize) {
+            let start = match range.start_bound() {
+                Bound::Included(&start) => start,
+                Bound::Excluded(&start) => start.checked_add(1).expect("Range start overflow"),
+                Bound::Unbounded => 0,
+            };
+            let end = match range.end_bound() {
+                Bound::Included(&end) => end.checked_add(1).expect("Range end overflow"),
+                Bound::Excluded(&end) => end,
+                Bound::Unbounded => len,
+            };
+            assert!(start <= end, "Range starts at {start} but ends at {end}");
+            assert!(end <= len, "Range end {end} out of range for length {len}");
+            (start, end)
+        }
+    }
+
+    /// This module provides a double-ended queue named `Deque`, stored as a
+    /// ring buffer in the allocation of a [Vector](super::vector::Vector)
+    ///
+    /// Functions Implemented:
+    /// * [new](struct.Deque.html#method.new) -> `Self`
+    /// * [with_capacity](struct.Deque.html#method.with_capacity) -> `Self`
+    /// * [len](struct.Deque.html#method.len) -> `usize`
+    /// * [is_empty](struct.Deque.html#method.is_empty) -> `bool`
+    /// * [capacity](struct.Deque.html#method.capacity) -> `usize`
+    /// * [reserve](struct.Deque.html#method.reserve) -> `()`
+    /// * [push_front](struct.Deque.html#method.push_front) -> `()`
+    /// * [push_back](struct.Deque.html#method.push_back) -> `()`
+    /// * [pop_front](struct.Deque.html#method.pop_front) -> `Option<T>`
+    /// * [pop_back](struct.Deque.html#method.pop_back) -> `Option<T>`
+    /// * [front](struct.Deque.html#method.front) -> `Option<&T>`
+    /// * [front_mut](struct.Deque.html#method.front_mut) -> `Option<&mut T>`
+    /// * [back](struct.Deque.html#method.back) -> `Option<&T>`
+    /// * [back_mut](struct.Deque.html#method.back_mut) -> `Option<&mut T>`
+    /// * [get](struct.Deque.html#method.get) -> `Option<&T>`
+    /// * [get_mut](struct.Deque.html#method.get_mut) -> `Option<&mut T>`
+    /// * [truncate](struct.Deque.html#method.truncate) -> `()`
+    /// * [clear](struct.Deque.html#method.clear) -> `()`
+    /// * [as_slices](struct.Deque.html#method.as_slices) -> `(&[T], &[T])`
+    /// * [as_mut_slices](struct.Deque.html#method.as_mut_slices) -> `(&mut [T], &mut [T])`
+    /// * [make_contiguous](struct.Deque.html#method.make_contiguous) -> `&mut [T]`
+    /// * [rotate_left](struct.Deque.html#method.rotate_left) -> `()`
+    /// * [rotate_right](struct.Deque.html#method.rotate_right) -> `()`
+    /// * [iter](struct.Deque.html#method.iter) -> `Iter<'_, T>`
+    /// * [iter_mut](struct.Deque.html#method.iter_mut) -> `IterMut<'_, T>`
+    /// * [range](struct.Deque.html#method.range) -> `Iter<'_, T>`
+    /// * [range_mut](struct.Deque.html#method.range_mut) -> `IterMut<'_, T>`
+    /// * [drain](struct.Deque.html#method.drain) -> `Drain<'_, T>`
+    ///
+    /// `Deque<T>` can be indexed like a slice, `deque[0]` being the front.
+    pub mod deque {
+        use std::fmt::{self, Debug};
+        use std::iter::FusedIterator;
+        use std::mem::MaybeUninit;
+        use std::ops::{Index, IndexMut, Range, RangeBounds};
+        use std::ptr;
+        use std::slice;
+        use crate::ds::Error;
+        use crate::ds::vector::{self, Vector};
+
+        /// Double-ended queue over a growable ring buffer
+        ///
+        /// The elements live in the buffer of a `Vector<T>` whose length stays 0,
+        /// so the deque grows by the vector's growth policy and the vector only
+        /// frees the buffer. The element at index `i` is stored `i` slots after
+        /// `head`, wrapping around the end of the buffer, which makes pushing and
+        /// popping at either end `O(1)`.
+        ///
+        /// # Example
+        /// ```
+        /// # use crate::data_structure::ds::deque::Deque;
+        /// let mut deque = Deque::new();
+        /// deque.push_back(2);
+        /// deque.push_back(3);
+        /// deque.push_front(1);
+        /// assert_eq!(deque, [1, 2, 3]);
+        /// assert_eq!(deque.pop_front(), Some(1));
+        /// assert_eq!(deque.pop_back(), Some(3));
+        /// assert_eq!(deque[0], 2);
+        /// ```
+        pub struct Deque<T> {
+            buf: Vector<T>,
+            head: usize,
+            len: usize,
+        }
+
+        impl<T> Deque<T> {
+            /// Constructs a new, empty `Deque<T>` without allocating
+            pub fn new() -> Self {
+                Deque {
+                    buf: Vector::new(),
+                    head: 0,
+                    len: 0,
+                }
+            }
+
+            /// Constructs a new, empty `Deque<T>` with room for at least `capacity`
+            /// elements
+            ///
+            /// # Example
+            /// ```
+            /// # use crate::data_structure::ds::deque::Deque;
+            /// let mut deque = Deque::with_capacity(10);
+            /// deque.extend(0..10);
+            /// assert_eq!(deque.capacity(), 10);
+            /// ```
+            pub fn with_capacity(capacity: usize) -> Self {
+                Deque {
+                    buf: Vector::with_capacity(capacity),
+                    head: 0,
+                    len: 0,
+                }
+            }
+
+            /// Returns the number of elements in the deque
+            pub fn len(&self) -> usize {
+                self.len
+            }
+
+            /// Returns `true` if the deque contains no elements
+            pub fn is_empty(&self) -> bool {
+                self.len == 0
+            }
+
+            /// Returns the number of elements the deque can hold without reallocating
+            pub fn capacity(&self) -> usize {
+                self.buf.capacity()
+            }
+
+            /// Reserves room for at least `additional` more elements, growing the
+            /// buffer by the growth policy of `Vector<T>`
+            ///
+            /// # Panics
+            /// Panics if the new capacity overflows `usize`
+            pub fn reserve(&mut self, additional: usize) {
+                let old_cap = self.capacity();
+                let required = self
+                    .len
+                    .checked_add(additional)
+                    .unwrap_or_else(|| panic!("{}", Error::CapacityOverflow));
+                if required <= old_cap {
+                    return;
+                }
+                // `buf` is empty, so this grows its buffer to at least `required`,
+                // keeping the contents of every slot
+                self.buf.reserve(required);
+                self.handle_capacity_increase(old_cap);
+            }
+
+            /// Moves the part of the elements that wrapped around the end of the
+            /// old buffer, so that they follow on from `head` in the grown one
+            fn handle_capacity_increase(&mut self, old_cap: usize) {
+                if self.head <= old_cap - self.len {
+                    return;
+                }
+                let new_cap = self.capacity();
+                let head_len = old_cap - self.head;
+                let tail_len = self.len - head_len;
+                let ptr = self.buf.buf_ptr();
+                // SAFETY: both copies stay inside the new buffer, and only move the
+                // initialized slots into ones the elements don't occupy
+                unsafe {
+                    if tail_len < head_len && tail_len <= new_cap - old_cap {
+                        // The wrapped part fits right after the old end
+                        ptr::copy_nonoverlapping(ptr, ptr.add(old_cap), tail_len);
+                    } else {
+                        let new_head = new_cap - head_len;
+                        ptr::copy(ptr.add(self.head), ptr.add(new_head), head_len);
+                        self.head = new_head;
+                    }
+                }
+            }
+
+            /// Returns the slot of the buffer the element at `index` is stored in
+            fn to_physical(&self, index: usize) -> usize {
+                let cap = self.capacity();
+                let slot = self.head.wrapping_add(index);
+                if slot >= cap { slot - cap } else { slot }
+            }
+
+            /// Appends an element to the back of the deque
+            pub fn push_back(&mut self, val: T) {
+                if self.len == self.capacity() {
+                    self.reserve(1);
+                }
+                let slot = self.to_physical(self.len);
+                unsafe { ptr::write(self.buf.buf_ptr().add(slot), val) };
+                self.len += 1;
+            }
+
+            /// Prepends an element to the front of the deque
+            pub fn push_front(&mut self, val: T) {
+                if self.len == self.capacity() {
+                    self.reserve(1);
+                }
+                self.head = self.to_physical(self.capacity() - 1);
+                unsafe { ptr::write(self.buf.buf_ptr().add(self.head), val) };
+                self.len += 1;
+            }
+
+            /// Removes the front element and returns it, or `None` if the deque is empty
+            pub fn pop_front(&mut self) -> Option<T> {
+                if self.len == 0 {
+                    return None;
+                }
+                let val = unsafe { ptr::read(self.buf.buf_ptr().add(self.head)) };
+                self.head = self.to_physical(1);
+                self.len -= 1;
+                Some(val)
+            }
+
+            /// Removes the back element and returns it, or `None` if the deque is empty
+            pub fn pop_back(&mut self) -> Option<T> {
+                if self.len == 0 {
+                    return None;
+                }
+                self.len -= 1;
+                let slot = self.to_physical(self.len);
+                unsafe { Some(ptr::read(self.buf.buf_ptr().add(slot))) }
+            }
+
+            /// Returns a reference to the front element, or `None` if the deque is empty
+            pub fn front(&self) -> Option<&T> {
+                self.get(0)
+            }
+
+            /// Returns a mutable reference to the front element, or `None` if the
+            /// deque is empty
+            pub fn front_mut(&mut self) -> Option<&mut T> {
+                self.get_mut(0)
+            }
+
+            /// Returns a reference to the back element, or `None` if the deque is empty
+            pub fn back(&self) -> Option<&T> {
+                self.get(self.len.checked_sub(1)?)
+            }
+
+            /// Returns a mutable reference to the back element, or `None` if the
+            /// deque is empty
+            pub fn back_mut(&mut self) -> Option<&mut T> {
+                self.get_mut(self.len.checked_sub(1)?)
+            }
+
+            /// Returns a reference to the element at `index` from the front, or
+            /// `None` if it is out of range
+            pub fn get(&self, index: usize) -> Option<&T> {
+                if index >= self.len {
+                    return None;
+                }
+                unsafe { Some(&*self.buf.buf_ptr().add(self.to_physical(index))) }
+            }
+
+            /// Returns a mutable reference to the element at `index` from the front,
+            /// or `None` if it is out of range
+            pub fn get_mut(&mut self, index: usize) -> Option<&mut T> {
+                if index >= self.len {
+                    return None;
+                }
+                unsafe { Some(&mut *self.buf.buf_ptr().add(self.to_physical(index))) }
+            }
+
+            /// Shortens the deque to `len` elements, dropping the ones at the back
+            ///
+            /// Does nothing if `len` is not less than the current length.
+            pub fn truncate(&mut self, len: usize) {
+                if len >= self.len {
+                    return;
+                }
+                let (front, back) = self.slices_in_mut(len, self.len);
+                let (front, back) = (front as *mut [T], back as *mut [T]);
+                // Shorten first, so a panicking destructor leaks instead of double dropping
+                self.len = len;
+                unsafe {
+                    ptr::drop_in_place(front);
+                    ptr::drop_in_place(back);
+                }
+            }
+
+            /// Drops all the elements, keeping the buffer
+            pub fn clear(&mut self) {
+                self.truncate(0);
+                self.head = 0;
+            }
+
+            /// Splits the elements in `start..end` into the ranges of slots they
+            /// occupy, the second one being empty unless they wrap around
+            fn slot_ranges(&self, start: usize, end: usize) -> (Range<usize>, Range<usize>) {
+                if start == end {
+                    return (0..0, 0..0);
+                }
+                let first = self.to_physical(start);
+                let len = end - start;
+                let first_len = self.capacity() - first;
+                if len <= first_len {
+                    (first..first + len, 0..0)
+                } else {
+                    (first..self.capacity(), 0..len - first_len)
+                }
+            }
+
+            /// Returns the elements in `start..end` as the two slices they are stored in
+            fn slices_in(&self, start: usize, end: usize) -> (&[T], &[T]) {
+                let (first, second) = self.slot_ranges(start, end);
+                let ptr = self.buf.buf_ptr();
+                // SAFETY: both ranges only cover initialized slots
+                unsafe {
+                    (
+                        slice::from_raw_parts(ptr.add(first.start), first.len()),
+                        slice::from_raw_parts(ptr.add(second.start), second.len()),
+                    )
+                }
+            }
+
+            /// Returns the elements in `start..end` as the two mutable slices they
+            /// are stored in
+            fn slices_in_mut(&mut self, start: usize, end: usize) -> (&mut [T], &mut [T]) {
+                let (first, second) = self.slot_ranges(start, end);
+                let ptr = self.buf.buf_ptr();
+                // SAFETY: both ranges only cover initialized slots, and never overlap
+                unsafe {
+                    (
+                        slice::from_raw_parts_mut(ptr.add(first.start), first.len()),
+                        slice::from_raw_parts_mut(ptr.add(second.start), second.len()),
+                    )
+                }
+            }
+
+            /// Returns the elements as the two slices they are stored in, front
+            /// to back
+            ///
+            /// The second slice is empty unless the elements wrap around the end
+            /// of the buffer.
+            ///
+            /// # Example
+            /// ```
+            /// # use crate::data_structure::ds::deque::Deque;
+            /// let mut deque = Deque::with_capacity(4);
+            /// deque.extend([2, 3]);
+            /// assert_eq!(deque.as_slices(), (&[2, 3][..], &[][..]));
+            /// deque.push_front(1);
+            /// assert_eq!(deque.as_slices(), (&[1][..], &[2, 3][..]));
+            /// ```
+            pub fn as_slices(&self) -> (&[T], &[T]) {
+                self.slices_in(0, self.len)
+            }
+
+            /// Returns the elements as the two mutable slices they are stored in,
+            /// front to back
+            pub fn as_mut_slices(&mut self) -> (&mut [T], &mut [T]) {
+                self.slices_in_mut(0, self.len)
+            }
+
+            /// Rearranges the buffer so the elements are stored contiguously,
+            /// returning them as a single slice
+            ///
+            /// Takes `O(capacity)` time if the elements wrap around the end of the
+            /// buffer, and `O(1)` otherwise.
+            ///
+            /// # Example
+            /// ```
+            /// # use crate::data_structure::ds::deque::Deque;
+            /// let mut deque = Deque::with_capacity(4);
+            /// deque.extend([2, 3]);
+            /// deque.push_front(1);
+            /// deque.make_contiguous().sort_by(|a, b| b.cmp(a));
+            /// assert_eq!(deque.as_slices(), (&[3, 2, 1][..], &[][..]));
+            /// ```
+            pub fn make_contiguous(&mut self) -> &mut [T] {
+                if self.head > self.capacity() - self.len {
+                    // SAFETY: every slot is a valid `MaybeUninit<T>`, so the whole buffer
+                    // can be rotated as such, moving the slot at `head` to the start
+                    let slots = unsafe {
+                        slice::from_raw_parts_mut(self.buf.buf_ptr().cast::<MaybeUninit<T>>(), self.capacity())
+                    };
+                    slots.rotate_left(self.head);
+                    self.head = 0;
+                }
+                self.as_mut_slices().0
+            }
+
+            /// Rotates the deque `n` places to the left, so that the element at
+            /// index `n` becomes the front
+            ///
+            /// Moves `min(n, len - n)` elements and never reallocates.
+            ///
+            /// # Panics
+            /// Panics if `n > len`
+            ///
+            /// # Example
+            /// ```
+            /// # use crate::data_structure::ds::deque::Deque;
+            /// let mut deque: Deque<_> = (0..5).collect();
+            /// deque.rotate_left(2);
+            /// assert_eq!(deque, [2, 3, 4, 0, 1]);
+            /// deque.rotate_right(3);
+            /// assert_eq!(deque, [4, 0, 1, 2, 3]);
+            /// ```
+            pub fn rotate_left(&mut self, n: usize) {
+                assert!(n <= self.len, "Rotation by {n} out of range for length {}", self.len);
+                if n <= self.len - n {
+                    self.move_front_to_back(n);
+                } else {
+                    self.move_back_to_front(self.len - n);
+                }
+            }
+
+            /// Rotates the deque `n` places to the right, so that the element at
+            /// index `len - n` becomes the front
+            ///
+            /// Moves `min(n, len - n)` elements and never reallocates.
+            ///
+            /// # Panics
+            /// Panics if `n > len`
+            pub fn rotate_right(&mut self, n: usize) {
+                assert!(n <= self.len, "Rotation by {n} out of range for length {}", self.len);
+                if n <= self.len - n {
+                    self.move_back_to_front(n);
+                } else {
+                    self.move_front_to_back(self.len - n);
+                }
+            }
+
+            /// Moves `n` elements from the front to the back, one at a time; popping
+            /// first always leaves room, so this never reallocates
+            fn move_front_to_back(&mut self, n: usize) {
+                for _ in 0..n {
+                    if let Some(val) = self.pop_front() {
+                        self.push_back(val);
+                    }
+                }
+            }
+
+            /// Moves `n` elements from the back to the front, one at a time
+            fn move_back_to_front(&mut self, n: usize) {
+                for _ in 0..n {
+                    if let Some(val) = self.pop_back() {
+                        self.push_front(val);
+                    }
+                }
+            }
+
+            /// Returns an iterator over the elements, front to back
+            pub fn iter(&self) -> Iter<'_, T> {
+                self.range(..)
+            }
+
+            /// Returns an iterator over mutable references to the elements, front
+            /// to back
+            pub fn iter_mut(&mut self) -> IterMut<'_, T> {
+                self.range_mut(..)
+            }
+
+            /// Returns an iterator over the elements in `range`, front to back
+            ///
+            /// # Panics
+            /// Panics if the range starts after it ends or ends after `len`
+            ///
+            /// # Example
+            /// ```
+            /// # use crate::data_structure::ds::deque::Deque;
+            /// let deque: Deque<_> = (0..6).collect();
+            /// assert_eq!(deque.range(2..5).copied().collect::<Vec<_>>(), vec![2, 3, 4]);
+            /// assert_eq!(deque.range(4..).rev().copied().collect::<Vec<_>>(), vec![5, 4]);
+            /// ```
+            pub fn range<R: RangeBounds<usize>>(&self, range: R) -> Iter<'_, T> {
+                let (start, end) = vector::resolve_range(range, self.len);
+                let (first, second) = self.slices_in(start, end);
+                Iter {
+                    first: first.iter(),
+                    second: second.iter(),
+                }
+            }
+
+            /// Returns an iterator over mutable references to the elements in
+            /// `range`, front to back
+            ///
+            /// # Panics
+            /// Panics if the range starts after it ends or ends after `len`
+            pub fn range_mut<R: RangeBounds<usize>>(&mut self, range: R) -> IterMut<'_, T> {
+                let (start, end) = vector::resolve_range(range, self.len);
+                let (first, second) = self.slices_in_mut(start, end);
+                IterMut {
+                    first: first.iter_mut(),
+                    second: second.iter_mut(),
+                }
+            }
+
+            /// Removes the elements in `range`, returning them as an iterator
+            ///
+            /// The elements left on either side of the range are joined up when the
+            /// iterator is dropped, by moving whichever side is shorter.
+            ///
+            /// # Panics
+            /// Panics if the range starts after it ends or ends after `len`
+            ///
+            /// # Example
+            /// ```
+            /// # use crate::data_structure::ds::deque::Deque;
+            /// let mut deque: Deque<_> = (0..6).collect();
+            /// let drained: Vec<_> = deque.drain(1..3).collect();
+            /// assert_eq!(drained, vec![1, 2]);
+            /// assert_eq!(deque, [0, 3, 4, 5]);
+            ///
+            /// deque.drain(..);
+            /// assert!(deque.is_empty());
+            /// ```
+            pub fn drain<R: RangeBounds<usize>>(&mut self, range: R) -> Drain<'_, T> {
+                let (start, end) = vector::resolve_range(range, self.len);
+                let orig_len = self.len;
+                self.len = start;
+                Drain {
+                    deque: self,
+                    idx: start,
+                    end,
+                    tail_start: end,
+                    orig_len,
+                }
+            }
+        }
+
+        impl<T> Default for Deque<T> {
+            fn default() -> Self {
+                Self::new()
+            }
+        }
+
+        impl<T> Drop for Deque<T> {
+            fn drop(&mut self) {
+                // `buf` frees the allocation afterwards
+                self.clear();
+            }
+        }
+
+        impl<T> Index<usize> for Deque<T> {
+            type Output = T;
+
+            fn index(&self, index: usize) -> &T {
+                let len = self.len;
+                self.get(index)
+                    .unwrap_or_else(|| panic!("{}", Error::IndexOutOfRange { index, len }))
+            }
+        }
+
+        impl<T> IndexMut<usize> for Deque<T> {
+            fn index_mut(&mut self, index: usize) -> &mut T {
+                let len = self.len;
+                self.get_mut(index)
+                    .unwrap_or_else(|| panic!("{}", Error::IndexOutOfRange { index, len }))
+            }
+        }
+
+        impl<T: Clone> Clone for Deque<T> {
+            fn clone(&self) -> Self {
+                self.iter().cloned().collect()
+            }
+        }
+
+        impl<T: Debug> Debug for Deque<T> {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.debug_list().entries(self.iter()).finish()
+            }
+        }
+
+        impl<T: PartialEq> PartialEq for Deque<T> {
+            fn eq(&self, other: &Self) -> bool {
+                self.len == other.len && self.iter().eq(other.iter())
+            }
+        }
+
+        impl<T: Eq> Eq for Deque<T> {}
+
+        impl<T: PartialEq, const N: usize> PartialEq<[T; N]> for Deque<T> {
+            fn eq(&self, other: &[T; N]) -> bool {
+                self.len == N && self.iter().eq(other.iter())
+            }
+        }
+
+        impl<T: PartialEq> PartialEq<[T]> for Deque<T> {
+            fn eq(&self, other: &[T]) -> bool {
+                self.len == other.len() && self.iter().eq(other.iter())
+            }
+        }
+
+        impl<T> FromIterator<T> for Deque<T> {
+            fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+                let iter = iter.into_iter();
+                let mut deque = Deque::with_capacity(iter.size_hint().0);
+                deque.extend(iter);
+                deque
+            }
+        }
+
+        impl<T> Extend<T> for Deque<T> {
+            fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+                let iter = iter.into_iter();
+                self.reserve(iter.size_hint().0);
+                for val in iter {
+                    self.push_back(val);
+                }
+            }
+        }
+
+        impl<'a, T: Copy + 'a> Extend<&'a T> for Deque<T> {
+            fn extend<I: IntoIterator<Item = &'a T>>(&mut self, iter: I) {
+                self.extend(iter.into_iter().copied());
+            }
+        }
+
+        impl<T, const N: usize> From<[T; N]> for Deque<T> {
+            fn from(arr: [T; N]) -> Self {
+                arr.into_iter().collect()
+            }
+        }
+
+        impl<T> IntoIterator for Deque<T> {
+            type Item = T;
+            type IntoIter = IntoIter<T>;
+
+            /// Consumes the deque, yielding its elements front to back
+            fn into_iter(self) -> IntoIter<T> {
+                IntoIter { deque: self }
+            }
+        }
+
+        impl<'a, T> IntoIterator for &'a Deque<T> {
+            type Item = &'a T;
+            type IntoIter = Iter<'a, T>;
+
+            fn into_iter(self) -> Iter<'a, T> {
+                self.iter()
+            }
+        }
+
+        impl<'a, T> IntoIterator for &'a mut Deque<T> {
+            type Item = &'a mut T;
+            type IntoIter = IterMut<'a, T>;
+
+            fn into_iter(self) -> IterMut<'a, T> {
+                self.iter_mut()
+            }
+        }
+
+        /// Iterator over the elements of a `Deque<T>`, created by
+        /// [Deque::iter](struct.Deque.html#method.iter) and
+        /// [Deque::range](struct.Deque.html#method.range)
+        pub struct Iter<'a, T> {
+            first: slice::Iter<'a, T>,
+            second: slice::Iter<'a, T>,
+        }
+
+        impl<T> Clone for Iter<'_, T> {
+            fn clone(&self) -> Self {
+                Iter {
+                    first: self.first.clone(),
+                    second: self.second.clone(),
+                }
+            }
+        }
+
+        impl<'a, T> Iterator for Iter<'a, T> {
+            type Item = &'a T;
+
+            fn next(&mut self) -> Option<&'a T> {
+                self.first.next().or_else(|| self.second.next())
+            }
+
+            fn size_hint(&self) -> (usize, Option<usize>) {
+                let len = self.first.len() + self.second.len();
+                (len, Some(len))
+            }
+        }
+
+        impl<T> DoubleEndedIterator for Iter<'_, T> {
+            fn next_back(&mut self) -> Option<Self::Item> {
+                self.second.next_back().or_else(|| self.first.next_back())
+            }
+        }
+
+        impl<T> ExactSizeIterator for Iter<'_, T> {}
+
+        impl<T> FusedIterator for Iter<'_, T> {}
+
+        impl<T: Debug> Debug for Iter<'_, T> {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.debug_list().entries(self.clone()).finish()
+            }
+        }
+
+        /// Iterator over mutable references to the elements of a `Deque<T>`,
+        /// created by [Deque::iter_mut](struct.Deque.html#method.iter_mut) and
+        /// [Deque::range_mut](struct.Deque.html#method.range_mut)
+        pub struct IterMut<'a, T> {
+            first: slice::IterMut<'a, T>,
+            second: slice::IterMut<'a, T>,
+        }
+
+        impl<'a, T> Iterator for IterMut<'a, T> {
+            type Item = &'a mut T;
+
+            fn next(&mut self) -> Option<&'a mut T> {
+                self.first.next().or_else(|| self.second.next())
+            }
+
+            fn size_hint(&self) -> (usize, Option<usize>) {
+                let len = self.first.len() + self.second.len();
+                (len, Some(len))
+            }
+        }
+
+        impl<T> DoubleEndedIterator for IterMut<'_, T> {
+            fn next_back(&mut self) -> Option<Self::Item> {
+                self.second.next_back().or_else(|| self.first.next_back())
+            }
+        }
+
+        impl<T> ExactSizeIterator for IterMut<'_, T> {}
+
+        impl<T> FusedIterator for IterMut<'_, T> {}
+
+        impl<T: Debug> Debug for IterMut<'_, T> {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.debug_list()
+                    .entries(self.first.as_slice())
+                    .entries(self.second.as_slice())
+                    .finish()
+            }
+        }
+
+        /// Owning iterator over a `Deque<T>`, created by its `IntoIterator` impl
+        pub struct IntoIter<T> {
+            deque: Deque<T>,
+        }
+
+        impl<T> Iterator for IntoIter<T> {
+            type Item = T;
+
+            fn next(&mut self) -> Option<T> {
+                self.deque.pop_front()
+            }
+
+            fn size_hint(&self) -> (usize, Option<usize>) {
+                (self.deque.len, Some(self.deque.len))
+            }
+        }
+
+        impl<T> DoubleEndedIterator for IntoIter<T> {
+            fn next_back(&mut self) -> Option<T> {
+                self.deque.pop_back()
+            }
+        }
+
+        impl<T> ExactSizeIterator for IntoIter<T> {}
+
+        impl<T> FusedIterator for IntoIter<T> {}
+
+        impl<T: Debug> Debug for IntoIter<T> {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.debug_tuple("IntoIter").field(&self.deque).finish()
+            }
+        }
+
+        /// Draining iterator over a range of a `Deque<T>`, created by
+        /// [Deque::drain](struct.Deque.html#method.drain)
+        ///
+        /// The deque is truncated to the start of the range as soon as the drain
+        /// is created, so leaking it with `mem::forget` only leaks the drained
+        /// elements and the tail, it never exposes moved-out values.
+        pub struct Drain<'a, T> {
+            deque: &'a mut Deque<T>,
+            // Elements in `idx..end` haven't been yielded yet, the ones in
+            // `tail_start..orig_len` are kept
+            idx: usize,
+            end: usize,
+            tail_start: usize,
+            orig_len: usize,
+        }
+
+        impl<T> Drain<'_, T> {
+            /// Returns the elements that haven't been yielded yet as two slices
+            pub fn as_slices(&self) -> (&[T], &[T]) {
+                self.deque.slices_in(self.idx, self.end)
+            }
+
+            /// Joins the elements before the drained range up with the tail, moving
+            /// whichever side is shorter, and restores the length of the deque
+            fn join_tail(&mut self) {
+                let deque = &mut *self.deque;
+                let front_len = deque.len;
+                let gap = self.tail_start - front_len;
+                let tail_len = self.orig_len - self.tail_start;
+                let ptr = deque.buf.buf_ptr();
+                // SAFETY: every element is moved into a slot of the gap or one that was
+                // moved out of before, in an order that never overwrites a kept element
+                unsafe {
+                    if front_len < tail_len {
+                        for i in (0..front_len).rev() {
+                            ptr::copy(ptr.add(deque.to_physical(i)), ptr.add(deque.to_physical(i + gap)), 1);
+                        }
+                        deque.head = deque.to_physical(gap);
+                    } else {
+                        for i in 0..tail_len {
+                            let from = deque.to_physical(self.tail_start + i);
+                            ptr::copy(ptr.add(from), ptr.add(deque.to_physical(front_len + i)), 1);
+                        }
+                    }
+                }
+                deque.len = front_len + tail_len;
+                self.tail_start = front_len;
+                self.orig_len = deque.len;
+            }
+        }
+
+        impl<T> Iterator for Drain<'_, T> {
+            type Item = T;
+
+            fn next(&mut self) -> Option<T> {
+                if self.idx == self.end {
+                    return None;
+                }
+                let slot = self.deque.to_physical(self.idx);
+                let val = unsafe { ptr::read(self.deque.buf.buf_ptr().add(slot)) };
+                self.idx += 1;
+                Some(val)
+            }
+
+            fn size_hint(&self) -> (usize, Option<usize>) {
+                let len = self.end - self.idx;
+                (len, Some(len))
+            }
+        }
+
+        impl<T> DoubleEndedIterator for Drain<'_, T> {
+            fn next_back(&mut self) -> Option<T> {
+                if self.idx == self.end {
+                    return None;
+                }
+                self.end -= 1;
+                let slot = self.deque.to_physical(self.end);
+                unsafe { Some(ptr::read(self.deque.buf.buf_ptr().add(slot))) }
+            }
+        }
+
+        impl<T> ExactSizeIterator for Drain<'_, T> {}
+
+        impl<T> FusedIterator for Drain<'_, T> {}
+
+        impl<T: Debug> Debug for Drain<'_, T> {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.debug_tuple("Drain").field(&self.as_slices()).finish()
+            }
+        }
+
+        impl<T> Drop for Drain<'_, T> {
+            fn drop(&mut self) {
+                /// Joins the tail back up even if dropping the remaining elements panics
+                struct TailGuard<'r, 'a, T>(&'r mut Drain<'a, T>);
+
+                impl<T> Drop for TailGuard<'_, '_, T> {
+                    fn drop(&mut self) {
+                        self.0.join_tail();
+                    }
+                }
+
+                let guard = TailGuard(self);
+                let (idx, end) = (guard.0.idx, guard.0.end);
+                guard.0.idx = end;
+                let (first, second) = guard.0.deque.slot_ranges(idx, end);
+                let ptr = guard.0.deque.buf.buf_ptr();
+                unsafe {
+                    let first = ptr::slice_from_raw_parts_mut(ptr.add(first.start), first.len());
+                    let second = ptr::slice_from_raw_parts_mut(ptr.add(second.start), second.len());
+                    // The second half leaks if a destructor in the first one panics
+                    ptr::drop_in_place(first);
+                    ptr::drop_in_place(second);
+                }
+            }
+        }
+    }
+
+    /// This module provides a growable array named `SmallVector`, which keeps
+    /// up to `N` elements inline and only allocates past that
+    ///
+    /// Functions Implemented:
+    /// * [new](struct.SmallVector.html#method.new) -> `Self`
+    /// * [len](struct.SmallVector.html#method.len) -> `usize`
+    /// * [capacity](struct.SmallVector.html#method.capacity) -> `usize`
+    /// * [is_empty](struct.SmallVector.html#method.is_empty) -> `bool`
+    /// * [spilled](struct.SmallVector.html#method.spilled) -> `bool`
+    /// * [reserve](struct.SmallVector.html#method.reserve) -> `()`
+    /// * [push](struct.SmallVector.html#method.push) -> `()`
+    /// * [pop](struct.SmallVector.html#method.pop) -> `Option<T>`
+    /// * [insert](struct.SmallVector.html#method.insert) -> `()`
+    /// * [remove](struct.SmallVector.html#method.remove) -> `T`
+    /// * [truncate](struct.SmallVector.html#method.truncate) -> `()`
+    /// * [clear](struct.SmallVector.html#method.clear) -> `()`
+    /// * [as_slice](struct.SmallVector.html#method.as_slice) -> `&[T]`
+    /// * [as_mut_slice](struct.SmallVector.html#method.as_mut_slice) -> `&mut [T]`
+    ///
+    /// Like `Vector<T>`, `SmallVector<T, N>` dereferences to `[T]`.
+    pub mod small_vector {
+        use crate::ds::Error;
+        use crate::ds::vector::{self, Vector};
+        use std::fmt::{self, Debug};
+        use std::iter::FusedIterator;
+        use std::mem::{self, ManuallyDrop, MaybeUninit};
+        use std::ops::{Deref, DerefMut};
+        use std::ptr;
+        use std::slice;
+
+        /// Growable array storing up to `N` elements inline, spilling them into
+        /// a `Vector<T>` once it grows past that
+        ///
+        /// A spilled vector stays on the heap, even if it shrinks back under `N`.
+        ///
+        /// # Example
+        /// ```
+        /// # use crate::data_structure::ds::small_vector::SmallVector;
+        /// let mut vector: SmallVector<i32, 2> = SmallVector::new();
+        /// vector.push(1);
+        /// vector.push(2);
+        /// assert!(!vector.spilled());
+        /// vector.push(3);
+        /// assert!(vector.spilled());
+        /// assert_eq!(vector, [1, 2, 3]);
+        /// ```
+        pub struct SmallVector<T, const N: usize> {
+            data: Data<T, N>,
+        }
+
+        enum Data<T, const N: usize> {
+            // Only `buf[..len]` is initialised
+            Inline { buf: [MaybeUninit<T>; N], len: usize },
+            Heap(Vector<T>),
+        }
+
+        impl<T, const N: usize> SmallVector<T, N> {
+            /// Constructs a new, empty `SmallVector<T, N>` without allocating
+            pub fn new() -> Self {
+                SmallVector {
+                    data: Data::Inline {
+                        buf: [const { MaybeUninit::uninit() }; N],
+                        len: 0,
+                    },
+                }
+            }
+
+            /// Returns the number of elements in the vector
+            pub fn len(&self) -> usize {
+                match &self.data {
+                    Data::Inline { len, .. } => *len,
+                    Data::Heap(vec) => vec.len(),
+                }
+            }
+
+            /// Returns the number of elements the vector can hold without
+            /// (re)allocating, which is `N` until it spills
+            pub fn capacity(&self) -> usize {
+                match &self.data {
+                    Data::Inline { .. } => N,
+                    Data::Heap(vec) => vec.capacity(),
+                }
+            }
+
+            /// Returns `true` if the vector contains no elements
+            pub fn is_empty(&self) -> bool {
+                self.len() == 0
+            }
+
+            /// Returns `true` if the elements have been moved to the heap
+            pub fn spilled(&self) -> bool {
+                matches!(self.data, Data::Heap(_))
+            }
+
+            /// Reserves room for at least `additional` more elements, spilling
+            /// right away if they won't fit inline
+            pub fn reserve(&mut self, additional: usize) {
+                match &mut self.data {
+                    Data::Inline { len, .. } if additional <= N - *len => {}
+                    _ => self.heap_mut(additional).reserve(additional),
+                }
+            }
+
+            pub fn push(&mut self, val: T) {
+                match &mut self.data {
+                    Data::Inline { buf, len } if *len < N => {
+                        buf[*len].write(val);
+                        *len += 1;
+                    }
+                    _ => self.heap_mut(1).push(val),
+                }
+            }
+
+            pub fn pop(&mut self) -> Option<T> {
+                match &mut self.data {
+                    Data::Inline { buf, len } => {
+                        if *len == 0 {
+                            return None;
+                        }
+                        *len -= 1;
+                        // SAFETY: `buf[len]` was initialised and is now past the end
+                        Some(unsafe { buf[*len].assume_init_read() })
+                    }
+                    Data::Heap(vec) => vec.pop(),
+                }
+            }
+
+            /// Inserts `val` at `index`, shifting every element after it to the right
+            ///
+            /// # Panics
+            /// Panics if `index > len`
+            pub fn insert(&mut self, index: usize, val: T) {
+                let len = self.len();
+                if index > len {
+                    panic!("{}", Error::IndexOutOfRange { index, len });
+                }
+                match &mut self.data {
+                    Data::Inline { buf, len } if *len < N => unsafe {
+                        let p = buf.as_mut_ptr().add(index);
+                        ptr::copy(p, p.add(1), *len - index);
+                        (*p).write(val);
+                        *len += 1;
+                    },
+                    _ => self.heap_mut(1).insert(index, val),
+                }
+            }
+
+            /// Removes and returns the element at `index`, shifting every element
+            /// after it to the left
+            ///
+            /// # Panics
+            /// Panics if `index >= len`
+            pub fn remove(&mut self, index: usize) -> T {
+                match &mut self.data {
+                    Data::Inline { buf, len } => {
+                        if index >= *len {
+                            panic!("{}", Error::IndexOutOfRange { index, len: *len });
+                        }
+                        *len -= 1;
+                        unsafe {
+                            let p = buf.as_mut_ptr().add(index);
+                            let val = (*p).assume_init_read();
+                            ptr::copy(p.add(1), p, *len - index);
+                            val
+                        }
+                    }
+                    Data::Heap(vec) => vec.remove(index),
+                }
+            }
+
+            /// Shortens the vector to `len` elements, dropping the rest. Does nothing
+            /// if `len` is not less than the current length
+            pub fn truncate(&mut self, new_len: usize) {
+                match &mut self.data {
+                    Data::Inline { buf, len } => {
+                        if new_len >= *len {
+                            return;
+                        }
+                        let tail = ptr::slice_from_raw_parts_mut(
+                            unsafe { buf.as_mut_ptr().add(new_len) } as *mut T,
+                            *len - new_len,
+                        );
+                        // Shrink first so a panicking destructor leaks instead of double dropping
+                        *len = new_len;
+                        unsafe { ptr::drop_in_place(tail) };
+                    }
+                    Data::Heap(vec) => vec.truncate(new_len),
+                }
+            }
+
+            /// Drops every element, keeping the storage
+            pub fn clear(&mut self) {
+                self.truncate(0);
+            }
+
+            /// Returns the elements as a slice
+            pub fn as_slice(&self) -> &[T] {
+                match &self.data {
+                    Data::Inline { buf, len } => unsafe {
+                        slice::from_raw_parts(buf.as_ptr() as *const T, *len)
+                    },
+                    Data::Heap(vec) => vec,
+                }
+            }
+
+            /// Returns the elements as a mutable slice
+            pub fn as_mut_slice(&mut self) -> &mut [T] {
+                match &mut self.data {
+                    Data::Inline { buf, len } => unsafe {
+                        slice::from_raw_parts_mut(buf.as_mut_ptr() as *mut T, *len)
+                    },
+                    Data::Heap(vec) => vec,
+                }
+            }
+
+            /// Returns the heap storage, moving the inline elements into a `Vector<T>`
+            /// with room for `additional` more first if the vector hasn't spilled yet
+            fn heap_mut(&mut self, additional: usize) -> &mut Vector<T> {
+                if let Data::Inline { buf, len } = &mut self.data {
+                    let required = len
+                        .checked_add(additional)
+                        .unwrap_or_else(|| panic!("{}", Error::CapacityOverflow));
+                    let mut vec = Vector::new();
+                    vec.reserve(required);
+                    let count = mem::replace(len, 0);
+                    for slot in &buf[..count] {
+                        // SAFETY: `buf[..count]` was initialised and is no longer counted by `len`
+                        vec.push(unsafe { slot.assume_init_read() });
+                    }
+                    self.data = Data::Heap(vec);
+                }
+                match &mut self.data {
+                    Data::Heap(vec) => vec,
+                    Data::Inline { .. } => unreachable!(),
+                }
+            }
+        }
+
+        impl<T, const N: usize> Drop for SmallVector<T, N> {
+            fn drop(&mut self) {
+                // The heap storage drops its own elements
+                self.clear();
+            }
+        }
+
+        impl<T, const N: usize> Default for SmallVector<T, N> {
+            fn default() -> Self {
+                Self::new()
+            }
+        }
+
+        impl<T, const N: usize> Deref for SmallVector<T, N> {
+            type Target = [T];
+
+            fn deref(&self) -> &[T] {
+                self.as_slice()
+            }
+        }
+
+        impl<T, const N: usize> DerefMut for SmallVector<T, N> {
+            fn deref_mut(&mut self) -> &mut [T] {
+                self.as_mut_slice()
+            }
+        }
+
+        impl<T: Clone, const N: usize> Clone for SmallVector<T, N> {
+            fn clone(&self) -> Self {
+                self.iter().cloned().collect()
+            }
+        }
+
+        impl<T: Debug, const N: usize> Debug for SmallVector<T, N> {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.debug_list().entries(self.iter()).finish()
+            }
+        }
+
+        impl<T: PartialEq, const N: usize, const M: usize> PartialEq<SmallVector<T, M>> for SmallVector<T, N> {
+            fn eq(&self, other: &SmallVector<T, M>) -> bool {
+                self.as_slice() == other.as_slice()
+            }
+        }
+
+        impl<T: Eq, const N: usize> Eq for SmallVector<T, N> {}
+
+        impl<T: PartialEq, const N: usize, const M: usize> PartialEq<[T; M]> for SmallVector<T, N> {
+            fn eq(&self, other: &[T; M]) -> bool {
+                self.as_slice() == other
+            }
+        }
+
+        impl<T: PartialEq, const N: usize> PartialEq<[T]> for SmallVector<T, N> {
+            fn eq(&self, other: &[T]) -> bool {
+                self.as_slice() == other
+            }
+        }
+
+        impl<T, const N: usize> FromIterator<T> for SmallVector<T, N> {
+            fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+                let mut vector = SmallVector::new();
+                vector.extend(iter);
+                vector
+            }
+        }
+
+        impl<T, const N: usize> Extend<T> for SmallVector<T, N> {
+            fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+                let iter = iter.into_iter();
+                self.reserve(iter.size_hint().0);
+                for val in iter {
+                    self.push(val);
+                }
+            }
+        }
+
+        impl<'a, T: Copy + 'a, const N: usize> Extend<&'a T> for SmallVector<T, N> {
+            fn extend<I: IntoIterator<Item = &'a T>>(&mut self, iter: I) {
+                self.extend(iter.into_iter().copied());
+            }
+        }
+
+        impl<T, const N: usize, const M: usize> From<[T; M]> for SmallVector<T, N> {
+            fn from(arr: [T; M]) -> Self {
+                arr.into_iter().collect()
+            }
+        }
+
+        impl<T, const N: usize> IntoIterator for SmallVector<T, N> {
+            type Item = T;
+            type IntoIter = IntoIter<T, N>;
+
+            /// Consumes the vector, yielding its elements front to back
+            fn into_iter(self) -> IntoIter<T, N> {
+                let this = ManuallyDrop::new(self);
+                // SAFETY: `this` is never dropped, so the data is moved out exactly once
+                let data = unsafe { ptr::read(&this.data) };
+                IntoIter {
+                    inner: match data {
+                        Data::Inline { buf, len } => Inner::Inline { buf, start: 0, end: len },
+                        Data::Heap(vec) => Inner::Heap(vec.into_iter()),
+                    },
+                }
+            }
+        }
+
+        impl<'a, T, const N: usize> IntoIterator for &'a SmallVector<T, N> {
+            type Item = &'a T;
+            type IntoIter = slice::Iter<'a, T>;
+
+            fn into_iter(self) -> Self::IntoIter {
+                self.iter()
+            }
+        }
+
+        impl<'a, T, const N: usize> IntoIterator for &'a mut SmallVector<T, N> {
+            type Item = &'a mut T;
+            type IntoIter = slice::IterMut<'a, T>;
+
+            fn into_iter(self) -> Self::IntoIter {
+                self.iter_mut()
+            }
+        }
+
+        /// Owning double-ended iterator over a `SmallVector<T, N>`
+        ///
+        /// # Example
+        /// ```
+        /// # use crate::data_structure::ds::small_vector::SmallVector;
+        /// let vector: SmallVector<_, 4> = SmallVector::from([1, 2, 3]);
+        /// assert_eq!(vector.into_iter().rev().collect::<Vec<_>>(), vec![3, 2, 1]);
+        /// ```
+        pub struct IntoIter<T, const N: usize> {
+            inner: Inner<T, N>,
+        }
+
+        enum Inner<T, const N: usize> {
+            // Only `buf[start..end]` is initialised and owned by the iterator
+            Inline { buf: [MaybeUninit<T>; N], start: usize, end: usize },
+            Heap(vector::IntoIter<T>),
+        }
+
+        impl<T, const N: usize> Iterator for IntoIter<T, N> {
+            type Item = T;
+
+            fn next(&mut self) -> Option<T> {
+                match &mut self.inner {
+                    Inner::Inline { buf, start, end } => {
+                        if start == end {
+                            return None;
+                        }
+                        *start += 1;
+                        // SAFETY: `buf[start - 1]` was initialised and is no longer owned
+                        Some(unsafe { buf[*start - 1].assume_init_read() })
+                    }
+                    Inner::Heap(iter) => iter.next(),
+                }
+            }
+
+            fn size_hint(&self) -> (usize, Option<usize>) {
+                match &self.inner {
+                    Inner::Inline { start, end, .. } => (end - start, Some(end - start)),
+                    Inner::Heap(iter) => iter.size_hint(),
+                }
+            }
+        }
+
+        impl<T, const N: usize> DoubleEndedIterator for IntoIter<T, N> {
+            fn next_back(&mut self) -> Option<T> {
+                match &mut self.inner {
+                    Inner::Inline { buf, start, end } => {
+                        if start == end {
+                            return None;
+                        }
+                        *end -= 1;
+                        // SAFETY: `buf[end]` was initialised and is no longer owned
+                        Some(unsafe { buf[*end].assume_init_read() })
+                    }
+                    Inner::Heap(iter) => iter.next_back(),
+                }
+            }
+        }
+
+        impl<T, const N: usize> ExactSizeIterator for IntoIter<T, N> {}
+
+        impl<T, const N: usize> FusedIterator for IntoIter<T, N> {}
+
+        impl<T, const N: usize> Drop for IntoIter<T, N> {
+            fn drop(&mut self) {
+                if let Inner::Inline { buf, start, end } = &mut self.inner {
+                    let remaining = ptr::slice_from_raw_parts_mut(
+                        unsafe { buf.as_mut_ptr().add(*start) } as *mut T,
+                        *end - *start,
+                    );
+                    *start = *end;
+                    unsafe { ptr::drop_in_place(remaining) };
+                }
+            }
+        }
+    }
+}
+
+/// This module provides an arithmetic expression evaluator built on `ds::stack::Stack`
+///
+/// Infix input is tokenized, converted to Reverse Polish Notation with the
+/// shunting-yard algorithm, and evaluated with a stack of operands.
+/// `+ - * / %` are left associative, `^` is right associative and binds tighter
+/// than unary minus, so `-2^2` is `-4`. Variables are looked up at evaluation time.
+///
+/// Functions Implemented:
+/// * [tokenize](fn.tokenize.html) -> `Result<Vec<Token>, Error>`
+/// * [to_rpn](fn.to_rpn.html) -> `Result<Vec<Token>, Error>`
+/// * [eval_rpn](fn.eval_rpn.html) -> `Result<f64, Error>`
+/// * [evaluate](fn.evaluate.html) -> `Result<f64, Error>`
+///
+/// # Example
+/// ```
+/// # use crate::data_structure::expr::Expr;
+/// # use std::collections::HashMap;
+/// let expr = Expr::parse("2 * (x + 1) ^ 2").unwrap();
+/// assert_eq!(expr.to_string(), "2 x 1 + 2 ^ *");
+/// assert_eq!(expr.eval(&HashMap::from([("x".to_string(), 2.0)])), Ok(18.0));
+/// ```
+pub mod expr {
+    use crate::ds::stack::Stack;
+    use std::collections::HashMap;
+    use std::fmt;
+    use std::str::FromStr;
+
+    /// Arithmetic operators, `Neg` being unary minus
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub enum Operator {
+        Add,
+        Sub,
+        Mul,
+        Div,
+        Rem,
+        Pow,
+        Neg,
+    }
+
+    impl Operator {
+        /// Returns how tightly the operator binds, higher binding tighter
+        pub fn precedence(self) -> u8 {
+            match self {
+                Operator::Add | Operator::Sub => 1,
+                Operator::Mul | Operator::Div | Operator::Rem => 2,
+                Operator::Neg => 3,
+                Operator::Pow => 4,
+            }
+        }
+
+        /// Returns `true` if a chain of the operator groups from the right
+        pub fn is_right_associative(self) -> bool {
+            matches!(self, Operator::Pow | Operator::Neg)
+        }
+
+        fn symbol(self) -> &'static str {
+            match self {
+                Operator::Add => "+",
+                Operator::Sub => "-",
+                Operator::Mul => "*",
+                Operator::Div => "/",
+                Operator::Rem => "%",
+                Operator::Pow => "^",
+                Operator::Neg => "neg",
+            }
+        }
+    }
+
+    /// What a token is
+    #[derive(Debug, Clone, PartialEq)]
+    pub enum TokenKind {
+        Number(f64),
+        Variable(String),
+        Operator(Operator),
+        LeftParen,
+        RightParen,
+    }
+
+    /// A token of an expression, with the 1-based column it starts at
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct Token {
+        pub kind: TokenKind,
+        pub column: usize,
+    }
+
+    impl fmt::Display for Token {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            match &self.kind {
+                TokenKind::Number(val) => write!(f, "{}", val),
+                TokenKind::Variable(name) => write!(f, "{}", name),
+                TokenKind::Operator(op) => write!(f, "{}", op.symbol()),
+                TokenKind::LeftParen => write!(f, "("),
+                TokenKind::RightParen => write!(f, ")"),
+            }
+        }
+    }
+
+    /// Why an expression couldn't be parsed or evaluated
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    pub enum ErrorKind {
+        /// A character that can't start any token
+        UnexpectedChar(char),
+        /// A run of digits and dots that isn't a number, like `1.2.3`
+        InvalidNumber(String),
+        /// An operand or `(` where an operator or `)` should be
+        UnexpectedToken,
+        /// An operator or `)` where an operand should be
+        MissingOperand,
+        /// The input ended where an operand should be
+        UnexpectedEnd,
+        /// A `(` which is never closed
+        UnclosedParen,
+        /// A `)` which doesn't close anything
+        UnmatchedParen,
+        /// A variable which has no value
+        UnknownVariable(String),
+        /// A division or remainder by zero
+        DivisionByZero,
+    }
+
+    impl fmt::Display for ErrorKind {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            match self {
+                ErrorKind::UnexpectedChar(c) => write!(f, "Unexpected character '{}'", c),
+                ErrorKind::InvalidNumber(num) => write!(f, "Invalid number '{}'", num),
+                ErrorKind::UnexpectedToken => write!(f, "Expected an operator"),
+                ErrorKind::MissingOperand => write!(f, "Expected an operand"),
+                ErrorKind::UnexpectedEnd => write!(f, "Unexpected end of expression"),
+                ErrorKind::UnclosedParen => write!(f, "Unclosed parenthesis"),
+                ErrorKind::UnmatchedParen => write!(f, "Unmatched closing parenthesis"),
+                ErrorKind::UnknownVariable(name) => write!(f, "Unknown variable '{}'", name),
+                ErrorKind::DivisionByZero => write!(f, "Division by zero"),
+            }
+        }
+    }
+
+    /// Error returned when parsing or evaluating an expression, pointing at the
+    /// 1-based column it was found at
+    ///
+    /// # Example
+    /// ```
+    /// # use crate::data_structure::expr::{evaluate, ErrorKind};
+    /// # use std::collections::HashMap;
+    /// let err = evaluate("1 + * 2", &HashMap::new()).unwrap_err();
+    /// assert_eq!(err.kind, ErrorKind::MissingOperand);
+    /// assert_eq!(err.column, 5);
+    /// assert_eq!(err.to_string(), "Expected an operand at column 5");
+    /// ```
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    pub struct Error {
+        pub kind: ErrorKind,
+        pub column: usize,
+    }
+
+    impl Error {
+        fn new(kind: ErrorKind, column: usize) -> Self {
+            Error { kind, column }
+        }
+    }
+
+    impl fmt::Display for Error {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            write!(f, "{} at column {}", self.kind, self.column)
+        }
+    }
+
+    impl std::error::Error for Error {}
+
+    /// Splits infix `input` into tokens
+    ///
+    /// Numbers are decimal, like `3` or `0.25`, and variables are made of
+    /// letters, digits and `_`, not starting with a digit.
+    /// `-` is always tokenized as `Operator::Sub`; [to_rpn] tells unary minus apart.
+    ///
+    /// # Example
+    /// ```
+    /// # use crate::data_structure::expr::{tokenize, ErrorKind};
+    /// let tokens = tokenize("rate * 1.5").unwrap();
+    /// assert_eq!(tokens.len(), 3);
+    /// assert_eq!(tokens[2].column, 8);
+    /// assert_eq!(tokenize("2 $ 3").unwrap_err().kind, ErrorKind::UnexpectedChar('$'));
+    /// ```
+    pub fn tokenize(input: &str) -> Result<Vec<Token>, Error> {
+        let chars: Vec<char> = input.chars().collect();
+        let mut tokens = Vec::new();
+        let mut i = 0;
+        while i < chars.len() {
+            let column = i + 1;
+            let c = chars[i];
+            let kind = match c {
+                c if c.is_whitespace() => {
+                    i += 1;
+                    continue;
+                }
+                '0'..='9' | '.' => {
+                    let start = i;
+                    while i < chars.len() && (chars[i].is_ascii_digit() || chars[i] == '.') {
+                        i += 1;
+                    }
+                    let text: String = chars[start..i].iter().collect();
+                    let val = text
+                        .parse()
+                        .map_err(|_| Error::new(ErrorKind::InvalidNumber(text.clone()), column))?;
+                    tokens.push(Token { kind: TokenKind::Number(val), column });
+                    continue;
+                }
+                c if c.is_alphabetic() || c == '_' => {
+                    let start = i;
+                    while i < chars.len() && (chars[i].is_alphanumeric() || chars[i] == '_') {
+                        i += 1;
+                    }
+                    let name = chars[start..i].iter().collect();
+                    tokens.push(Token { kind: TokenKind::Variable(name), column });
+                    continue;
+                }
+                '+' => TokenKind::Operator(Operator::Add),
+                '-' => TokenKind::Operator(Operator::Sub),
+                '*' => TokenKind::Operator(Operator::Mul),
+                '/' => TokenKind::Operator(Operator::Div),
+                '%' => TokenKind::Operator(Operator::Rem),
+                '^' => TokenKind::Operator(Operator::Pow),
+                '(' => TokenKind::LeftParen,
+                ')' => TokenKind::RightParen,
+                c => return Err(Error::new(ErrorKind::UnexpectedChar(c), column)),
+            };
+            tokens.push(Token { kind, column });
+            i += 1;
+        }
+        Ok(tokens)
+    }
+
+    /// Converts infix `tokens` to Reverse Polish Notation with the shunting-yard
+    /// algorithm, checking that the expression is well formed
+    ///
+    /// A `-` where an operand is expected becomes `Operator::Neg`. Parentheses
+    /// don't appear in the output.
+    ///
+    /// # Example
+    /// ```
+    /// # use crate::data_structure::expr::{to_rpn, tokenize};
+    /// let rpn = to_rpn(&tokenize("-(1 - 2) * 3").unwrap()).unwrap();
+    /// let rpn: Vec<String> = rpn.iter().map(|token| token.to_string()).collect();
+    /// assert_eq!(rpn, ["1", "2", "-", "neg", "3", "*"]);
+    /// ```
+    pub fn to_rpn(tokens: &[Token]) -> Result<Vec<Token>, Error> {
+        let mut output = Vec::with_capacity(tokens.len());
+        let mut ops: Stack<Token> = Stack::new();
+        let mut expect_operand = true;
+
+        for token in tokens {
+            match &token.kind {
+                TokenKind::Number(_) | TokenKind::Variable(_) => {
+                    if !expect_operand {
+                        return Err(Error::new(ErrorKind::UnexpectedToken, token.column));
+                    }
+                    output.push(token.clone());
+                    expect_operand = false;
+                }
+                TokenKind::LeftParen => {
+                    if !expect_operand {
+                        return Err(Error::new(ErrorKind::UnexpectedToken, token.column));
+                    }
+                    ops.push(token.clone());
+                }
+                TokenKind::RightParen => {
+                    if expect_operand {
+                        return Err(Error::new(ErrorKind::MissingOperand, token.column));
+                    }
+                    loop {
+                        match ops.pop() {
+                            Some(Token { kind: TokenKind::LeftParen, .. }) => break,
+                            Some(op) => output.push(op),
+                            None => return Err(Error::new(ErrorKind::UnmatchedParen, token.column)),
+                        }
+                    }
+                }
+                TokenKind::Operator(Operator::Sub | Operator::Neg) if expect_operand => {
+                    // Prefix operators apply to what follows, so nothing is popped for them
+                    ops.push(Token {
+                        kind: TokenKind::Operator(Operator::Neg),
+                        column: token.column,
+                    });
+                }
+                TokenKind::Operator(_) if expect_operand => {
+                    return Err(Error::new(ErrorKind::MissingOperand, token.column));
+                }
+                &TokenKind::Operator(op) => {
+                    while let Some(Token { kind: TokenKind::Operator(top), .. }) = ops.peek() {
+                        let pops = top.precedence() > op.precedence()
+                            || (top.precedence() == op.precedence() && !op.is_right_associative());
+                        if !pops {
+                            break;
+                        }
+                        output.extend(ops.pop());
+                    }
+                    ops.push(token.clone());
+                    expect_operand = true;
+                }
+            }
+        }
+
+        if expect_operand {
+            // Only a single character operator or `(` can leave an operand expected
+            let column = tokens.last().map_or(1, |token| token.column + 1);
+            return Err(Error::new(ErrorKind::UnexpectedEnd, column));
+        }
+        while let Some(op) = ops.pop() {
+            if op.kind == TokenKind::LeftParen {
+                return Err(Error::new(ErrorKind::UnclosedParen, op.column));
+            }
+            output.push(op);
+        }
+        Ok(output)
+    }
+
+    /// Evaluates `rpn` with a stack of operands, looking variables up with `lookup`
+    ///
+    /// # Example
+    /// ```
+    /// # use crate::data_structure::expr::{eval_rpn, to_rpn, tokenize};
+    /// let rpn = to_rpn(&tokenize("2 ^ 3 ^ 2").unwrap()).unwrap();
+    /// assert_eq!(eval_rpn(&rpn, |_| None), Ok(512.0));
+    /// ```
+    pub fn eval_rpn<F>(rpn: &[Token], mut lookup: F) -> Result<f64, Error>
+    where
+        F: FnMut(&str) -> Option<f64>,
+    {
+        let mut operands: Stack<f64> = Stack::new();
+        for token in rpn {
+            let missing = || Error::new(ErrorKind::MissingOperand, token.column);
+            let val = match &token.kind {
+                &TokenKind::Number(val) => val,
+                TokenKind::Variable(name) => lookup(name)
+                    .ok_or_else(|| Error::new(ErrorKind::UnknownVariable(name.clone()), token.column))?,
+                TokenKind::Operator(Operator::Neg) => -operands.pop().ok_or_else(missing)?,
+                &TokenKind::Operator(op) => {
+                    let rhs = operands.pop().ok_or_else(missing)?;
+                    let lhs = operands.pop().ok_or_else(missing)?;
+                    match op {
+                        Operator::Add => lhs + rhs,
+                        Operator::Sub => lhs - rhs,
+                        Operator::Mul => lhs * rhs,
+                        Operator::Div | Operator::Rem if rhs == 0.0 => {
+                            return Err(Error::new(ErrorKind::DivisionByZero, token.column));
+                        }
+                        Operator::Div => lhs / rhs,
+                        Operator::Rem => lhs % rhs,
+                        Operator::Pow => lhs.powf(rhs),
+                        Operator::Neg => unreachable!(),
+                    }
+                }
+                TokenKind::LeftParen | TokenKind::RightParen => {
+                    return Err(Error::new(ErrorKind::UnexpectedToken, token.column));
+                }
+            };
+            operands.push(val);
+        }
+
+        let result = operands.pop();
+        match (result, operands.peek()) {
+            (Some(result), None) => Ok(result),
+            (None, _) => Err(Error::new(ErrorKind::UnexpectedEnd, 1)),
+            (Some(_), Some(_)) => {
+                let column = rpn.last().map_or(1, |token| token.column);
+                Err(Error::new(ErrorKind::UnexpectedToken, column))
+            }
+        }
+    }
+
+    /// Parses and evaluates infix `input` in one go
+    ///
+    /// # Example
+    /// ```
+    /// # use crate::data_structure::expr::evaluate;
+    /// # use std::collections::HashMap;
+    /// let vars = HashMap::from([("width".to_string(), 3.0), ("height".to_string(), 4.0)]);
+    /// assert_eq!(evaluate("(width ^ 2 + height ^ 2) ^ 0.5", &vars), Ok(5.0));
+    /// ```
+    pub fn evaluate(input: &str, vars: &HashMap<String, f64>) -> Result<f64, Error> {
+        Expr::parse(input)?.eval(vars)
+    }
+
+    /// A parsed expression, kept in Reverse Polish Notation so it can be
+    /// evaluated repeatedly with different variables
+    ///
+    /// Displays as its RPN tokens separated by spaces.
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct Expr {
+        rpn: Vec<Token>,
+    }
+
+    impl Expr {
+        /// Parses infix `input`
+        pub fn parse(input: &str) -> Result<Self, Error> {
+            Ok(Expr {
+                rpn: to_rpn(&tokenize(input)?)?,
+            })
+        }
+
+        /// Returns the expression in Reverse Polish Notation
+        pub fn rpn(&self) -> &[Token] {
+            &self.rpn
+        }
+
+        /// Evaluates the expression with the variables in `vars`
+        pub fn eval(&self, vars: &HashMap<String, f64>) -> Result<f64, Error> {
+            eval_rpn(&self.rpn, |name| vars.get(name).copied())
+        }
+
+        /// Evaluates the expression, looking variables up with `lookup`
+        pub fn eval_with<F>(&self, lookup: F) -> Result<f64, Error>
+        where
+            F: FnMut(&str) -> Option<f64>,
+        {
+            eval_rpn(&self.rpn, lookup)
+        }
+    }
+
+    impl FromStr for Expr {
+        type Err = Error;
+
+        fn from_str(s: &str) -> Result<Self, Self::Err> {
+            Expr::parse(s)
+        }
+    }
+
+    impl fmt::Display for Expr {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            for (i, token) in self.rpn.iter().enumerate() {
+                if i > 0 {
+                    write!(f, " ")?;
+                }
+                write!(f, "{}", token)?;
+            }
+            Ok(())
+        }
+    }
+}
+
+/// This module provides a validator for balanced delimiters built on `ds::stack::Stack`
+///
+/// Brackets, quoted strings with escapes, user defined open/close pairs such as
+/// `begin`/`end`, and optionally XML-like `<tag>`/`</tag>` pairs are checked.
+/// Validation doesn't stop at the first problem: every [Mismatch] is reported
+/// with its line, column and the closer that was expected.
+///
+/// Functions Implemented:
+/// * [new](struct.Validator.html#method.new) -> `Validator`
+/// * [validate](struct.Validator.html#method.validate) -> `Result<(), Vec<Mismatch>>`
+///
+/// # Example
+/// ```
+/// # use crate::data_structure::validator::{MismatchKind, Validator};
+/// let errors = Validator::new().validate("f(a[0)\n{ \"}\" ").unwrap_err();
+/// assert_eq!(errors.len(), 2);
+/// assert_eq!(errors[0].to_string(), "Expected `]` but found `)` at line 1, column 6");
+/// assert_eq!(errors[1].to_string(), "`{` is never closed, expected `}` at line 2, column 1");
+/// ```
+pub mod validator {
+    use crate::ds::stack::Stack;
+    use std::fmt;
+
+    /// What went wrong at a [Mismatch]
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    pub enum MismatchKind {
+        /// `found` doesn't close the innermost open delimiter, whose closer is
+        /// `expected`, or there is no open delimiter at all
+        Unexpected { found: String, expected: Option<String> },
+        /// The delimiter or quote `open` is never closed by `expected`
+        Unclosed { open: String, expected: String },
+    }
+
+    /// A delimiter which isn't balanced, found at a 1-based line and column
+    ///
+    /// `Unexpected` mismatches point at the closer that was found, `Unclosed`
+    /// ones at the opener that was never closed.
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    pub struct Mismatch {
+        pub kind: MismatchKind,
+        pub line: usize,
+        pub column: usize,
+    }
+
+    impl Mismatch {
+        /// Returns the closer that was expected, if any
+        pub fn expected(&self) -> Option<&str> {
+            match &self.kind {
+                MismatchKind::Unexpected { expected, .. } => expected.as_deref(),
+                MismatchKind::Unclosed { expected, .. } => Some(expected),
+            }
+        }
+    }
+
+    impl fmt::Display for Mismatch {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            match &self.kind {
+                MismatchKind::Unexpected { found, expected: Some(expected) } => {
+                    write!(f, "Expected `{}` but found `{}`", expected, found)?
+                }
+                MismatchKind::Unexpected { found, expected: None } => write!(f, "Unexpected `{}`", found)?,
+                MismatchKind::Unclosed { open, expected } => {
+                    write!(f, "`{}` is never closed, expected `{}`", open, expected)?
+                }
+            }
+            write!(f, " at line {}, column {}", self.line, self.column)
+        }
+    }
+
+    impl std::error::Error for Mismatch {}
+
+    /// Which delimiters a [Validator] checks
+    ///
+    /// Quotes are matched before anything else, and nothing inside a quoted string
+    /// is checked. Where several pairs match at the same place the longest one is used.
+    ///
+    /// # Example
+    /// ```
+    /// # use crate::data_structure::validator::Validator;
+    /// let mut validator = Validator {
+    ///     quotes: vec!['"'],
+    ///     tags: true,
+    ///     ..Validator::new()
+    /// };
+    /// validator.pairs.push(("begin".to_string(), "end".to_string()));
+    /// assert!(validator.validate("<p class=\"x\">don't <br/> begin (1) end</p>").is_ok());
+    ///
+    /// let errors = validator.validate("<ul>\n  <li>item</ul>").unwrap_err();
+    /// assert_eq!(errors[0].expected(), Some("</li>"));
+    /// assert_eq!((errors[0].line, errors[0].column), (2, 11));
+    /// ```
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    pub struct Validator {
+        /// Open and close tokens which have to be balanced
+        pub pairs: Vec<(String, String)>,
+        /// Characters which open and close a quoted string
+        pub quotes: Vec<char>,
+        /// Character which escapes the next one inside a quoted string
+        pub escape: Option<char>,
+        /// Whether `<name ...>` has to be closed by `</name>`, `<name/>` closing itself
+        pub tags: bool,
+    }
+
+    impl Validator {
+        /// Constructs a `Validator` checking `()`, `[]` and `{}`, and `"` and `'`
+        /// quoted strings escaped with `\`
+        pub fn new() -> Self {
+            Validator {
+                pairs: [("(", ")"), ("[", "]"), ("{", "}")]
+                    .iter()
+                    .map(|&(open, close)| (open.to_string(), close.to_string()))
+                    .collect(),
+                quotes: vec!['"', '\''],
+                escape: Some('\\'),
+                tags: false,
+            }
+        }
+
+        /// Checks that every delimiter in `input` is balanced, returning every
+        /// mismatch ordered by position otherwise
+        pub fn validate(&self, input: &str) -> Result<(), Vec<Mismatch>> {
+            let mut scan = Scan {
+                stack: Stack::new(),
+                errors: Vec::new(),
+            };
+            let mut pos = Position { offset: 0, line: 1, column: 1 };
+
+            while let Some(c) = input[pos.offset..].chars().next() {
+                let rest = &input[pos.offset..];
+                let start = pos;
+
+                if self.quotes.contains(&c) {
+                    pos.advance(c);
+                    if !self.skip_quoted(input, &mut pos, c) {
+                        scan.errors.push(Mismatch {
+                            kind: MismatchKind::Unclosed {
+                                open: c.to_string(),
+                                expected: c.to_string(),
+                            },
+                            line: start.line,
+                            column: start.column,
+                        });
+                    }
+                    continue;
+                }
+
+                if self.tags {
+                    if let Some((tag, len)) = parse_tag(rest) {
+                        match tag {
+                            Tag::Open(name) => scan.open(rest[..len].to_string(), format!("</{}>", name), start),
+                            Tag::Close(name) => scan.close(format!("</{}>", name), start),
+                            Tag::SelfClosing => {}
+                        }
+                        pos.advance_str(&rest[..len]);
+                        continue;
+                    }
+                }
+
+                // The innermost closer wins over anything else, so pairs whose
+                // open and close tokens are the same can be nested in other pairs
+                let expected = scan.stack.peek().map(|open| open.close.as_str());
+                if let Some(close) = expected.filter(|close| !close.is_empty() && rest.starts_with(close)) {
+                    let close = close.to_string();
+                    scan.close(close.clone(), start);
+                    pos.advance_str(&close);
+                    continue;
+                }
+
+                let mut longest: Option<(&str, Option<&str>)> = None;
+                for (open, close) in &self.pairs {
+                    for (token, closes) in [(open, Some(close)), (close, None)] {
+                        let longer = longest.is_none_or(|(best, _)| token.len() > best.len());
+                        if !token.is_empty() && longer && rest.starts_with(token.as_str()) {
+                            longest = Some((token, closes.map(String::as_str)));
+                        }
+                    }
+                }
+                match longest {
+                    Some((open, Some(close))) => {
+                        scan.open(open.to_string(), close.to_string(), start);
+                        pos.advance_str(open);
+                    }
+                    Some((close, None)) => {
+                        scan.close(close.to_string(), start);
+                        pos.advance_str(close);
+                    }
+                    None => pos.advance(c),
+                }
+            }
+
+            while let Some(open) = scan.stack.pop() {
+                scan.errors.push(open.unclosed());
+            }
+            if scan.errors.is_empty() {
+                Ok(())
+            } else {
+                scan.errors.sort_by_key(|err| (err.line, err.column));
+                Err(scan.errors)
+            }
+        }
+
+        /// Moves `pos` past the string closed by `quote`, returning `false` if the
+        /// input ends first
+        fn skip_quoted(&self, input: &str, pos: &mut Position, quote: char) -> bool {
+            let mut chars = input[pos.offset..].chars();
+            while let Some(c) = chars.next() {
+                pos.advance(c);
+                if Some(c) == self.escape {
+                    if let Some(escaped) = chars.next() {
+                        pos.advance(escaped);
+                    }
+                } else if c == quote {
+                    return true;
+                }
+            }
+            false
+        }
+    }
+
+    impl Default for Validator {
+        fn default() -> Self {
+            Self::new()
+        }
+    }
+
+    #[derive(Debug, Clone, Copy)]
+    struct Position {
+        offset: usize,
+        line: usize,
+        column: usize,
+    }
+
+    impl Position {
+        fn advance(&mut self, c: char) {
+            self.offset += c.len_utf8();
+            if c == '\n' {
+                self.line += 1;
+                self.column = 1;
+            } else {
+                self.column += 1;
+            }
+        }
+
+        fn advance_str(&mut self, s: &str) {
+            s.chars().for_each(|c| self.advance(c));
+        }
+    }
+
+    /// A delimiter waiting for its closer
+    struct Open {
+        open: String,
+        close: String,
+        line: usize,
+        column: usize,
+    }
+
+    impl Open {
+        fn unclosed(self) -> Mismatch {
+            Mismatch {
+                kind: MismatchKind::Unclosed {
+                    open: self.open,
+                    expected: self.close,
+                },
+                line: self.line,
+                column: self.column,
+            }
+        }
+    }
+
+    struct Scan {
+        stack: Stack<Open>,
+        errors: Vec<Mismatch>,
+    }
+
+    impl Scan {
+        fn open(&mut self, open: String, close: String, at: Position) {
+            self.stack.push(Open {
+                open,
+                close,
+                line: at.line,
+                column: at.column,
+            });
+        }
+
+        /// Closes the innermost delimiter closed by `found`
+        ///
+        /// Delimiters opened inside it are reported: the innermost one as expecting
+        /// its closer here, the others as never closed. A closer which matches
+        /// nothing open is reported and otherwise ignored.
+        fn close(&mut self, found: String, at: Position) {
+            let depth = self.stack.iter().position(|open| open.close == found);
+            if depth != Some(0) {
+                self.errors.push(Mismatch {
+                    kind: MismatchKind::Unexpected {
+                        found,
+                        expected: self.stack.peek().map(|open| open.close.clone()),
+                    },
+                    line: at.line,
+                    column: at.column,
+                });
+            }
+            if let Some(depth) = depth {
+                if depth > 0 {
+                    self.stack.pop();
+                }
+                for _ in 1..depth {
+                    let open = self.stack.pop().unwrap();
+                    self.errors.push(open.unclosed());
+                }
+                self.stack.pop();
+            }
+        }
+    }
+
+    enum Tag<'a> {
+        Open(&'a str),
+        Close(&'a str),
+        SelfClosing,
+    }
+
+    /// Parses the tag `rest` starts with, returning it and its length in bytes
+    ///
+    /// Anything that doesn't look like a tag, such as `a < b`, isn't one.
+    fn parse_tag(rest: &str) -> Option<(Tag<'_>, usize)> {
+        // A tag can't contain `<`, so the search for its end stops at the next
+        // one, which keeps validating input full of bare `<` linear
+        let after = rest.strip_prefix('<')?;
+        let end = after.find(['<', '>'])?;
+        if !after[end..].starts_with('>') {
+            return None;
+        }
+        let body = &after[..end];
+        let (closing, body) = match body.strip_prefix('/') {
+            Some(body) => (true, body),
+            None => (false, body),
+        };
+
+        let name_len = body
+            .find(|c: char| !(c.is_alphanumeric() || matches!(c, '-' | '_' | ':' | '.')))
+            .unwrap_or(body.len());
+        let (name, attrs) = body.split_at(name_len);
+        if !name.starts_with(char::is_alphabetic) {
+            return None;
+        }
+
+        let tag = if closing {
+            if !attrs.trim().is_empty() {
+                return None;
+            }
+            Tag::Close(name)
+        } else if attrs.ends_with('/') {
+            Tag::SelfClosing
+        } else if attrs.is_empty() || attrs.starts_with(char::is_whitespace) {
+            Tag::Open(name)
+        } else {
+            return None;
+        };
+        Some((tag, end + 2))
+    }
+}
+
+/// This module provides an undo/redo history built on two `ds::stack::Stack`s
+///
+/// Each step of the history is an entry of one or more [Command]s: applying a
+/// command pushes an entry on the undo stack and clears the redo stack, undoing
+/// moves the top entry to the redo stack, and redoing moves it back.
+///
+/// Functions Implemented:
+/// * [new](struct.History.html#method.new) -> `History<C>`
+/// * [with_limits](struct.History.html#method.with_limits) -> `History<C>`
+/// * [apply](struct.History.html#method.apply) -> `()`
+/// * [undo](struct.History.html#method.undo) -> `bool`
+/// * [redo](struct.History.html#method.redo) -> `bool`
+/// * [begin](struct.History.html#method.begin) -> `()`
+/// * [commit](struct.History.html#method.commit) -> `()`
+/// * [seal](struct.History.html#method.seal) -> `()`
+/// * [mark_saved](struct.History.html#method.mark_saved) -> `()`
+/// * [is_dirty](struct.History.html#method.is_dirty) -> `bool`
+/// * [clear](struct.History.html#method.clear) -> `()`
+///
+/// # Example
+/// ```
+/// # use crate::data_structure::history::{Command, History};
+/// struct Insert {
+///     at: usize,
+///     text: String,
+/// }
+///
+/// impl Command for Insert {
+///     type Target = String;
+///
+///     fn apply(&mut self, doc: &mut String) {
+///         doc.insert_str(self.at, &self.text);
+///     }
+///
+///     fn undo(&mut self, doc: &mut String) {
+///         doc.replace_range(self.at..self.at + self.text.len(), "");
+///     }
+///
+///     fn merge(&mut self, next: &Self) -> bool {
+///         let typed_after = next.at == self.at + self.text.len();
+///         if typed_after {
+///             self.text.push_str(&next.text);
+///         }
+///         typed_after
+///     }
+/// }
+///
+/// let mut doc = String::new();
+/// let mut history = History::new();
+/// history.apply(&mut doc, Insert { at: 0, text: "he".to_string() });
+/// history.apply(&mut doc, Insert { at: 2, text: "llo".to_string() });
+/// history.seal();
+/// history.apply(&mut doc, Insert { at: 5, text: "!".to_string() });
+/// assert_eq!(history.undo_len(), 2);
+///
+/// history.undo(&mut doc);
+/// assert_eq!(doc, "hello");
+/// history.undo(&mut doc);
+/// assert_eq!(doc, "");
+/// history.redo(&mut doc);
+/// assert_eq!(doc, "hello");
+/// ```
+pub mod history {
+    use crate::ds::doubly_linked_list::DoublyLinkedList;
+    use crate::ds::stack::Stack;
+    use crate::ds::vector::Vector;
+    use std::mem;
+
+    /// A reversible change to a `Target`
+    pub trait Command {
+        /// What the command changes, e.g. a text buffer
+        type Target;
+
+        /// Applies the change to `target`
+        fn apply(&mut self, target: &mut Self::Target);
+
+        /// Reverts the change to `target`, which is in the state `apply` left it in
+        fn undo(&mut self, target: &mut Self::Target);
+
+        /// Absorbs `next`, which was just applied after `self`, so that undoing
+        /// `self` reverts both, returning whether it did
+        ///
+        /// Never merges by default.
+        fn merge(&mut self, next: &Self) -> bool {
+            let _ = next;
+            false
+        }
+
+        /// Returns roughly how many bytes the command keeps alive, counted
+        /// against the memory limit of the history
+        fn size(&self) -> usize {
+            mem::size_of_val(self)
+        }
+    }
+
+    /// One step of the history
+    #[derive(Debug, Clone)]
+    struct Entry<C> {
+        commands: Vector<C>,
+        size: usize,
+    }
+
+    impl<C: Command> Entry<C> {
+        fn new() -> Self {
+            Entry {
+                commands: Vector::new(),
+                size: 0,
+            }
+        }
+
+        /// Merges `cmd` into the last command, returning whether it did
+        fn merge(&mut self, cmd: &C) -> bool {
+            let Some(last) = self.commands.last_mut() else {
+                return false;
+            };
+            let before = last.size();
+            let merged = last.merge(cmd);
+            if merged {
+                self.size = self.size - before + last.size();
+            }
+            merged
+        }
+
+        /// Merges `cmd` into the last command if `coalesce` is set, or adds it after it
+        fn push(&mut self, cmd: C, coalesce: bool) {
+            if coalesce && self.merge(&cmd) {
+                return;
+            }
+            self.size += cmd.size();
+            self.commands.push(cmd);
+        }
+
+        fn undo(&mut self, target: &mut C::Target) {
+            self.commands.iter_mut().rev().for_each(|cmd| cmd.undo(target));
+        }
+
+        fn redo(&mut self, target: &mut C::Target) {
+            self.commands.iter_mut().for_each(|cmd| cmd.apply(target));
+        }
+    }
+
+    /// An undo/redo history of [Command]s
+    ///
+    /// Consecutive commands are coalesced through [Command::merge] until the history
+    /// is sealed, which happens on [undo](History::undo), [redo](History::redo),
+    /// [mark_saved](History::mark_saved), [commit](History::commit) and
+    /// [seal](History::seal). Once the undo stack is deeper than the depth limit,
+    /// or its commands are bigger than the memory limit, the oldest entries are
+    /// evicted.
+    #[derive(Debug)]
+    pub struct History<C: Command> {
+        /// Oldest entry at the front, so evicting it is `O(1)`
+        undo: Stack<Entry<C>, DoublyLinkedList<Entry<C>>>,
+        redo: Stack<Entry<C>>,
+        /// Open transaction and how many times it was begun
+        pending: Option<(Entry<C>, usize)>,
+        coalesce: bool,
+        /// Size of the entries on the undo stack
+        size: usize,
+        max_depth: usize,
+        max_size: usize,
+        /// Number of entries evicted so far, so that positions stay comparable
+        evicted: usize,
+        saved: Option<usize>,
+    }
+
+    impl<C: Command> History<C> {
+        /// Constructs an unlimited, clean `History`
+        pub fn new() -> Self {
+            Self::with_limits(usize::MAX, usize::MAX)
+        }
+
+        /// Constructs a clean `History` keeping at most `max_depth` undo entries
+        /// whose commands are at most `max_size` bytes in total
+        ///
+        /// # Example
+        /// ```
+        /// # use crate::data_structure::history::{Command, History};
+        /// struct Add(i32);
+        ///
+        /// impl Command for Add {
+        ///     type Target = i32;
+        ///
+        ///     fn apply(&mut self, total: &mut i32) {
+        ///         *total += self.0;
+        ///     }
+        ///
+        ///     fn undo(&mut self, total: &mut i32) {
+        ///         *total -= self.0;
+        ///     }
+        /// }
+        ///
+        /// let mut total = 0;
+        /// let mut history = History::with_limits(2, usize::MAX);
+        /// for n in 1..=3 {
+        ///     history.apply(&mut total, Add(n));
+        /// }
+        /// while history.undo(&mut total) {}
+        /// assert_eq!(total, 1);
+        /// ```
+        pub fn with_limits(max_depth: usize, max_size: usize) -> Self {
+            History {
+                undo: Stack::with_storage(DoublyLinkedList::new()),
+                redo: Stack::new(),
+                pending: None,
+                coalesce: false,
+                size: 0,
+                max_depth,
+                max_size,
+                evicted: 0,
+                saved: Some(0),
+            }
+        }
+
+        /// Returns the number of entries that can be undone
+        pub fn undo_len(&self) -> usize {
+            self.undo.len()
+        }
+
+        /// Returns the number of entries that can be redone
+        pub fn redo_len(&self) -> usize {
+            self.redo.len()
+        }
+
+        /// Returns `true` if there is an entry to undo
+        pub fn can_undo(&self) -> bool {
+            !self.undo.is_empty() || self.pending_len() > 0
+        }
+
+        /// Returns `true` if there is an entry to redo
+        pub fn can_redo(&self) -> bool {
+            !self.redo.is_empty()
+        }
+
+        /// Returns the size of the commands that can be undone
+        pub fn size(&self) -> usize {
+            self.size
+        }
+
+        /// Applies `cmd` to `target` and records it, discarding everything that
+        /// could be redone
+        ///
+        /// Inside a transaction the command joins the transaction, otherwise it
+        /// is merged into the last entry if possible.
+        pub fn apply(&mut self, target: &mut C::Target, mut cmd: C) {
+            cmd.apply(target);
+            if !self.redo.is_empty() {
+                self.redo.clear();
+                // The saved state can't be reached anymore
+                if self.saved.is_some_and(|saved| saved > self.position()) {
+                    self.saved = None;
+                }
+            }
+
+            let coalesce = mem::replace(&mut self.coalesce, true);
+            if let Some((entry, _)) = &mut self.pending {
+                entry.push(cmd, coalesce);
+                return;
+            }
+            // Marking the state as saved seals the history, so merging never
+            // changes the saved state
+            if let Some(entry) = self.undo.peek_mut().filter(|_| coalesce) {
+                let before = entry.size;
+                if entry.merge(&cmd) {
+                    self.size = self.size - before + entry.size;
+                    self.evict();
+                    return;
+                }
+            }
+            self.push_entry(cmd);
+            self.evict();
+        }
+
+        /// Undoes the last entry, returning `false` if there is none
+        ///
+        /// An open transaction is committed first.
+        pub fn undo(&mut self, target: &mut C::Target) -> bool {
+            self.close_pending();
+            self.coalesce = false;
+            match self.undo.pop() {
+                Some(mut entry) => {
+                    entry.undo(target);
+                    self.size -= entry.size;
+                    self.redo.push(entry);
+                    true
+                }
+                None => false,
+            }
+        }
+
+        /// Redoes the last undone entry, returning `false` if there is none
+        pub fn redo(&mut self, target: &mut C::Target) -> bool {
+            self.close_pending();
+            self.coalesce = false;
+            match self.redo.pop() {
+                Some(mut entry) => {
+                    entry.redo(target);
+                    self.size += entry.size;
+                    self.undo.push(entry);
+                    self.evict();
+                    true
+                }
+                None => false,
+            }
+        }
+
+        /// Begins a transaction: every command applied until the matching
+        /// [commit](History::commit) is undone and redone as one entry
+        ///
+        /// Transactions nest, only the outermost one making an entry.
+        ///
+        /// # Example
+        /// ```
+        /// # use crate::data_structure::history::{Command, History};
+        /// struct Push(char);
+        ///
+        /// impl Command for Push {
+        ///     type Target = String;
+        ///
+        ///     fn apply(&mut self, doc: &mut String) {
+        ///         doc.push(self.0);
+        ///     }
+        ///
+        ///     fn undo(&mut self, doc: &mut String) {
+        ///         doc.pop();
+        ///     }
+        /// }
+        ///
+        /// let mut doc = String::new();
+        /// let mut history = History::new();
+        /// history.apply(&mut doc, Push('a'));
+        /// history.begin();
+        /// history.apply(&mut doc, Push('b'));
+        /// history.apply(&mut doc, Push('c'));
+        /// history.commit();
+        /// history.undo(&mut doc);
+        /// assert_eq!(doc, "a");
+        /// ```
+        pub fn begin(&mut self) {
+            match &mut self.pending {
+                Some((_, depth)) => *depth += 1,
+                None => {
+                    self.pending = Some((Entry::new(), 1));
+                    self.coalesce = false;
+                }
+            }
+        }
+
+        /// Ends the innermost transaction, recording the outermost one as a single
+        /// entry once it ends
+        ///
+        /// # Panics
+        /// Panics if no transaction is open
+        pub fn commit(&mut self) {
+            let (_, depth) = self.pending.as_mut().expect("No transaction to commit");
+            *depth -= 1;
+            if *depth == 0 {
+                self.close_pending();
+            }
+        }
+
+        /// Stops the next command from being merged into the previous one
+        pub fn seal(&mut self) {
+            self.coalesce = false;
+        }
+
+        /// Marks the current state as saved
+        pub fn mark_saved(&mut self) {
+            self.close_pending();
+            self.coalesce = false;
+            self.saved = Some(self.position());
+        }
+
+        /// Returns `true` if the target differs from the state it was in when
+        /// last saved, or from its initial state if it never was
+        ///
+        /// Once the saved state can't be reached by undoing or redoing anymore,
+        /// the target stays dirty until it is saved again.
+        pub fn is_dirty(&self) -> bool {
+            self.pending_len() > 0 || self.saved != Some(self.position())
+        }
+
+        /// Forgets every entry, the target staying as it is
+        pub fn clear(&mut self) {
+            if self.is_dirty() {
+                self.saved = None;
+            } else {
+                self.saved = Some(0);
+            }
+            self.undo.clear();
+            self.redo.clear();
+            self.pending = None;
+            self.coalesce = false;
+            self.size = 0;
+            self.evicted = 0;
+        }
+
+        /// Returns the position of the current state, counted in entries from the
+        /// initial state
+        fn position(&self) -> usize {
+            self.evicted + self.undo.len()
+        }
+
+        fn pending_len(&self) -> usize {
+            self.pending.as_ref().map_or(0, |(entry, _)| entry.commands.len())
+        }
+
+        fn push_entry(&mut self, cmd: C) {
+            let mut entry = Entry::new();
+            entry.push(cmd, false);
+            self.size += entry.size;
+            self.undo.push(entry);
+        }
+
+        /// Records the open transaction, if it has any commands
+        fn close_pending(&mut self) {
+            if let Some((entry, _)) = self.pending.take() {
+                self.coalesce = false;
+                if !entry.commands.is_empty() {
+                    self.size += entry.size;
+                    self.undo.push(entry);
+                    self.evict();
+                }
+            }
+        }
+
+        /// Evicts the oldest entries until the undo stack is within the limits
+        fn evict(&mut self) {
+            while self.undo.len() > self.max_depth || self.size > self.max_size {
+                let Some(entry) = self.undo.storage_mut().pop_front() else {
+                    break;
+                };
+                self.size -= entry.size;
+                self.evicted += 1;
+            }
+            if self.saved.is_some_and(|saved| saved < self.evicted) {
+                self.saved = None;
+            }
+        }
+    }
+
+    impl<C: Command> Default for History<C> {
+        fn default() -> Self {
+            Self::new()
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::ds::allocator::{AllocError, Allocator, Box, Global};
+    use crate::ds::doubly_linked_list::DoublyLinkedList;
+    use crate::ds::linked_list::{LinkedList, ListNode, SortConfig};
+    use crate::ds::Error;
+    use crate::ds::stack::Stack;
+    use crate::ds::vector::Vector;
+
+    /// Counts how many times it is dropped in the shared cell
+    struct Counted<'a>(&'a std::cell::Cell<usize>);
+
+    impl Drop for Counted<'_> {
+        fn drop(&mut self) {
+            self.0.set(self.0.get() + 1);
+        }
+    }
+
+    /// Panics when the element holding 2 is dropped, to test panic safety
+    struct PanicOnDrop(i32);
+
+    impl Drop for PanicOnDrop {
+        fn drop(&mut self) {
+            if self.0 == 2 {
+                panic!("drop {}", self.0);
+            }
+        }
+    }
+
+    #[test]
+    fn test_linked_list() {
+        let mut l1 = ListNode::new(1);
+        let temp = ListNode::from_vec(vec![2, 3, 4, 5]);
+        l1.next = Some(temp);
+        assert_eq!(l1, ListNode::from_vec(vec![1, 2, 3, 4, 5]));
+
+        let mut l2 = ListNode::from_vec(vec![2, 3, 4, 5]);
+        l2.push(1);
+        assert_eq!(l2, ListNode::from_vec(vec![1, 2, 3, 4, 5]));
+
+        let mut l4 = ListNode::from_vec(vec![1, 2, 3, 4]);
+        l4.push_back(5);
+        assert_eq!(l4, ListNode::from_vec(vec![1, 2, 3, 4, 5]));
+
+        let mut l5 = ListNode::from_vec(vec![1, 2, 2, 3, 4, 5]);
+        match l5.delete(2) {
+            Ok(_) => (),
+            Err(e) => panic!("{}", e),
+        }
+        assert_eq!(l5, ListNode::from_vec(vec![1, 2, 3, 4, 5]));
+
+        let l6 = ListNode::from_vec(vec![3, 3, 3, 1, 2, 3, 4, 5]);
+        let l6 = match l6.find(1) {
+            Ok(res) => res,
+            Err(e) => panic!("{e}"),
+        };
+        assert_eq!(l6, ListNode::from_vec(vec![1, 2, 3, 4, 5]).as_ref());
+
+        let mut l7 = ListNode::from_vec(vec![1, 2, 3, 4, 5]);
+        assert_eq!(l7.len(), 5);
+
+        let mut l8 = ListNode::from_vec(vec![5, 4, 3, 2, 1]);
+        l8.reverse();
+        assert_eq!(l8, ListNode::from_vec(vec![1, 2, 3, 4, 5]));
+
+        let temp = ListNode::from_vec(vec![1, 2, 3, 4, 5]);
+        let l9 = temp.copy();
+        assert_eq!(temp, l9);
+
+        let l10 = ListNode::from_vec(vec![1, 2, 3, 4, 5]);
+        let element = l10[1];
+        let element2 = l10[2];
+        assert_eq!(element, 2);
+        assert_eq!(element2, 3);
+
+        let mut l11 = ListNode::from_vec(vec![1, 3, 4, 5]);
+        match l11.insert(1, 2) {
+            Ok(_) => (),
+            Err(e) => panic!("{e}"),
+        };
+        assert_eq!(l11, ListNode::from_vec(vec![1, 2, 3, 4, 5]));
+
+        let mut l12 = Some(ListNode::from_vec(vec![1, 1, 2, 3, 4, 5]));
+        let _poped = ListNode::pop(&mut l12).unwrap();
+        assert_eq!(l12, Some(ListNode::from_vec(vec![1, 2, 3, 4, 5])));
 
         let l13 = ListNode::from_vec(vec![1, 2, 3, 4, 5]);
         let found = l13.contains(3);
-        assert_eq!(found, true);
+        assert!(found);
         let notfound = l13.contains(0);
-        assert_eq!(notfound, false);
+        assert!(!notfound);
+
+        let t1 = ListNode::from_vec(vec![1, 3, 5]);
+        let t2 = ListNode::from_vec(vec![2, 4]);
+        let l16 = ListNode::<i32>::merge(Some(t1), Some(t2)).unwrap();
+        assert_eq!(l16, ListNode::from_vec(vec![1, 2, 3, 4, 5]));
+
+        let l18 = ListNode::from_vec(vec![1, 5, 3, 2, 4]);
+        let l18 = ListNode::<i32>::sort(Some(l18)).unwrap();
+        assert_eq!(l18, ListNode::from_vec(vec![1, 2, 3, 4, 5]));
+    }
+
+    #[test]
+    fn test_linked_list_container() {
+        let mut l1 = LinkedList::new();
+        assert!(l1.is_empty());
+        assert_eq!(l1.pop_front(), None);
+        assert_eq!(l1.pop_back(), None);
+
+        l1.push_back(2);
+        l1.push_front(1);
+        l1.push_back(3);
+        assert_eq!(l1.len(), 3);
+        assert_eq!(l1, LinkedList::from(ListNode::from_vec(vec![1, 2, 3])));
+
+        assert_eq!(l1.pop_back(), Some(3));
+        assert_eq!(l1.pop_front(), Some(1));
+        assert_eq!(l1.pop_front(), Some(2));
+        assert_eq!(l1.len(), 0);
+        assert!(l1.is_empty());
+
+        let mut l2 = LinkedList::from(ListNode::from_vec(vec![1, 2, 4]));
+        l2.insert(2, 3).unwrap();
+        l2.insert(4, 5).unwrap();
+        assert_eq!(l2.insert(7, 0), Err(Error::IndexOutOfRange { index: 7, len: 5 }));
+        assert_eq!(l2.len(), 5);
+        l2.delete(1).unwrap();
+        l2.delete(5).unwrap();
+        assert_eq!(l2.len(), 3);
+        assert_eq!(l2.into_head(), Some(ListNode::from_vec(vec![2, 3, 4])));
+
+        let mut l3 = LinkedList::from(ListNode::new(1));
+        l3.reverse();
+        assert!(l3.contains(1));
+        l3.clear();
+        assert!(!l3.contains(1));
+        assert_eq!(l3.len(), 0);
+    }
+
+    #[test]
+    fn test_linked_list_iter() {
+        let mut l1 = ListNode::from_vec(vec![1, 2, 3]);
+        assert_eq!(l1.iter().collect::<Vec<_>>(), vec![&1, &2, &3]);
+        assert_eq!(l1.iter().size_hint(), (1, None));
+
+        for val in &mut *l1 {
+            *val += 1;
+        }
+        let mut sum = 0;
+        for val in &*l1 {
+            sum += val;
+        }
+        assert_eq!(sum, 9);
+
+        let mut into_iter = l1.into_iter();
+        assert_eq!(into_iter.next(), Some(2));
+        assert_eq!(into_iter.collect::<Vec<_>>(), vec![3, 4]);
+
+        let l2 = LinkedList::from(ListNode::from_vec(vec!["a", "b", "c"]));
+        let mut iter = l2.iter();
+        assert_eq!(iter.size_hint(), (3, Some(3)));
+        assert_eq!(iter.by_ref().last(), Some(&"c"));
+        assert_eq!(iter.next(), None);
+        assert_eq!(iter.size_hint(), (0, Some(0)));
+        assert_eq!(l2.into_iter().collect::<Vec<_>>(), vec!["a", "b", "c"]);
+
+        let empty: LinkedList<i32> = LinkedList::new();
+        assert_eq!(empty.iter().next(), None);
+        assert_eq!(empty.into_iter().size_hint(), (0, Some(0)));
+    }
+
+    #[test]
+    fn test_from_iterator() {
+        let l1: Box<ListNode<String>> = ["a", "b"].iter().map(|s| s.to_string()).collect();
+        assert_eq!(l1, ListNode::from_vec(vec![String::from("a"), String::from("b")]));
+
+        let mut l2 = Box::<ListNode<i32>>::from([1, 2]);
+        l2.extend(vec![3, 4]);
+        l2.extend(&[5]);
+        assert_eq!(l2, ListNode::from_vec(vec![1, 2, 3, 4, 5]));
+
+        let mut l3: LinkedList<i32> = std::iter::empty().collect();
+        assert!(l3.is_empty());
+        l3.extend([1, 2]);
+        l3.extend(&[3]);
+        assert_eq!(l3.len(), 3);
+        assert_eq!(l3, LinkedList::from([1, 2, 3]));
+
+        let mut s1: Stack<i32> = (1..=3).collect();
+        assert_eq!(s1, Stack::from_vec(vec![3, 2, 1]));
+        s1.extend(&[0]);
+        assert_eq!(s1.len(), 4);
+        assert_eq!(s1, Stack::from([0, 3, 2, 1]));
+        let mut s2 = Stack::new();
+        s2.extend(1..=3);
+        assert_eq!(s2, (1..=3).collect::<Stack<i32>>());
+
+        let mut v1: Vector<i32> = (0..3).collect();
+        v1.extend(&[3, 4]);
+        let mut v2 = Vector::from([0, 1, 2, 3, 4]);
+        while let Some(val) = v2.pop() {
+            assert_eq!(v1.pop(), Some(val));
+        }
+        assert_eq!(v1.pop(), None);
+    }
+
+    #[test]
+    fn test_vector() {
+        let mut v1 = Vector::with_capacity(2);
+        assert!(v1.is_empty());
+        assert_eq!(v1.capacity(), 2);
+        v1.extend([3, 1, 2]);
+        assert_eq!(v1.len(), 3);
+        assert!(v1.capacity() >= 3);
+
+        v1.insert(0, 0);
+        assert_eq!(v1, [0, 3, 1, 2]);
+        assert_eq!(v1.remove(1), 3);
+        assert_eq!(v1.try_remove(3), Err(Error::IndexOutOfRange { index: 3, len: 3 }));
+        assert_eq!(v1.swap_remove(0), 0);
+        assert_eq!(v1, [2, 1]);
+
+        v1.sort();
+        assert_eq!(v1[..], [1, 2]);
+        assert_eq!(v1.get(1), Some(&2));
+        assert_eq!(v1.get(2), None);
+        *v1.get_mut(0).unwrap() = 5;
+        v1[1] = 6;
+        assert_eq!(v1.iter().sum::<i32>(), 11);
+
+        v1.reserve_exact(10);
+        assert_eq!(v1.capacity(), 12);
+        v1.shrink_to_fit();
+        assert_eq!(v1.capacity(), 2);
+        v1.clear();
+        assert!(v1.is_empty());
+        v1.shrink_to_fit();
+        assert_eq!(v1.capacity(), 0);
+
+        let mut v2: Vector<String> = ["a", "b", "c", "d"].iter().map(|s| s.to_string()).collect();
+        v2.insert(4, String::from("e"));
+        v2.truncate(2);
+        assert_eq!(v2.clone(), [String::from("a"), String::from("b")]);
+        assert_eq!(format!("{:?}", v2), r#"["a", "b"]"#);
+    }
+
+    #[test]
+    fn test_vector_growth() {
+        use crate::ds::vector::GrowthPolicy;
+        use std::sync::Arc;
+        use std::sync::atomic::{AtomicUsize, Ordering};
+
+        let capacities = |policy: GrowthPolicy, pushes: usize| -> Vec<usize> {
+            let mut vector: Vector<u32> = Vector::new();
+            vector.set_growth_policy(policy);
+            let mut caps = vec![];
+            for i in 0..pushes {
+                vector.push(i as u32);
+                if caps.last() != Some(&vector.capacity()) {
+                    caps.push(vector.capacity());
+                }
+            }
+            caps
+        };
+        assert_eq!(capacities(GrowthPolicy::Doubling, 20), vec![4, 8, 16, 32]);
+        assert_eq!(capacities(GrowthPolicy::OneAndHalf, 20), vec![4, 6, 9, 13, 19, 28]);
+        assert_eq!(capacities(GrowthPolicy::Fixed(5), 20), vec![5, 10, 15, 20]);
+        assert_eq!(capacities(GrowthPolicy::Custom(Arc::new(|cap| cap + 1)), 6), vec![4, 5, 6]);
+        let calls = Arc::new(AtomicUsize::new(0));
+        let counted = GrowthPolicy::Custom(Arc::new({
+            let calls = Arc::clone(&calls);
+            move |cap| {
+                calls.fetch_add(1, Ordering::Relaxed);
+                cap * 3
+            }
+        }));
+        assert_eq!(capacities(counted.clone(), 40), vec![4, 12, 36, 108]);
+        assert_eq!(calls.load(Ordering::Relaxed), 4);
+        assert_eq!(format!("{:?}", counted), "Custom(..)");
 
-        let t1 = ListNode::from_vec(vec![1, 3, 5]);
-        let t2 = ListNode::from_vec(vec![2, 4]);
-        let l16 = ListNode::<i32>::merge(Some(t1), Some(t2)).unwrap();
-        assert_eq!(l16, ListNode::from_vec(vec![1, 2, 3, 4, 5]));
+        let mut fixed: Vector<u32> = Vector::new();
+        fixed.set_growth_policy(GrowthPolicy::Fixed(5));
+        assert!(matches!(fixed.growth_policy(), GrowthPolicy::Fixed(5)));
 
-        let l18 = ListNode::from_vec(vec![1, 5, 3, 2, 4]);
-        let l18 = ListNode::<i32>::sort(Some(l18)).unwrap();
-        assert_eq!(l18, ListNode::from_vec(vec![1, 2, 3, 4, 5]));
+        let mut bytes: Vector<u8> = Vector::new();
+        bytes.push(1);
+        assert_eq!(bytes.capacity(), 8);
+        let mut large: Vector<[u8; 2048]> = Vector::new();
+        large.push([0; 2048]);
+        assert_eq!(large.capacity(), 1);
+
+        let mut v1 = Vector::from([1u64, 2]);
+        assert_eq!(v1.try_reserve(usize::MAX - 1), Err(Error::CapacityOverflow));
+        assert_eq!(v1.try_reserve_exact(isize::MAX as usize / 8), Err(Error::CapacityOverflow));
+        assert_eq!(v1.try_reserve(3), Ok(()));
+        assert!(v1.capacity() >= 5);
+        assert_eq!(v1, [1, 2]);
+
+        let mut v2: Vector<()> = Vector::new();
+        v2.push(());
+        assert_eq!(v2.try_reserve(usize::MAX), Err(Error::CapacityOverflow));
+        assert_eq!(Error::CapacityOverflow.to_string(), "Capacity overflow");
+    }
+
+    #[test]
+    #[should_panic(expected = "Capacity overflow")]
+    fn test_vector_reserve_overflow() {
+        Vector::<u16>::new().reserve(usize::MAX / 2);
+    }
+
+    #[test]
+    fn test_small_vector() {
+        use crate::ds::small_vector::SmallVector;
+        use std::cell::Cell;
+
+        let mut v1: SmallVector<i32, 4> = SmallVector::new();
+        assert!(v1.is_empty());
+        assert_eq!(v1.capacity(), 4);
+        v1.extend([1, 3]);
+        v1.insert(1, 2);
+        v1.push(4);
+        assert!(!v1.spilled());
+        assert_eq!(v1, [1, 2, 3, 4]);
+        v1.insert(0, 0);
+        assert!(v1.spilled());
+        assert_eq!(v1, [0, 1, 2, 3, 4]);
+        assert!(v1.capacity() >= 5);
+        assert_eq!(v1.remove(0), 0);
+        assert_eq!(v1.pop(), Some(4));
+        assert!(v1.spilled());
+        v1.sort_by(|a, b| b.cmp(a));
+        assert_eq!(v1.iter().copied().collect::<Vec<_>>(), vec![3, 2, 1]);
+
+        let mut v2: SmallVector<String, 3> = ["a", "b", "c"].iter().map(|s| s.to_string()).collect();
+        assert!(!v2.spilled());
+        assert_eq!(v2.remove(1), "b");
+        for val in &mut v2 {
+            val.push('!');
+        }
+        assert_eq!(v2.clone().into_iter().collect::<Vec<_>>(), vec!["a!", "c!"]);
+        assert_eq!(format!("{:?}", v2), r#"["a!", "c!"]"#);
+        let v3: SmallVector<String, 1> = v2.iter().cloned().collect();
+        assert!(v3.spilled());
+        assert_eq!(v3.into_iter().rev().collect::<Vec<_>>(), vec!["c!", "a!"]);
+
+        let mut v4: SmallVector<u8, 0> = SmallVector::new();
+        v4.push(1);
+        assert!(v4.spilled());
+        let v5: SmallVector<(), 2> = std::iter::repeat_n((), 10).collect();
+        assert_eq!(v5.len(), 10);
+
+        let drops = Cell::new(0);
+        let mut v6: SmallVector<Counted, 4> = (0..3).map(|_| Counted(&drops)).collect();
+        v6.truncate(2);
+        assert_eq!(drops.get(), 1);
+        let mut iter = v6.into_iter();
+        drop(iter.next());
+        assert_eq!(drops.get(), 2);
+        drop(iter);
+        assert_eq!(drops.get(), 3);
+        let v7: SmallVector<Counted, 2> = (0..5).map(|_| Counted(&drops)).collect();
+        drop(v7);
+        assert_eq!(drops.get(), 8);
+    }
+
+    #[test]
+    fn test_vector_zst() {
+        use std::cell::Cell;
+        use std::marker::PhantomData;
+
+        let mut v1: Vector<()> = Vector::new();
+        assert_eq!(v1.capacity(), usize::MAX);
+        for _ in 0..1000 {
+            v1.push(());
+        }
+        assert_eq!(v1.len(), 1000);
+        assert_eq!(v1.iter().count(), 1000);
+        v1.insert(500, ());
+        assert_eq!(v1.remove(0), ());
+        v1.shrink_to_fit();
+        assert_eq!(v1.capacity(), usize::MAX);
+        assert_eq!(v1.pop(), Some(()));
+        assert_eq!(v1.len(), 999);
+
+        let v2: Vector<PhantomData<String>> = (0..3).map(|_| PhantomData).collect();
+        assert_eq!(v2.len(), 3);
+        assert_eq!(Vector::<PhantomData<u8>>::with_capacity(5).capacity(), usize::MAX);
+
+        let drops = Cell::new(0);
+        let mut v3 = Vector::new();
+        for _ in 0..10 {
+            v3.push(Counted(&drops));
+        }
+        drop(v3.pop());
+        assert_eq!(drops.get(), 1);
+        v3.truncate(5);
+        assert_eq!(drops.get(), 5);
+        drop(v3);
+        assert_eq!(drops.get(), 10);
+    }
+
+    #[test]
+    fn test_vector_iterators() {
+        let strings = |vals: &[&str]| -> Vector<String> { vals.iter().map(|s| s.to_string()).collect() };
+
+        let mut iter = strings(&["a", "b", "c", "d"]).into_iter();
+        assert_eq!(iter.len(), 4);
+        assert_eq!(iter.next().as_deref(), Some("a"));
+        assert_eq!(iter.next_back().as_deref(), Some("d"));
+        drop(iter);
+        let mut v1 = strings(&["a", "b"]);
+        for val in &mut v1 {
+            val.push('!');
+        }
+        assert_eq!(v1.into_iter().rev().collect::<Vec<_>>(), vec!["b!", "a!"]);
+
+        let mut v2 = strings(&["a", "b", "c", "d", "e"]);
+        let mut drain = v2.drain(1..=3);
+        assert_eq!(drain.next_back().as_deref(), Some("d"));
+        assert_eq!(drain.as_slice(), ["b", "c"]);
+        drop(drain);
+        assert_eq!(v2, strings(&["a", "e"]));
+
+        let mut v3 = Vector::from([1, 2, 3, 4]);
+        std::mem::forget(v3.drain(1..2));
+        assert_eq!(v3, [1]);
+
+        let mut v4 = Vector::from([1, 2, 3, 4, 5]);
+        assert_eq!(v4.splice(1..4, []).collect::<Vec<_>>(), vec![2, 3, 4]);
+        assert_eq!(v4, [1, 5]);
+        v4.splice(1..1, 2..=4);
+        assert_eq!(v4, [1, 2, 3, 4, 5]);
+        v4.splice(..2, (0..5).map(|_| 0).filter(|_| true));
+        assert_eq!(v4, [0, 0, 0, 0, 0, 3, 4, 5]);
+        v4.splice(3.., [9]);
+        assert_eq!(v4, [0, 0, 0, 9]);
+
+        let mut v5 = strings(&["a", "b", "c"]);
+        let mut splice = v5.splice(..1, strings(&["x", "y", "z"]));
+        assert_eq!(splice.next().as_deref(), Some("a"));
+        drop(splice);
+        assert_eq!(v5, strings(&["x", "y", "z", "b", "c"]));
+    }
+
+    #[test]
+    fn test_vector_drain_panic_safety() {
+        use std::panic::{catch_unwind, AssertUnwindSafe};
+
+        let mut v1: Vector<PanicOnDrop> = (0..6).map(PanicOnDrop).collect();
+        let result = catch_unwind(AssertUnwindSafe(|| {
+            v1.drain(1..4);
+        }));
+        assert!(result.is_err());
+        assert_eq!(v1.iter().map(|val| val.0).collect::<Vec<_>>(), vec![0, 4, 5]);
+
+        let mut v2: Vector<PanicOnDrop> = (0..5).map(PanicOnDrop).collect();
+        let result = catch_unwind(AssertUnwindSafe(|| {
+            v2.splice(1..3, (10..13).map(PanicOnDrop));
+        }));
+        assert!(result.is_err());
+        assert_eq!(v2.iter().map(|val| val.0).collect::<Vec<_>>(), vec![0, 3, 4]);
+
+        let mut v3 = Vector::from([1, 2, 3, 4]);
+        let result = catch_unwind(AssertUnwindSafe(|| {
+            v3.splice(1..2, (0..3).map(|i| if i == 1 { panic!("replace") } else { i }));
+        }));
+        assert!(result.is_err());
+        assert_eq!(v3, [1, 0, 3, 4]);
+
+        let v4: Vector<PanicOnDrop> = (0..4).map(PanicOnDrop).collect();
+        assert!(catch_unwind(AssertUnwindSafe(|| drop(v4.into_iter()))).is_err());
+    }
+
+    #[test]
+    #[should_panic(expected = "Range end 4 out of range for length 3")]
+    fn test_vector_drain_out_of_range() {
+        Vector::from([1, 2, 3]).drain(1..4);
+    }
+
+    #[test]
+    #[should_panic(expected = "Index 2 out of range for length 1")]
+    fn test_vector_insert_out_of_range() {
+        let mut v1 = Vector::from([1]);
+        v1.insert(2, 2);
+    }
+
+    #[test]
+    fn test_allocator() {
+        use crate::ds::stack::ListStack;
+        use std::alloc::Layout;
+        use std::cell::Cell;
+        use std::ptr::NonNull;
+
+        /// Tracks live blocks and bytes, refusing to go over `limit` bytes
+        struct Counting {
+            blocks: Cell<usize>,
+            bytes: Cell<usize>,
+            limit: usize,
+        }
+
+        unsafe impl Allocator for Counting {
+            fn allocate(&self, layout: Layout) -> Result<NonNull<u8>, AllocError> {
+                if self.bytes.get() + layout.size() > self.limit {
+                    return Err(AllocError);
+                }
+                self.blocks.set(self.blocks.get() + 1);
+                self.bytes.set(self.bytes.get() + layout.size());
+                Global.allocate(layout)
+            }
+
+            unsafe fn deallocate(&self, ptr: NonNull<u8>, layout: Layout) {
+                self.blocks.set(self.blocks.get() - 1);
+                self.bytes.set(self.bytes.get() - layout.size());
+                Global.deallocate(ptr, layout)
+            }
+        }
+
+        // Each arena frees only its own blocks
+        impl PartialEq for Counting {
+            fn eq(&self, other: &Self) -> bool {
+                std::ptr::eq(self, other)
+            }
+        }
+
+        let counting = Counting { blocks: Cell::new(0), bytes: Cell::new(0), limit: 1024 };
+        let mut v1 = Vector::with_capacity_in(4, &counting);
+        v1.extend(0..4u64);
+        assert_eq!((counting.blocks.get(), counting.bytes.get()), (1, 32));
+        v1.push(4);
+        assert_eq!((counting.blocks.get(), counting.bytes.get()), (1, 64));
+        v1.shrink_to_fit();
+        assert_eq!(counting.bytes.get(), 40);
+        let v2 = v1.clone();
+        assert_eq!(v2, [0, 1, 2, 3, 4]);
+        assert_eq!(counting.blocks.get(), 2);
+        drop(v1);
+        let mut iter = v2.into_iter();
+        assert_eq!(iter.next(), Some(0));
+        drop(iter);
+        assert_eq!(counting.blocks.get(), 0);
+
+        let mut l1 = DoublyLinkedList::new_in(&counting);
+        l1.extend([String::from("a"), String::from("b"), String::from("c")]);
+        assert_eq!(counting.blocks.get(), 3);
+        let mut l2 = l1.split_off(1).unwrap();
+        l2.push_front(String::from("z"));
+        l1.append(&mut l2);
+        assert_eq!(l1, DoublyLinkedList::from_vec(vec!["a", "z", "b", "c"].into_iter().map(String::from).collect()));
+        assert_eq!(counting.blocks.get(), 4);
+        assert_eq!(l1.pop_back().as_deref(), Some("c"));
+        drop(l1);
+        assert_eq!(counting.blocks.get(), 0);
+
+        let mut l3 = LinkedList::new_in(&counting);
+        l3.extend([3, 1, 2]);
+        l3.insert(1, 0).unwrap();
+        l3.sort();
+        assert!(l3.iter().eq(&[0, 1, 2, 3]));
+        assert_eq!(counting.blocks.get(), 4);
+        let l4 = l3.clone();
+        assert_eq!(counting.blocks.get(), 8);
+        let mut cursor = l3.cursor_front_mut();
+        cursor.insert_after(5);
+        let tail = cursor.split_after();
+        assert!(tail.iter().eq(&[5, 1, 2, 3]));
+        assert_eq!(Box::allocator(&l4.into_head().unwrap()).blocks.get(), 9);
+        drop((l3, tail));
+        assert_eq!(counting.blocks.get(), 0);
+
+        let mut s1 = ListStack::with_storage(LinkedList::new_in(&counting));
+        s1.push(1);
+        s1.push(2);
+        assert_eq!(counting.blocks.get(), 2);
+        assert_eq!(s1.pop(), Some(2));
+        drop(s1);
+        assert_eq!(counting.blocks.get(), 0);
+
+        let small = Counting { blocks: Cell::new(0), bytes: Cell::new(0), limit: 8 };
+        assert_eq!(small.allocate(Layout::new::<[u8; 16]>()), Err(AllocError));
+        assert_eq!(AllocError.to_string(), "Memory allocation failed");
+    }
+
+    #[test]
+    #[should_panic(expected = "Iterator can't be empty")]
+    fn test_from_iterator_empty_list_node() {
+        let _: Box<ListNode<i32>> = std::iter::empty().collect();
+    }
+
+    #[test]
+    fn test_error() {
+        let mut l1 = ListNode::from_vec(vec![1, 2, 3]);
+        assert_eq!(l1.delete(0), Err(Error::NotFound));
+        assert_eq!(l1.find(0).unwrap_err(), Error::NotFound);
+        assert_eq!(l1.insert(4, 0), Err(Error::IndexOutOfRange { index: 4, len: 3 }));
+        assert_eq!(l1.try_get(5), Err(Error::IndexOutOfRange { index: 5, len: 3 }));
+        assert_eq!(l1.delete(1), Ok(()));
+        assert_eq!(l1.delete(3), Ok(()));
+        assert_eq!(l1.delete(2), Err(Error::Empty));
+        assert_eq!(l1, ListNode::new(2));
+        assert_eq!(ListNode::<i32>::try_from_iter(std::iter::empty()), Err(Error::Empty));
+
+        let mut l2: LinkedList<i32> = LinkedList::new();
+        assert_eq!(l2.delete(1), Err(Error::NotFound));
+        assert_eq!(l2.insert(1, 1), Err(Error::IndexOutOfRange { index: 1, len: 0 }));
+
+        let mut s1 = Stack::from([1]);
+        assert_eq!(s1.try_pop(), Ok(1));
+        assert_eq!(s1.try_pop(), Err(Error::Empty));
+
+        let err: std::boxed::Box<dyn std::error::Error> = Error::IndexOutOfRange { index: 2, len: 1 }.into();
+        assert_eq!(err.to_string(), "Index 2 out of range for length 1");
+    }
+
+    #[test]
+    fn test_linked_list_sort() {
+        let l1 = ListNode::from_vec(vec!["pear", "apple", "fig", "banana"]);
+        let l1 = ListNode::sort(Some(l1)).unwrap();
+        assert_eq!(l1, ListNode::from_vec(vec!["apple", "banana", "fig", "pear"]));
+
+        let l2 = ListNode::from_vec(vec![(3, 0), (1, 1), (3, 2), (2, 3), (1, 4), (3, 5)]);
+        let l2 = ListNode::sort_by_key(Some(l2), |pair| pair.0).unwrap();
+        assert_eq!(
+            l2,
+            ListNode::from_vec(vec![(1, 1), (1, 4), (2, 3), (3, 0), (3, 2), (3, 5)])
+        );
+
+        let t1 = ListNode::from_vec(vec![(1, 'a'), (2, 'a')]);
+        let t2 = ListNode::from_vec(vec![(1, 'b'), (2, 'b')]);
+        let l3 = ListNode::merge_by(Some(t1), Some(t2), |a, b| a.0.cmp(&b.0)).unwrap();
+        assert_eq!(l3, ListNode::from_vec(vec![(1, 'a'), (1, 'b'), (2, 'a'), (2, 'b')]));
+
+        assert_eq!(ListNode::<i32>::sort(None), None);
+        assert_eq!(ListNode::<i32>::merge(None, Some(ListNode::new(1))), Some(ListNode::new(1)));
+
+        let l4: Box<ListNode<u64>> = (0..10_000u64).map(|i| i * 7919 % 10_007).collect();
+        let l4 = ListNode::sort_unstable(Some(l4)).unwrap();
+        assert!(l4.iter().zip(l4.iter().skip(1)).all(|(a, b)| a <= b));
+        assert_eq!(l4.iter().count(), 10_000);
+
+        let mut l5 = LinkedList::from([String::from("b"), String::from("a")]);
+        l5.sort();
+        assert_eq!(l5.len(), 2);
+        assert_eq!(l5.front().map(String::as_str), Some("a"));
+    }
+
+    #[test]
+    fn test_linked_list_par_sort() {
+        let config = SortConfig {
+            threads: 4,
+            sequential_cutoff: 1_000,
+        };
+
+        let l1: Box<ListNode<(u64, usize)>> = (0..20_000usize)
+            .map(|i| ((i as u64 * 7919) % 1_000, i))
+            .collect();
+        let l1 = ListNode::par_sort_by(Some(l1), &config, |a, b| a.0.cmp(&b.0)).unwrap();
+        assert_eq!(l1.iter().count(), 20_000);
+        assert!(l1.iter().zip(l1.iter().skip(1)).all(|(a, b)| a.0 < b.0 || (a.0 == b.0 && a.1 < b.1)));
+
+        let l2 = ListNode::from_vec(vec![3, 1, 2]);
+        assert_eq!(ListNode::par_sort(Some(l2), &config), Some(ListNode::from_vec(vec![1, 2, 3])));
+        assert_eq!(ListNode::<i32>::par_sort(None, &config), None);
+
+        let mut l3: LinkedList<i32> = (0..5_003).rev().collect();
+        l3.par_sort(&SortConfig {
+            threads: 3,
+            sequential_cutoff: 10,
+        });
+        assert_eq!(l3.len(), 5_003);
+        assert!(l3.iter().eq((0..5_003).collect::<Vec<_>>().iter()));
+    }
+
+    #[test]
+    fn test_linked_list_long_chain() {
+        let len = 3_000_000;
+        let l1: Box<ListNode<usize>> = (0..len).collect();
+        let l2 = l1.copy();
+        let l3 = l1.clone();
+        assert_eq!(l1, l2);
+        assert_eq!(l2, l3);
+        assert!(format!("{:?}", l3).ends_with("2999999]"));
+        drop(l1);
+        drop(l2);
+
+        let mut l4 = LinkedList::from(l3);
+        assert_eq!(l4.len(), len);
+        l4.reverse();
+        assert_eq!(l4.front(), Some(&(len - 1)));
+        let l5 = l4.clone();
+        drop(l4);
+        assert_eq!(l5.into_iter().take(2).collect::<Vec<_>>(), vec![len - 1, len - 2]);
+    }
+
+    #[test]
+    fn test_linked_list_cursor() {
+        let mut l1: LinkedList<i32> = (1..=10).collect();
+        let mut cursor = l1.cursor_front_mut();
+        while let Some(val) = cursor.current() {
+            if *val % 3 == 0 {
+                cursor.remove_current();
+            } else {
+                *val *= 10;
+                cursor.move_next();
+            }
+        }
+        assert_eq!(cursor.index(), 7);
+        cursor.insert_after(110);
+        assert_eq!(l1, LinkedList::from([10, 20, 40, 50, 70, 80, 100, 110]));
+        assert_eq!(l1.len(), 8);
+
+        let mut cursor = l1.cursor_front_mut();
+        cursor.move_next();
+        cursor.move_next();
+        assert_eq!(cursor.peek_next(), Some(&mut 50));
+        let mut tail = cursor.split_after();
+        cursor.splice_after(LinkedList::from([41, 42]));
+        cursor.splice_after(LinkedList::new());
+        assert_eq!(l1, LinkedList::from([10, 20, 40, 41, 42]));
+        assert_eq!(l1.len(), 5);
+        assert_eq!(tail.len(), 5);
+        assert_eq!(tail.pop_back(), Some(110));
+
+        let mut head = Some(ListNode::from_vec(vec![String::from("a"), String::from("b")]));
+        let mut cursor = ListNode::cursor_mut(&mut head);
+        cursor.move_next();
+        let split = cursor.split_after();
+        assert!(split.is_empty());
+        assert_eq!(cursor.remove_current(), Some(String::from("b")));
+        assert_eq!(cursor.remove_current(), None);
+        cursor.splice_after(LinkedList::from([String::from("c")]));
+        assert_eq!(cursor.current(), Some(&mut String::from("c")));
+        assert_eq!(head, Some(ListNode::from_vec(vec![String::from("a"), String::from("c")])));
+    }
+
+    #[test]
+    fn test_doubly_linked_list() {
+        let mut d1 = DoublyLinkedList::new();
+        d1.push_back(2);
+        d1.push_front(1);
+        d1.push_back(3);
+        assert_eq!(d1.len(), 3);
+        assert_eq!((d1.front(), d1.back()), (Some(&1), Some(&3)));
+        assert_eq!(d1.pop_back(), Some(3));
+        assert_eq!(d1.pop_front(), Some(1));
+        assert_eq!(d1.pop_front(), Some(2));
+        assert_eq!(d1.pop_back(), None);
+        assert!(d1.is_empty());
+
+        let mut d2 = DoublyLinkedList::from_vec(vec![1, 2, 4, 5]);
+        d2.insert(2, 3).unwrap();
+        assert_eq!(d2.insert(9, 0), Err(Error::IndexOutOfRange { index: 9, len: 5 }));
+        assert_eq!(d2[3], 4);
+        d2[4] = 50;
+        d2.delete(1).unwrap();
+        d2.delete(50).unwrap();
+        assert_eq!(d2.delete(7), Err(Error::NotFound));
+        assert_eq!(d2.find(3), Ok(&3));
+        assert!(d2.contains(2) && !d2.contains(1));
+        assert_eq!(d2, DoublyLinkedList::from([2, 3, 4]));
+
+        d2.reverse();
+        assert_eq!(d2.iter().copied().collect::<Vec<_>>(), vec![4, 3, 2]);
+        assert_eq!(d2.iter().rev().copied().collect::<Vec<_>>(), vec![2, 3, 4]);
+        d2.push_back(1);
+        assert_eq!(d2.pop_front(), Some(4));
+
+        let mut d3: DoublyLinkedList<(u32, usize)> = (0..1_000usize).map(|i| ((i as u32 * 7) % 10, i)).collect();
+        d3.sort_by_key(|pair| pair.0);
+        assert!(d3.iter().zip(d3.iter().skip(1)).all(|(a, b)| a.0 < b.0 || (a.0 == b.0 && a.1 < b.1)));
+        assert_eq!(d3.back().map(|pair| pair.0), Some(9));
+        assert_eq!(d3.iter().rev().count(), 1_000);
+
+        let mut d4 = DoublyLinkedList::from([String::from("a"), String::from("b")]);
+        let mut d5 = DoublyLinkedList::from([String::from("c"), String::from("d")]);
+        d4.append(&mut d5);
+        assert!(d5.is_empty());
+        let d6 = d4.split_off(3).unwrap();
+        assert_eq!(d6, DoublyLinkedList::from([String::from("d")]));
+        assert_eq!(d4.split_off(5).unwrap_err(), Error::IndexOutOfRange { index: 5, len: 3 });
+        let mut iter = d4.clone().into_iter();
+        assert_eq!(iter.next_back(), Some(String::from("c")));
+        assert_eq!(iter.next(), Some(String::from("a")));
+        assert_eq!(iter.len(), 1);
+
+        let mut iter = d4.iter_mut();
+        iter.next().unwrap().push('!');
+        iter.next_back().unwrap().push('?');
+        assert_eq!(iter.next(), Some(&mut String::from("b")));
+        assert_eq!(iter.next_back(), None);
+        assert_eq!(format!("{:?}", d4), r#"["a!", "b", "c?"]"#);
+        let d7 = d4.split_off(0).unwrap();
+        assert!(d4.is_empty());
+        assert_eq!(d7.len(), 3);
+    }
+
+    #[test]
+    fn test_doubly_linked_list_cursor() {
+        let mut d1: DoublyLinkedList<i32> = (1..=6).collect();
+        let mut cursor = d1.cursor_front_mut();
+        while let Some(val) = cursor.current() {
+            if *val % 2 == 0 {
+                cursor.remove_current();
+            } else {
+                cursor.move_next();
+            }
+        }
+        assert_eq!(cursor.index(), 3);
+        cursor.insert_before(7);
+        cursor.insert_after(0);
+        cursor.move_prev();
+        assert_eq!(cursor.current(), Some(&mut 7));
+        assert_eq!(cursor.peek_next(), None);
+        assert_eq!(cursor.peek_prev(), Some(&mut 5));
+        cursor.move_next();
+        cursor.move_next();
+        assert_eq!(cursor.index(), 0);
+        assert_eq!(cursor.current(), Some(&mut 0));
+        cursor.move_prev();
+        assert_eq!(cursor.index(), 5);
+        assert_eq!(cursor.current(), None);
+        cursor.move_prev();
+        assert_eq!(cursor.index(), 4);
+        assert_eq!(cursor.current(), Some(&mut 7));
+        assert_eq!(d1, DoublyLinkedList::from([0, 1, 3, 5, 7]));
+
+        let mut cursor = d1.cursor_back_mut();
+        cursor.move_prev();
+        cursor.move_prev();
+        let tail = cursor.split_after();
+        assert_eq!(tail, DoublyLinkedList::from([5, 7]));
+        cursor.splice_after(DoublyLinkedList::from([4, 6]));
+        cursor.move_next();
+        cursor.move_next();
+        cursor.move_next();
+        assert_eq!(cursor.current(), None);
+        cursor.splice_after(DoublyLinkedList::from([-1]));
+        assert_eq!(cursor.index(), 6);
+        let all = cursor.split_after();
+        assert_eq!(all, DoublyLinkedList::from([-1, 0, 1, 3, 4, 6]));
+        assert!(d1.is_empty());
+    }
+
+    #[test]
+    #[should_panic(expected = "Can't move nodes between lists with different allocators")]
+    fn test_doubly_linked_list_append_foreign_allocator() {
+        use std::alloc::Layout;
+        use std::ptr::NonNull;
+
+        /// Allocates through `Global`, but only ever frees its own blocks
+        struct Arena {
+            // Not zero-sized, so two arenas never share an address
+            _id: u8,
+        }
+
+        unsafe impl Allocator for Arena {
+            fn allocate(&self, layout: Layout) -> Result<NonNull<u8>, AllocError> {
+                Global.allocate(layout)
+            }
+
+            unsafe fn deallocate(&self, ptr: NonNull<u8>, layout: Layout) {
+                Global.deallocate(ptr, layout)
+            }
+        }
+
+        impl PartialEq for Arena {
+            fn eq(&self, other: &Self) -> bool {
+                std::ptr::eq(self, other)
+            }
+        }
+
+        let (a1, a2) = (Arena { _id: 1 }, Arena { _id: 2 });
+        let mut l1 = DoublyLinkedList::new_in(&a1);
+        let mut l2 = DoublyLinkedList::new_in(&a2);
+        l1.push_back(1);
+        l2.push_back(2);
+        l1.append(&mut l2);
+    }
+
+    #[test]
+    fn test_bounded_stack() {
+        use crate::ds::stack::{BoundedStack, OverflowPolicy};
+        use std::cell::Cell;
+
+        static PARSER_STACK: std::sync::Mutex<BoundedStack<u8, 4>> = std::sync::Mutex::new(BoundedStack::new());
+        let mut s1 = PARSER_STACK.lock().unwrap();
+        assert_eq!(s1.capacity(), 4);
+        for val in 0..4 {
+            assert_eq!(s1.push(val), Ok(()));
+        }
+        assert!(s1.is_full());
+        assert_eq!(s1.push(4), Err(Error::CapacityExceeded));
+        assert_eq!(s1.pop(), Some(3));
+        *s1.peek_mut().unwrap() = 9;
+        assert_eq!(s1.iter().copied().collect::<Vec<_>>(), vec![9, 1, 0]);
+        s1.clear();
+        assert!(s1.is_empty());
+        drop(s1);
+
+        let mut s2: BoundedStack<String, 3> = BoundedStack::with_policy(OverflowPolicy::DropOldest);
+        s2.extend(["a", "b", "c", "d", "e"].map(String::from));
+        assert_eq!(s2.len(), 3);
+        assert_eq!(s2.as_slices(), (&[String::from("c")][..], &[String::from("d"), String::from("e")][..]));
+        assert_eq!(format!("{:?}", s2), r#"["e", "d", "c"]"#);
+        assert_eq!(s2.clone(), s2);
+        assert_eq!(s2.pop().as_deref(), Some("e"));
+        assert_eq!(s2.peek().map(String::as_str), Some("d"));
+        s2.push(String::from("f")).unwrap();
+        s2.push(String::from("g")).unwrap();
+        assert_eq!(s2.iter().map(String::as_str).collect::<Vec<_>>(), vec!["g", "f", "d"]);
+
+        let mut s3: BoundedStack<i32, 0> = BoundedStack::with_policy(OverflowPolicy::DropOldest);
+        assert_eq!(s3.push(1), Ok(()));
+        assert_eq!(s3.pop(), None);
+
+        let drops = Cell::new(0);
+        let mut s4: BoundedStack<Counted, 2> = BoundedStack::with_policy(OverflowPolicy::DropOldest);
+        s4.extend((0..5).map(|_| Counted(&drops)));
+        assert_eq!(drops.get(), 3);
+        let mut s5: BoundedStack<Counted, 1> = BoundedStack::new();
+        s5.extend((0..3).map(|_| Counted(&drops)));
+        assert_eq!(drops.get(), 5);
+        drop((s4, s5));
+        assert_eq!(drops.get(), 8);
+    }
+
+    #[test]
+    fn test_min_max_stack() {
+        use crate::ds::stack::{next_by, next_greater, next_smaller, MinMaxStack};
+
+        let mut s1 = MinMaxStack::new();
+        assert_eq!((s1.min(), s1.max()), (None, None));
+        let vals = [5, 3, 8, 3, 1, 9, 2];
+        for (i, val) in vals.iter().enumerate() {
+            s1.push(*val);
+            assert_eq!(s1.min(), vals[..=i].iter().min());
+            assert_eq!(s1.max(), vals[..=i].iter().max());
+        }
+        for i in (0..vals.len()).rev() {
+            assert_eq!(s1.min(), vals[..=i].iter().min());
+            assert_eq!(s1.max(), vals[..=i].iter().max());
+            assert_eq!(s1.peek(), Some(&vals[i]));
+            assert_eq!(s1.pop(), Some(vals[i]));
+        }
+        assert!(s1.is_empty());
+
+        let mut s2: MinMaxStack<String> = MinMaxStack::default();
+        s2.extend(["pear", "apple", "quince", "apple"].map(String::from));
+        assert_eq!(s2.min().map(String::as_str), Some("apple"));
+        assert_eq!(s2.max().map(String::as_str), Some("quince"));
+        assert_eq!(format!("{:?}", s2), r#"["apple", "quince", "apple", "pear"]"#);
+        s2.pop();
+        s2.pop();
+        assert_eq!(s2.clone().max().map(String::as_str), Some("pear"));
+        assert_eq!(s2.iter().len(), 2);
+
+        assert_eq!(next_greater::<i32>(&[]), vec![]);
+        assert_eq!(next_greater(&[1, 2, 3]), vec![Some(1), Some(2), None]);
+        assert_eq!(next_greater(&[3, 3, 3]), vec![None, None, None]);
+        assert_eq!(next_smaller(&[3, 2, 1]), vec![Some(1), Some(2), None]);
+        let temps = [73, 74, 75, 71, 69, 72, 76, 73];
+        let waits: Vec<_> = next_by(&temps, |later, earlier| later > earlier)
+            .iter()
+            .enumerate()
+            .map(|(i, next)| next.map_or(0, |j| j - i))
+            .collect();
+        assert_eq!(waits, vec![1, 1, 4, 2, 1, 1, 0, 0]);
+    }
+
+    #[test]
+    fn test_heap_owning_elements() {
+        let strings = |vals: &[&str]| -> Vec<String> { vals.iter().map(|s| s.to_string()).collect() };
+
+        let mut l1 = ListNode::from_vec(strings(&["a", "b", "c"]));
+        l1.reverse();
+        assert_eq!(l1, ListNode::from_vec(strings(&["c", "b", "a"])));
+        let mut l1 = Some(l1);
+        assert_eq!(ListNode::pop(&mut l1), Some(String::from("c")));
+        l1.as_mut().unwrap().reverse();
+        assert_eq!(l1, Some(ListNode::from_vec(strings(&["a", "b"]))));
+        assert_eq!(ListNode::pop(&mut l1), Some(String::from("a")));
+        assert_eq!(ListNode::pop(&mut l1), Some(String::from("b")));
+        assert_eq!(ListNode::pop(&mut l1), None);
+        assert!(l1.is_none());
+
+        let mut head = Some(ListNode::from_vec(vec![Box::new(1), Box::new(2)]));
+        assert_eq!(ListNode::pop(&mut head), Some(Box::new(1)));
+        assert_eq!(ListNode::pop(&mut head), Some(Box::new(2)));
+        assert_eq!(ListNode::pop(&mut head), None);
+        assert!(head.is_none());
+
+        let mut l2 = LinkedList::from([vec![1], vec![2, 2], vec![3, 3, 3]]);
+        l2.reverse();
+        assert_eq!(l2.pop_front(), Some(vec![3, 3, 3]));
+        assert_eq!(l2.len(), 2);
+
+        let mut s1 = Stack::from_vec(strings(&["x", "y"]));
+        assert_eq!(s1.pop().as_deref(), Some("x"));
+        assert_eq!(s1.pop().as_deref(), Some("y"));
+        assert_eq!(s1.try_pop(), Err(Error::Empty));
+        assert!(s1.is_empty());
+        s1.push(String::from("z"));
+        assert_eq!(s1.peek().map(String::as_str), Some("z"));
+        assert_eq!(s1.len(), 1);
     }
 
     #[test]
     fn test_stack() {
+        use crate::ds::stack::ListStack;
+
         let mut s1 = Stack::from_vec(vec![1, 1, 2, 3, 4, 5]);
         s1.pop();
-        assert_eq!(s1.list, Stack::from_vec(vec![1, 2, 3, 4, 5]).list);
+        assert_eq!(s1, Stack::from_vec(vec![1, 2, 3, 4, 5]));
 
         let mut s2 = Stack::from_vec(vec![2, 3, 4, 5]);
         s2.push(1);
-        assert_eq!(s2.list, Stack::from_vec(vec![1, 2, 3, 4, 5]).list);
+        assert_eq!(s2, Stack::from_vec(vec![1, 2, 3, 4, 5]));
 
         let s3 = Stack::from_vec(vec![1, 2, 3, 4, 5]);
-        let comp = s3.peak();
-        assert_eq!(comp, 1);
+        assert_eq!(s3.peek(), Some(&1));
+
+        let mut s4: Stack<String> = Stack::new();
+        assert_eq!(s4.pop(), None);
+        assert_eq!(s4.peek_mut(), None);
+        s4.push(String::from("a"));
+        s4.push(String::from("b"));
+        s4.peek_mut().unwrap().push('!');
+        assert_eq!(format!("{:?}", s4), r#"["b!", "a"]"#);
+        s4.clear();
+        assert!(s4.is_empty());
+
+        let mut s5 = ListStack::default();
+        s5.extend([1, 2, 3, 4, 5]);
+        assert_eq!(s5.peek(), Some(&5));
+        assert_eq!(s5.pop(), Some(5));
+        let s6: ListStack<i32> = (1..=4).collect();
+        assert_eq!(s5, s6);
+        assert_eq!(s5, Stack::from([4, 3, 2, 1]));
+        assert_eq!(s5.into_storage(), LinkedList::from([4, 3, 2, 1]));
+
+        let mut s7 = Stack::with_storage(DoublyLinkedList::new());
+        s7.extend([1, 2, 3, 4]);
+        assert_eq!(s7, Stack::from([4, 3, 2, 1]));
+        assert_eq!(s7.storage_mut().pop_front(), Some(1));
+        *s7.peek_mut().unwrap() = 5;
+        assert_eq!(s7.pop(), Some(5));
+        assert_eq!(s7.into_storage(), DoublyLinkedList::from([2, 3]));
+    }
+
+    #[test]
+    fn test_expr() {
+        use crate::expr::{evaluate, tokenize, ErrorKind, Expr};
+        use std::collections::HashMap;
+
+        let vars = HashMap::from([("x".to_string(), 3.0), ("y_2".to_string(), -2.0)]);
+        assert_eq!(evaluate("1 + 2 * 3", &vars), Ok(7.0));
+        assert_eq!(evaluate("(1 + 2) * 3", &vars), Ok(9.0));
+        assert_eq!(evaluate("10 - 4 - 3", &vars), Ok(3.0));
+        assert_eq!(evaluate("2 ^ 3 ^ 2", &vars), Ok(512.0));
+        assert_eq!(evaluate("-2 ^ 2", &vars), Ok(-4.0));
+        assert_eq!(evaluate("2 ^ -1", &vars), Ok(0.5));
+        assert_eq!(evaluate("--x * y_2", &vars), Ok(-6.0));
+        assert_eq!(evaluate("7 % 4 / .5", &vars), Ok(6.0));
+
+        let expr: Expr = "x * -(x - 1)".parse().unwrap();
+        assert_eq!(expr.to_string(), "x x 1 - neg *");
+        assert_eq!(expr.eval_with(|_| Some(5.0)), Ok(-20.0));
+
+        let err = |input: &str| {
+            let err = evaluate(input, &vars).unwrap_err();
+            (err.kind, err.column)
+        };
+        assert_eq!(err(""), (ErrorKind::UnexpectedEnd, 1));
+        assert_eq!(err("1 +"), (ErrorKind::UnexpectedEnd, 4));
+        assert_eq!(err("1 2"), (ErrorKind::UnexpectedToken, 3));
+        assert_eq!(err("x (1)"), (ErrorKind::UnexpectedToken, 3));
+        assert_eq!(err("* 2"), (ErrorKind::MissingOperand, 1));
+        assert_eq!(err("(1 + )"), (ErrorKind::MissingOperand, 6));
+        assert_eq!(err("(1 + (2)"), (ErrorKind::UnclosedParen, 1));
+        assert_eq!(err("1 + 2)"), (ErrorKind::UnmatchedParen, 6));
+        assert_eq!(err("1.2.3"), (ErrorKind::InvalidNumber("1.2.3".to_string()), 1));
+        assert_eq!(err("2 # 3"), (ErrorKind::UnexpectedChar('#'), 3));
+        assert_eq!(err("x + z"), (ErrorKind::UnknownVariable("z".to_string()), 5));
+        assert_eq!(err("1 / (x - 3)"), (ErrorKind::DivisionByZero, 3));
+
+        let tokens = tokenize("  ab+1").unwrap();
+        let columns: Vec<usize> = tokens.iter().map(|token| token.column).collect();
+        assert_eq!(columns, [3, 5, 6]);
+    }
+
+    #[test]
+    fn test_validator() {
+        use crate::validator::{Mismatch, MismatchKind, Validator};
+
+        let validator = Validator::new();
+        assert_eq!(validator.validate(""), Ok(()));
+        assert_eq!(validator.validate("fn f(a: [u8; 2]) { g(\"(\\\"]\", ')') }"), Ok(()));
+
+        let unexpected = |found: &str, expected: Option<&str>, line, column| Mismatch {
+            kind: MismatchKind::Unexpected {
+                found: found.to_string(),
+                expected: expected.map(str::to_string),
+            },
+            line,
+            column,
+        };
+        let unclosed = |open: &str, expected: &str, line, column| Mismatch {
+            kind: MismatchKind::Unclosed {
+                open: open.to_string(),
+                expected: expected.to_string(),
+            },
+            line,
+            column,
+        };
+
+        assert_eq!(validator.validate("a)"), Err(vec![unexpected(")", None, 1, 2)]));
+        assert_eq!(validator.validate("(]"), Err(vec![unclosed("(", ")", 1, 1), unexpected("]", Some(")"), 1, 2)]));
+        assert_eq!(
+            validator.validate("{\n  ( [\n}\n)"),
+            Err(vec![
+                unclosed("(", ")", 2, 3),
+                unexpected("}", Some("]"), 3, 1),
+                unexpected(")", None, 4, 1),
+            ])
+        );
+        assert_eq!(validator.validate("x = \"é\\\""), Err(vec![unclosed("\"", "\"", 1, 5)]));
+
+        let mut validator = Validator {
+            quotes: Vec::new(),
+            escape: None,
+            tags: true,
+            ..Validator::new()
+        };
+        validator.pairs.push(("$$".to_string(), "$$".to_string()));
+        validator.pairs.push(("begin".to_string(), "end".to_string()));
+        assert_eq!(validator.validate("<a href='x'>1 < 2 <b/></a >$$ (begin end) $$"), Ok(()));
+        let errors = validator.validate("<a><b>(</a>\nbegin $$").unwrap_err();
+        assert_eq!(
+            errors,
+            vec![
+                unclosed("<b>", "</b>", 1, 4),
+                unexpected("</a>", Some(")"), 1, 8),
+                unclosed("begin", "end", 2, 1),
+                unclosed("$$", "$$", 2, 7),
+            ]
+        );
+        assert_eq!(errors[1].to_string(), "Expected `)` but found `</a>` at line 1, column 8");
+
+        let input = "x < y ".repeat(100_000) + "<a>1 <b>2</b></a>";
+        assert_eq!(validator.validate(&input), Ok(()));
+    }
+
+    #[test]
+    fn test_history() {
+        use crate::history::{Command, History};
+
+        #[derive(Debug)]
+        struct Type(String);
+
+        impl Command for Type {
+            type Target = String;
+
+            fn apply(&mut self, doc: &mut String) {
+                doc.push_str(&self.0);
+            }
+
+            fn undo(&mut self, doc: &mut String) {
+                doc.truncate(doc.len() - self.0.len());
+            }
+
+            fn merge(&mut self, next: &Self) -> bool {
+                let word = !next.0.starts_with(' ');
+                if word {
+                    self.0.push_str(&next.0);
+                }
+                word
+            }
+
+            fn size(&self) -> usize {
+                self.0.len()
+            }
+        }
+
+        let mut doc = String::new();
+        let mut history = History::new();
+        assert!(!history.is_dirty());
+        assert!(!history.undo(&mut doc));
+        for s in ["a", "b", " c", "d"] {
+            history.apply(&mut doc, Type(s.to_string()));
+        }
+        assert_eq!((history.undo_len(), history.size()), (2, 5));
+        assert!(history.is_dirty());
+
+        history.mark_saved();
+        history.apply(&mut doc, Type("e".to_string()));
+        assert_eq!(history.undo_len(), 3);
+        assert!(history.undo(&mut doc));
+        assert_eq!(doc, "ab cd");
+        assert!(!history.is_dirty());
+        history.undo(&mut doc);
+        assert_eq!((doc.as_str(), history.redo_len(), history.size()), ("ab", 2, 2));
+        assert!(history.is_dirty());
+        history.redo(&mut doc);
+        assert!(!history.is_dirty());
+
+        // Undoing to the saved state and applying something else loses it
+        history.undo(&mut doc);
+        history.apply(&mut doc, Type("x".to_string()));
+        assert!(!history.can_redo());
+        history.undo(&mut doc);
+        assert_eq!(doc, "ab");
+        assert!(history.is_dirty());
+
+        history.begin();
+        history.apply(&mut doc, Type(" 1".to_string()));
+        history.begin();
+        history.apply(&mut doc, Type(" 2".to_string()));
+        history.commit();
+        assert!(history.can_undo());
+        history.apply(&mut doc, Type(" 3".to_string()));
+        history.commit();
+        assert_eq!(history.undo_len(), 2);
+        history.undo(&mut doc);
+        assert_eq!(doc, "ab");
+        history.redo(&mut doc);
+        assert_eq!(doc, "ab 1 2 3");
+
+        history.clear();
+        assert!(history.is_dirty());
+        history.mark_saved();
+        history.clear();
+        assert!(!history.is_dirty());
+
+        let mut doc = String::new();
+        let mut history = History::with_limits(3, 6);
+        history.mark_saved();
+        for s in [" 1", " 2", " 3"] {
+            history.apply(&mut doc, Type(s.to_string()));
+        }
+        assert_eq!((history.undo_len(), history.size()), (3, 6));
+        history.apply(&mut doc, Type("45".to_string()));
+        assert_eq!((history.undo_len(), history.size()), (2, 6));
+        history.apply(&mut doc, Type(" 5".to_string()));
+        history.apply(&mut doc, Type(" 6".to_string()));
+        assert_eq!((history.undo_len(), history.size()), (2, 4));
+        while history.undo(&mut doc) {}
+        assert_eq!(doc, " 1 2 345");
+        assert!(history.is_dirty());
+    }
+
+    #[test]
+    #[cfg(not(loom))]
+    fn test_concurrent_stack() {
+        use crate::ds::stack::ConcurrentStack;
+        use std::sync::Barrier;
+        use std::thread;
+
+        let stack = ConcurrentStack::new();
+        assert!(stack.is_empty());
+        stack.push(String::from("a"));
+        stack.push(String::from("b"));
+        assert_eq!(stack.pop().as_deref(), Some("b"));
+        assert_eq!(stack.pop().as_deref(), Some("a"));
+        assert_eq!(stack.pop(), None);
+
+        // Threads push and pop in lockstep so that pops race with each other and
+        // with pushes, and every value must come out exactly once
+        const THREADS: usize = 8;
+        const OPS: usize = 200;
+        let barrier = Barrier::new(THREADS);
+        let popped: Vec<Vec<String>> = thread::scope(|scope| {
+            let workers: Vec<_> = (0..THREADS)
+                .map(|t| {
+                    let (stack, barrier) = (&stack, &barrier);
+                    scope.spawn(move || {
+                        barrier.wait();
+                        let mut popped = Vec::new();
+                        for i in 0..OPS {
+                            stack.push((t * OPS + i).to_string());
+                            if i % 3 != 0 {
+                                popped.extend(stack.pop());
+                            }
+                            if i % 16 == 0 {
+                                thread::yield_now();
+                            }
+                        }
+                        popped
+                    })
+                })
+                .collect();
+            workers.into_iter().map(|worker| worker.join().unwrap()).collect()
+        });
+
+        let mut seen: Vec<usize> = popped.into_iter().flatten().map(|val| val.parse().unwrap()).collect();
+        while let Some(val) = stack.pop() {
+            seen.push(val.parse().unwrap());
+        }
+        seen.sort_unstable();
+        assert_eq!(seen, (0..THREADS * OPS).collect::<Vec<_>>());
+
+        // Values left on the stack are dropped with it
+        (0..100).for_each(|i| stack.push(i.to_string()));
+        drop(stack);
+    }
+
+    /// Model checks `ConcurrentStack` with loom, which runs the closure under
+    /// every interleaving of its threads, run with
+    /// `RUSTFLAGS="--cfg loom" cargo test --release --lib concurrent_stack_loom`
+    #[test]
+    #[cfg(loom)]
+    fn test_concurrent_stack_loom() {
+        use crate::ds::stack::ConcurrentStack;
+        use loom::sync::Arc;
+        use loom::thread;
+
+        // Two threads race their pushes and pops, and the second pop frees
+        // the nodes while the other thread may still be reading them
+        loom::model(|| {
+            let stack = Arc::new(ConcurrentStack::new());
+            let workers: Vec<_> = (0..2)
+                .map(|t| {
+                    let stack = Arc::clone(&stack);
+                    thread::spawn(move || {
+                        stack.push(t.to_string());
+                        stack.pop()
+                    })
+                })
+                .collect();
+            let mut popped: Vec<String> = workers
+                .into_iter()
+                .map(|worker| worker.join().unwrap().unwrap())
+                .collect();
+            popped.sort();
+            assert_eq!(popped, ["0", "1"]);
+            assert!(stack.is_empty());
+        });
+
+        // A third thread pops what the other two push, so reclaiming races
+        // with both a push and a pop
+        let mut builder = loom::model::Builder::new();
+        builder.preemption_bound = Some(2);
+        builder.check(|| {
+            let stack = Arc::new(ConcurrentStack::new());
+            stack.push(String::from("a"));
+            let pusher = {
+                let stack = Arc::clone(&stack);
+                thread::spawn(move || stack.push(String::from("b")))
+            };
+            let popper = {
+                let stack = Arc::clone(&stack);
+                thread::spawn(move || stack.pop())
+            };
+            let mut popped = vec![stack.pop()];
+            pusher.join().unwrap();
+            popped.push(popper.join().unwrap());
+            popped.push(stack.pop());
+            let mut popped: Vec<String> = popped.into_iter().flatten().collect();
+            popped.sort();
+            assert_eq!(popped, ["a", "b"]);
+        });
+    }
+
+    #[test]
+    fn test_queue() {
+        use crate::ds::queue::Queue;
+
+        let mut queue = Queue::new();
+        assert_eq!(queue.try_dequeue(), Err(Error::Empty));
+        queue.enqueue(String::from("a"));
+        assert_eq!(queue.peek(), queue.peek_back());
+        queue.extend([String::from("b"), String::from("c")]);
+        assert_eq!(queue.dequeue().as_deref(), Some("a"));
+        assert_eq!(queue.dequeue().as_deref(), Some("b"));
+        // Down to a single node, which is both ends
+        queue.peek_mut().unwrap().push('!');
+        queue.enqueue(String::from("d"));
+        assert_eq!(queue.peek_back().map(String::as_str), Some("d"));
+        queue.iter_mut().for_each(|val| val.push('?'));
+        queue.peek_back_mut().unwrap().push('#');
+        assert_eq!(queue.iter().collect::<Vec<_>>(), vec!["c!?", "d?#"]);
+        let cloned = queue.clone();
+        assert_eq!(queue.len(), 2);
+        assert_eq!(queue.into_iter().collect::<Vec<_>>(), vec!["c!?", "d?#"]);
+        assert_eq!(format!("{:?}", cloned), r#"["c!?", "d?#"]"#);
+
+        // Breadth-first order through a tree stored as child lists
+        let children = [vec![1, 2], vec![3], vec![4, 5], vec![], vec![], vec![]];
+        let mut order = Vec::new();
+        let mut pending = Queue::from([0]);
+        while let Some(node) = pending.pop() {
+            order.push(node);
+            pending.extend(&children[node]);
+        }
+        assert_eq!(order, [0, 1, 2, 3, 4, 5]);
+
+        let mut queue: Queue<i32> = (0..1_000).collect();
+        assert_eq!(queue.len(), 1_000);
+        assert!(queue.iter().copied().eq(0..1_000));
+        queue.clear();
+        assert!(queue.is_empty());
+        assert_eq!(queue.to_string(), "+\n|\n+\n(empty)");
+        queue.push(7);
+        assert_eq!(queue.to_string(), "+---+\n| 7 |\n+---+\n  ↑\nFRONT/BACK");
+        queue.push(8);
+        assert_eq!(queue.to_string(), "+---+---+\n| 7 | 8 |\n+---+---+\n  ↑   ↑\nFRONT BACK");
+        let drawn = "\
++-----+-----+-----+
+|  1  | 22  | 333 |
++-----+-----+-----+
+   ↑           ↑
+ FRONT       BACK";
+        assert_eq!(Queue::from([1, 22, 333]).to_string(), drawn);
+        assert_eq!(Queue::from([-10]).to_string(), "+-----+\n| -10 |\n+-----+\n   ↑\nFRONT/BACK");
+    }
+
+    #[test]
+    fn test_deque() {
+        use crate::ds::deque::Deque;
+
+        let mut d1 = Deque::with_capacity(4);
+        assert_eq!(d1.pop_front(), None);
+        assert_eq!(d1.back(), None);
+        d1.extend([2, 3, 4]);
+        d1.push_front(1);
+        assert_eq!(d1.as_slices(), (&[1][..], &[2, 3, 4][..]));
+        assert_eq!(d1.capacity(), 4);
+
+        // Growing while wrapped keeps the order, whichever part gets moved
+        d1.push_back(5);
+        assert_eq!(d1, [1, 2, 3, 4, 5]);
+        d1.push_front(0);
+        d1.push_front(-1);
+        assert_eq!(d1, [-1, 0, 1, 2, 3, 4, 5]);
+        let mut d2: Deque<i32> = Deque::with_capacity(4);
+        d2.push_back(0);
+        d2.pop_front();
+        d2.extend([1, 2, 3, 4]);
+        assert_eq!(d2.as_slices(), (&[1, 2, 3][..], &[4][..]));
+        d2.push_back(5);
+        assert_eq!(d2.as_slices(), (&[1, 2, 3, 4, 5][..], &[][..]));
+
+        assert_eq!((d1[0], d1[6]), (-1, 5));
+        d1[1] = 10;
+        *d1.back_mut().unwrap() += 10;
+        assert_eq!(d1.get(7), None);
+        assert_eq!((d1.front(), d1.back()), (Some(&-1), Some(&15)));
+        assert_eq!(d1.iter().rev().copied().collect::<Vec<_>>(), vec![15, 4, 3, 2, 1, 10, -1]);
+        for val in d1.range_mut(2..5) {
+            *val *= 2;
+        }
+        assert_eq!(d1.range(1..=4).copied().collect::<Vec<_>>(), vec![10, 2, 4, 6]);
+        assert_eq!(d1.range(3..3).len(), 0);
+
+        let mut d3: Deque<i32> = Deque::with_capacity(5);
+        d3.extend([3, 4, 5]);
+        d3.push_front(2);
+        d3.push_front(1);
+        d3.pop_back();
+        d3.push_front(0);
+        assert!(!d3.as_slices().1.is_empty());
+        assert_eq!(d3.make_contiguous(), [0, 1, 2, 3, 4]);
+        assert_eq!(d3.as_slices(), (&[0, 1, 2, 3, 4][..], &[][..]));
+        d3.rotate_left(4);
+        assert_eq!(d3, [4, 0, 1, 2, 3]);
+        d3.rotate_right(1);
+        assert_eq!(d3, [3, 4, 0, 1, 2]);
+        d3.rotate_left(0);
+        d3.rotate_right(5);
+        assert_eq!(d3, [3, 4, 0, 1, 2]);
+        assert_eq!(d3.capacity(), 5);
+
+        let strings: Deque<String> = ["a", "b", "c"].into_iter().map(String::from).collect();
+        let mut d4 = strings.clone();
+        d4.push_front(String::from("z"));
+        d4.truncate(2);
+        assert_eq!(d4.iter().map(String::as_str).collect::<Vec<_>>(), vec!["z", "a"]);
+        assert_eq!(format!("{:?}", d4), "[\"z\", \"a\"]");
+        let mut owned = strings.into_iter();
+        assert_eq!(owned.next_back().as_deref(), Some("c"));
+        assert_eq!(owned.len(), 2);
+        drop(owned);
+
+        let mut units: Deque<()> = Deque::new();
+        units.push_front(());
+        units.push_back(());
+        assert_eq!(units.len(), 2);
+        assert_eq!(units.make_contiguous().len(), 2);
+        units.rotate_left(1);
+        assert_eq!(units.drain(..).count(), 2);
+        assert_eq!(units.capacity(), usize::MAX);
+    }
+
+    #[test]
+    fn test_deque_drain() {
+        use crate::ds::deque::Deque;
+        use std::panic::{catch_unwind, AssertUnwindSafe};
+
+        fn wrapped(vals: std::ops::Range<i32>) -> Deque<String> {
+            let mut deque = Deque::with_capacity(8);
+            deque.extend(vec![String::new(); 5]);
+            while deque.pop_front().is_some() {}
+            deque.extend(vals.map(|val| val.to_string()));
+            deque
+        }
+
+        // The front is shorter than the tail and gets moved up
+        let mut d1 = wrapped(0..8);
+        assert!(!d1.as_slices().1.is_empty());
+        let mut drain = d1.drain(1..4);
+        assert_eq!(drain.next().as_deref(), Some("1"));
+        assert_eq!(drain.as_slices().0.len() + drain.as_slices().1.len(), 2);
+        drop(drain);
+        assert_eq!(d1.iter().map(String::as_str).collect::<Vec<_>>(), vec!["0", "4", "5", "6", "7"]);
+
+        // The tail is shorter than the front and gets moved down
+        let mut d2 = wrapped(0..8);
+        assert_eq!(d2.drain(4..6).rev().collect::<Vec<_>>(), vec!["5", "4"]);
+        assert_eq!(d2.iter().map(String::as_str).collect::<Vec<_>>(), vec!["0", "1", "2", "3", "6", "7"]);
+        d2.push_front(String::from("x"));
+        d2.push_back(String::from("y"));
+        assert_eq!(d2.len(), 8);
+        assert_eq!(d2.capacity(), 8);
+        assert_eq!(d2.drain(..).count(), 8);
+        assert!(d2.is_empty());
+
+        let mut d3: Deque<_> = (0..6).collect();
+        std::mem::forget(d3.drain(2..4));
+        assert_eq!(d3, [0, 1]);
+
+        let mut d4: Deque<PanicOnDrop> = (0..6).map(PanicOnDrop).collect();
+        let result = catch_unwind(AssertUnwindSafe(|| {
+            d4.drain(1..4);
+        }));
+        assert!(result.is_err());
+        assert_eq!(d4.iter().map(|val| val.0).collect::<Vec<_>>(), vec![0, 4, 5]);
+    }
+
+    #[test]
+    #[should_panic(expected = "Rotation by 4 out of range for length 3")]
+    fn test_deque_rotate_out_of_range() {
+        crate::ds::deque::Deque::from([1, 2, 3]).rotate_left(4);
     }
 }
\ No newline at end of file